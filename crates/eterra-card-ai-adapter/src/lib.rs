@@ -4,20 +4,16 @@ pub mod eterra_adapter {
     use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
     use scale_info::TypeInfo;
 
-    // Local copies of game types so this adapter has no dependency on pallet-eterra
-    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
-    pub enum Possession { PlayerOne, PlayerTwo }
-
-    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
-    pub struct Card {
-        pub top: u8,
-        pub right: u8,
-        pub bottom: u8,
-        pub left: u8,
-        pub possession: Option<Possession>,
-    }
+    // Board, card and capture rules are shared with pallet-eterra
+    pub use eterra_rules::{
+        Action, Board, BoardSize, Card, Elements, Possession, RuleSet, MAX_HAND_SIZE, WALL_RANK,
+    };
 
-    pub type Board = [[Option<Card>; 4]; 4];
+    /// Most legal actions in any state: every hand card on every cell of the largest board.
+    pub const MAX_ACTIONS: usize =
+        eterra_rules::MAX_BOARD_DIM * eterra_rules::MAX_BOARD_DIM * MAX_HAND_SIZE;
+    // The search lists actions into buffers of `pallet_eterra_monte_carlo_ai::MAX_ACTIONS`
+    const _: () = assert!(MAX_ACTIONS <= pallet_eterra_monte_carlo_ai::MAX_ACTIONS);
 
     /// One hand entry (mirrors data needed to place a card)
     #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
@@ -29,22 +25,42 @@ pub mod eterra_adapter {
         pub used: bool,
     }
 
-    /// Fixed-size hand (5 entries). If you make HandSize configurable later,
-    /// you can rework this to a BoundedVec, but fixed-size is fastest for AI.
+    /// Hand of up to `MAX_HAND_SIZE` entries; only the first `len` are real.
+    /// Kept as a fixed array rather than a Vec so cloning states stays cheap in playouts.
     #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
     pub struct Hand {
-        pub entries: [HandEntry; 5],
+        pub entries: [HandEntry; MAX_HAND_SIZE],
+        pub len: u8,
+    }
+
+    impl Hand {
+        /// Build a hand from `entries`, ignoring anything past `MAX_HAND_SIZE`.
+        pub fn new(entries: &[HandEntry]) -> Self {
+            let mut hand = Hand::default();
+            for (slot, e) in hand.entries.iter_mut().zip(entries.iter()) {
+                *slot = e.clone();
+                hand.len += 1;
+            }
+            hand
+        }
+
+        /// The real entries of this hand.
+        pub fn entries(&self) -> &[HandEntry] {
+            &self.entries[..self.len as usize]
+        }
     }
 
     /// Compact, cloneable snapshot of game state used by the AI
     #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
     pub struct State {
-        pub board: Board,   // 4x4 Option<Card>
+        pub board: Board,         // width x height Option<Card>
         pub scores: (u8, u8),     // (p0, p1)
         pub player_turn: u8,      // 0 or 1
         pub round: u8,
         pub max_rounds: u8,
         pub hands: [Hand; 2],
+        pub rules: RuleSet,
+        pub elements: Elements,
     }
 
     impl Default for State {
@@ -56,18 +72,12 @@ pub mod eterra_adapter {
                 round: 0,
                 max_rounds: 0,
                 hands: [Hand::default(), Hand::default()],
+                rules: RuleSet::default(),
+                elements: Default::default(),
             }
         }
     }
 
-    /// Play a card from hand at (x,y)
-    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
-    pub struct Action {
-        pub hand_index: u8, // 0..4
-        pub x: u8,          // 0..3
-        pub y: u8,          // 0..3
-    }
-
     /// Adapter gluing your card game rules to the generic Monte-Carlo AI.
     pub struct Adapter;
 
@@ -85,12 +95,12 @@ pub mod eterra_adapter {
                 return 0;
             }
             let mut k = 0;
-            for x in 0..4u8 {
-                for y in 0..4u8 {
+            for x in 0..s.board.width() as u8 {
+                for y in 0..s.board.height() as u8 {
                     if s.board[x as usize][y as usize].is_some() {
                         continue;
                     }
-                    for (idx, he) in s.hands[s.player_turn as usize].entries.iter().enumerate() {
+                    for (idx, he) in s.hands[s.player_turn as usize].entries().iter().enumerate() {
                         if he.used {
                             continue;
                         }
@@ -113,68 +123,26 @@ pub mod eterra_adapter {
 
             // Build a placed card from hand entry
             let he = g.hands[g.player_turn as usize].entries[a.hand_index as usize].clone();
-            let mut placed = Card {
-                top: he.north,
-                right: he.east,
-                bottom: he.south,
-                left: he.west,
-                possession: None,
-            };
-
-            let placing_player = if g.player_turn == 0 { Possession::PlayerOne } else { Possession::PlayerTwo };
-            placed.possession = Some(placing_player.clone());
-
-            // Place on board
-            g.board[a.x as usize][a.y as usize] = Some(placed.clone());
-
-            // Capture logic (mirrors pallet)
-            let dirs = [
-                (0i8, -1i8, placed.top),
-                (1, 0, placed.right),
-                (0, 1, placed.bottom),
-                (-1, 0, placed.left),
-            ];
-
-            for &(dx, dy, opposing_rank) in &dirs {
-                let nx = a.x as i8 + dx;
-                let ny = a.y as i8 + dy;
-                if nx >= 0 && nx < 4 && ny >= 0 && ny < 4 {
-                    if let Some(mut opp) = g.board[nx as usize][ny as usize].clone() {
-                        let rank = match (dx, dy) {
-                            (0, -1) => opp.bottom,
-                            (1, 0) => opp.left,
-                            (0, 1) => opp.top,
-                            (-1, 0) => opp.right,
-                            _ => 0,
-                        };
-                        if opposing_rank > rank {
-                            if let Some(prev) = opp.possession.clone() {
-                                if prev == Possession::PlayerOne {
-                                    g.scores.0 = g.scores.0.saturating_sub(1);
-                                } else if prev == Possession::PlayerTwo {
-                                    g.scores.1 = g.scores.1.saturating_sub(1);
-                                }
-                            }
-                            if placing_player == Possession::PlayerOne {
-                                g.scores.0 = g.scores.0.saturating_add(1);
-                            } else {
-                                g.scores.1 = g.scores.1.saturating_add(1);
-                            }
-                            opp.possession = Some(placing_player.clone());
-                            g.board[nx as usize][ny as usize] = Some(opp);
-                        }
-                    }
-                }
-            }
+            let placed = Card::new(he.north, he.east, he.south, he.west);
+
+            // Place and capture with the same rules the pallet uses
+            let player_ix = g.player_turn;
+            let rules = g.rules;
+            let elements = g.elements;
+            eterra_rules::play_card(
+                &mut g.board,
+                &mut g.scores,
+                a.x as usize,
+                a.y as usize,
+                placed,
+                player_ix,
+                &rules,
+                &elements,
+            );
 
             // Mark used & advance turn/round (increment round on wrap)
-            g.hands[g.player_turn as usize].entries[a.hand_index as usize].used = true;
-            if g.player_turn == 0 {
-                g.player_turn = 1;
-            } else {
-                g.player_turn = 0;
-                g.round = g.round.saturating_add(1);
-            }
+            g.hands[player_ix as usize].entries[a.hand_index as usize].used = true;
+            eterra_rules::advance_turn(&mut g.player_turn, &mut g.round);
             g
         }
     }
@@ -199,7 +167,7 @@ pub mod eterra_adapter {
         }
 
         fn is_terminal(s: &<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::State) -> bool {
-            s.round >= s.max_rounds
+            s.round >= s.max_rounds || s.board.is_full()
         }
 
         fn current_player(s: &<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::State) -> Self::Player {
@@ -218,13 +186,21 @@ pub mod eterra_adapter {
             s: &<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::State,
             seed: u64,
         ) -> Option<<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::Action> {
-            const MAX: usize = 128;
-            let mut buf: [Option<<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::Action>; MAX] =
+            let mut buf: [Option<<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::Action>; MAX_ACTIONS] =
                 core::array::from_fn(|_| None);
-            let n = <Self as pallet_eterra_monte_carlo_ai::GameAdapter>::list_actions::<MAX>(s, &mut buf);
+            let n = <Self as pallet_eterra_monte_carlo_ai::GameAdapter>::list_actions::<MAX_ACTIONS>(s, &mut buf);
             if n == 0 { return None; }
             let idx = (seed as usize) % n;
             buf[idx].clone()
         }
+
+        /// Endgames are small (a few cards and empty cells left, whatever the board size),
+        /// so search them exactly.
+        fn solve(
+            s: &<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::State,
+            budget: u32,
+        ) -> Option<<Self as pallet_eterra_monte_carlo_ai::GameAdapter>::Action> {
+            pallet_eterra_monte_carlo_ai::solver::solve::<Self>(s, budget)
+        }
     }
 }
\ No newline at end of file
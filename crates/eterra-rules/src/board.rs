@@ -0,0 +1,110 @@
+use crate::card::Card;
+use core::ops::{Index, IndexMut};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Largest supported side length; boards are stored at this size and only the
+/// `width x height` corner is in play.
+pub const MAX_BOARD_DIM: usize = 5;
+/// Smallest supported side length (the classic 3x3 table).
+pub const MIN_BOARD_DIM: usize = 3;
+
+/// Playable area of a board.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoardSize {
+    pub width: u8,
+    pub height: u8,
+}
+
+impl BoardSize {
+    pub const fn new(width: u8, height: u8) -> Self {
+        Self { width, height }
+    }
+
+    /// Whether both sides are within `MIN_BOARD_DIM..=MAX_BOARD_DIM`.
+    pub fn is_valid(&self) -> bool {
+        let ok = |d: u8| (MIN_BOARD_DIM..=MAX_BOARD_DIM).contains(&(d as usize));
+        ok(self.width) && ok(self.height)
+    }
+
+    pub fn cells(&self) -> u32 {
+        self.width as u32 * self.height as u32
+    }
+}
+
+impl Default for BoardSize {
+    fn default() -> Self {
+        Self::new(4, 4)
+    }
+}
+
+/// Grid indexed as `board[x][y]`, with `x < width` and `y < height`.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct Board {
+    pub size: BoardSize,
+    pub cells: [[Option<Card>; MAX_BOARD_DIM]; MAX_BOARD_DIM],
+}
+
+impl Board {
+    pub fn new(size: BoardSize) -> Self {
+        Self {
+            size,
+            cells: Default::default(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.size.width as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.size.height as usize
+    }
+
+    pub fn in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width() && y < self.height()
+    }
+
+    /// Columns of the playable area, each `height` cells long.
+    pub fn iter(&self) -> impl Iterator<Item = &[Option<Card>]> {
+        let h = self.height();
+        self.cells[..self.width()].iter().map(move |col| &col[..h])
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.iter().flatten().all(|c| c.is_some())
+    }
+
+    /// Convert a board from the original fixed 4x4 layout.
+    pub fn from_legacy(old: [[Option<Card>; 4]; 4]) -> Self {
+        let mut board = Self::new(BoardSize::new(4, 4));
+        for (x, col) in old.into_iter().enumerate() {
+            for (y, cell) in col.into_iter().enumerate() {
+                board.cells[x][y] = cell;
+            }
+        }
+        board
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new(BoardSize::default())
+    }
+}
+
+impl Index<usize> for Board {
+    type Output = [Option<Card>; MAX_BOARD_DIM];
+
+    fn index(&self, x: usize) -> &Self::Output {
+        &self.cells[x]
+    }
+}
+
+impl IndexMut<usize> for Board {
+    fn index_mut(&mut self, x: usize) -> &mut Self::Output {
+        &mut self.cells[x]
+    }
+}
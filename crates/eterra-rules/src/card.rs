@@ -0,0 +1,60 @@
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Ranks a card edge can take.
+pub const RANKS: core::ops::RangeInclusive<u8> = 1..=9;
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct Card {
+    pub top: u8,
+    pub right: u8,
+    pub bottom: u8,
+    pub left: u8,
+    pub possession: Option<Possession>, // None if not yet assigned
+}
+
+impl Card {
+    pub fn new(top: u8, right: u8, bottom: u8, left: u8) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+            possession: None,
+        }
+    }
+
+    pub fn with_possession(mut self, possession: Possession) -> Self {
+        self.possession = Some(possession);
+        self
+    }
+
+    pub fn get_possession(&self) -> Option<&Possession> {
+        self.possession.as_ref()
+    }
+
+    /// Whether every edge is within [`RANKS`].
+    pub fn has_valid_ranks(&self) -> bool {
+        [self.top, self.right, self.bottom, self.left]
+            .iter()
+            .all(|rank| RANKS.contains(rank))
+    }
+}
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum Possession {
+    PlayerOne,
+    PlayerTwo,
+}
+
+impl Possession {
+    /// Seat 0 plays as `PlayerOne`, anything else as `PlayerTwo`.
+    pub fn of(player_ix: u8) -> Self {
+        match player_ix {
+            0 => Possession::PlayerOne,
+            _ => Possession::PlayerTwo,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Eterra game rules.
+//!
+//! Owns the board, card and move types together with capture resolution and
+//! turn/round progression. Both `pallet-eterra` (on-chain play) and
+//! `eterra-card-ai-adapter` (AI simulation) call into this crate, so the two can
+//! never disagree about the outcome of a move.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod board;
+pub mod card;
+pub mod rules;
+pub mod turn;
+
+#[cfg(test)]
+mod tests;
+
+pub use board::{Board, BoardSize, MAX_BOARD_DIM, MIN_BOARD_DIM};
+pub use card::{Card, Possession, RANKS};
+pub use rules::{elements_from_seed, resolve_captures, Elements, RuleSet, WALL_RANK};
+pub use turn::{advance_turn, place_card, play_card, Action, Move};
+
+/// Largest hand a game can be configured with.
+pub const MAX_HAND_SIZE: usize = 10;
@@ -0,0 +1,218 @@
+use crate::board::{Board, MAX_BOARD_DIM};
+use crate::card::{Card, Possession};
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Rank of the board edge when `same_wall` is enabled (an "A" in classic notation).
+pub const WALL_RANK: u8 = 10;
+
+/// Per-cell edge modifier used by the Elemental rule (-1, 0 or +1).
+pub type Elements = [[i8; MAX_BOARD_DIM]; MAX_BOARD_DIM];
+
+/// Optional capture rules layered on top of the basic "higher edge captures" rule.
+/// All flags off reproduces the original behaviour.
+#[derive(
+    Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Debug, Default,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct RuleSet {
+    /// Two or more neighbours with an edge equal to the placed card's flip.
+    pub same: bool,
+    /// Two or more neighbours whose edge sums with the placed card match flip.
+    pub plus: bool,
+    /// Cards flipped by Same/Plus capture their own weaker neighbours, recursively.
+    pub combo: bool,
+    /// Board edges count as `WALL_RANK` for the Same rule.
+    pub same_wall: bool,
+    /// Each cell adds its `Elements` modifier to the edges of the card placed on it.
+    pub elemental: bool,
+}
+
+const DIRS: [(i8, i8); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Edge of `c` facing direction `(dx, dy)`.
+fn edge(c: &Card, dx: i8, dy: i8) -> u8 {
+    match (dx, dy) {
+        (0, -1) => c.top,
+        (1, 0) => c.right,
+        (0, 1) => c.bottom,
+        _ => c.left,
+    }
+}
+
+/// Edge rank for the basic and combo rules: the cell's elemental modifier applied, or the
+/// raw rank when the Elemental rule is off.
+fn effective_edge(c: &Card, x: usize, y: usize, dx: i8, dy: i8, elements: Option<&Elements>) -> u8 {
+    let rank = edge(c, dx, dy);
+    match elements {
+        Some(elements) => (rank as i16 + elements[x][y] as i16).clamp(1, WALL_RANK as i16) as u8,
+        None => rank,
+    }
+}
+
+fn neighbour(board: &Board, x: usize, y: usize, dx: i8, dy: i8) -> Option<(usize, usize)> {
+    let nx = x as i8 + dx;
+    let ny = y as i8 + dy;
+    if nx < 0 || ny < 0 || !board.in_bounds(nx as usize, ny as usize) {
+        return None;
+    }
+    Some((nx as usize, ny as usize))
+}
+
+/// Flip the card at `(x, y)` to `player_ix` if the opponent owns it, moving one point
+/// across. Returns whether it flipped.
+fn flip(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    x: usize,
+    y: usize,
+    player_ix: u8,
+    flipped: &mut Vec<(u8, u8)>,
+) -> bool {
+    let me = Possession::of(player_ix);
+    let card = match board[x][y].as_mut() {
+        Some(c) => c,
+        None => return false,
+    };
+    match card.possession.as_ref() {
+        Some(p) if *p != me => {}
+        _ => return false,
+    }
+    match me {
+        Possession::PlayerOne => {
+            scores.1 = scores.1.saturating_sub(1);
+            scores.0 = scores.0.saturating_add(1);
+        }
+        Possession::PlayerTwo => {
+            scores.0 = scores.0.saturating_sub(1);
+            scores.1 = scores.1.saturating_add(1);
+        }
+    }
+    card.possession = Some(me);
+    flipped.push((x as u8, y as u8));
+    true
+}
+
+/// Resolve all captures caused by the card just placed at `(x, y)` by `player_ix`.
+///
+/// Same and Plus compare raw ranks; the basic rule and combo chains use ranks with
+/// elemental modifiers applied. Returns the flipped cells in resolution order.
+pub fn resolve_captures(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    x: usize,
+    y: usize,
+    player_ix: u8,
+    rules: &RuleSet,
+    elements: &Elements,
+) -> Vec<(u8, u8)> {
+    let mut flipped = Vec::new();
+    let placed = match board[x][y].clone() {
+        Some(c) => c,
+        None => return flipped,
+    };
+    let elements = rules.elemental.then_some(elements);
+
+    // Same / Plus: gather matches around the placed card before flipping anything
+    let mut same_hits = 0u8;
+    let mut same_cells: Vec<(usize, usize)> = Vec::new();
+    let mut sums: Vec<(u16, usize, usize)> = Vec::new();
+    for &(dx, dy) in DIRS.iter() {
+        let my_rank = edge(&placed, dx, dy);
+        match neighbour(board, x, y, dx, dy) {
+            None => {
+                if rules.same_wall && my_rank == WALL_RANK {
+                    same_hits += 1;
+                }
+            }
+            Some((nx, ny)) => {
+                if let Some(n) = board[nx][ny].as_ref() {
+                    let opp_rank = edge(n, -dx, -dy);
+                    if my_rank == opp_rank {
+                        same_hits += 1;
+                        same_cells.push((nx, ny));
+                    }
+                    sums.push((my_rank as u16 + opp_rank as u16, nx, ny));
+                }
+            }
+        }
+    }
+
+    let mut chain: Vec<(usize, usize)> = Vec::new();
+    if rules.same && same_hits >= 2 {
+        for (nx, ny) in same_cells {
+            if flip(board, scores, nx, ny, player_ix, &mut flipped) {
+                chain.push((nx, ny));
+            }
+        }
+    }
+    if rules.plus {
+        for &(sum, nx, ny) in sums.iter() {
+            if sums.iter().filter(|(s, _, _)| *s == sum).count() >= 2
+                && flip(board, scores, nx, ny, player_ix, &mut flipped)
+            {
+                chain.push((nx, ny));
+            }
+        }
+    }
+
+    // Basic rule: strictly greater captures; ties do not capture
+    for &(dx, dy) in DIRS.iter() {
+        if let Some((nx, ny)) = neighbour(board, x, y, dx, dy) {
+            if let Some(n) = board[nx][ny].as_ref() {
+                let mine = effective_edge(&placed, x, y, dx, dy, elements);
+                let theirs = effective_edge(n, nx, ny, -dx, -dy, elements);
+                if mine > theirs {
+                    flip(board, scores, nx, ny, player_ix, &mut flipped);
+                }
+            }
+        }
+    }
+
+    // Combo: cards taken by Same/Plus attack their neighbours with the basic rule
+    if rules.combo {
+        let mut at = 0;
+        while at < chain.len() {
+            let (cx, cy) = chain[at];
+            at += 1;
+            let card = match board[cx][cy].clone() {
+                Some(c) => c,
+                None => continue,
+            };
+            for &(dx, dy) in DIRS.iter() {
+                if let Some((nx, ny)) = neighbour(board, cx, cy, dx, dy) {
+                    if let Some(n) = board[nx][ny].as_ref() {
+                        let mine = effective_edge(&card, cx, cy, dx, dy, elements);
+                        let theirs = effective_edge(n, nx, ny, -dx, -dy, elements);
+                        if mine > theirs && flip(board, scores, nx, ny, player_ix, &mut flipped)
+                        {
+                            chain.push((nx, ny));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    flipped
+}
+
+/// Derive per-cell elemental modifiers from a seed: roughly 1 in 8 cells is -1,
+/// 1 in 8 is +1, the rest neutral.
+pub fn elements_from_seed(seed: &[u8]) -> Elements {
+    let mut out: Elements = Default::default();
+    if seed.is_empty() {
+        return out;
+    }
+    for x in 0..MAX_BOARD_DIM {
+        for y in 0..MAX_BOARD_DIM {
+            out[x][y] = match seed[(x * MAX_BOARD_DIM + y) % seed.len()] % 8 {
+                0 => -1,
+                1 => 1,
+                _ => 0,
+            };
+        }
+    }
+    out
+}
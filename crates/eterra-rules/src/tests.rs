@@ -0,0 +1,230 @@
+use crate::*;
+
+fn opp(c: Card) -> Option<Card> {
+    Some(c.with_possession(Possession::PlayerTwo))
+}
+
+#[test]
+fn basic_rule_needs_strictly_greater_edge() {
+    let mut board: Board = Default::default();
+    board[1][0] = opp(Card::new(1, 1, 1, 4));
+    board[0][1] = opp(Card::new(5, 1, 1, 1));
+    let mut scores = (5, 5);
+    let flipped = play_card(
+        &mut board,
+        &mut scores,
+        0,
+        0,
+        Card::new(1, 5, 5, 1),
+        0,
+        &RuleSet::default(),
+        &Default::default(),
+    );
+    // right 5 > left 4 flips; bottom 5 vs top 5 is a tie
+    assert_eq!(flipped, vec![(1, 0)]);
+    assert_eq!(scores, (6, 4));
+    assert_eq!(board[0][1].as_ref().unwrap().possession, Some(Possession::PlayerTwo));
+}
+
+#[test]
+fn same_rule_flips_ties_and_combo_chains() {
+    let mut board: Board = Default::default();
+    board[1][0] = opp(Card::new(3, 3, 3, 5));
+    board[0][1] = opp(Card::new(4, 3, 3, 3));
+    board[2][0] = opp(Card::new(1, 1, 1, 1));
+    let rules = RuleSet {
+        same: true,
+        combo: true,
+        ..Default::default()
+    };
+    let mut scores = (5, 5);
+    let flipped = play_card(
+        &mut board,
+        &mut scores,
+        0,
+        0,
+        Card::new(1, 5, 4, 1),
+        0,
+        &rules,
+        &Default::default(),
+    );
+    assert_eq!(flipped, vec![(1, 0), (0, 1), (2, 0)]);
+    assert_eq!(scores, (8, 2));
+}
+
+#[test]
+fn same_needs_two_matches_unless_wall_counts() {
+    let mut board: Board = Default::default();
+    board[1][0] = opp(Card::new(3, 3, 3, 5));
+    let mut rules = RuleSet {
+        same: true,
+        ..Default::default()
+    };
+    let card = Card::new(WALL_RANK, 5, 1, 1);
+
+    let mut b = board.clone();
+    let flipped = play_card(&mut b, &mut (5, 5), 0, 0, card.clone(), 0, &rules, &Default::default());
+    assert!(flipped.is_empty());
+
+    rules.same_wall = true;
+    let flipped = play_card(&mut board, &mut (5, 5), 0, 0, card, 0, &rules, &Default::default());
+    assert_eq!(flipped, vec![(1, 0)]);
+}
+
+#[test]
+fn plus_rule_flips_equal_sums() {
+    let mut board: Board = Default::default();
+    // sums: right 2+6 = 8, bottom 5+3 = 8
+    board[1][0] = opp(Card::new(9, 9, 9, 6));
+    board[0][1] = opp(Card::new(3, 9, 9, 9));
+    let rules = RuleSet {
+        plus: true,
+        ..Default::default()
+    };
+    let flipped = play_card(
+        &mut board,
+        &mut (5, 5),
+        0,
+        0,
+        Card::new(1, 2, 5, 1),
+        0,
+        &rules,
+        &Default::default(),
+    );
+    assert_eq!(flipped, vec![(1, 0), (0, 1)]);
+}
+
+#[test]
+fn elemental_modifiers_only_apply_when_enabled() {
+    let mut elements: Elements = Default::default();
+    elements[0][0] = 1;
+    let mut rules = RuleSet::default();
+
+    let mut board: Board = Default::default();
+    board[1][0] = opp(Card::new(1, 1, 1, 5));
+    let mut b = board.clone();
+    assert!(play_card(&mut b, &mut (5, 5), 0, 0, Card::new(1, 5, 1, 1), 0, &rules, &elements)
+        .is_empty());
+
+    rules.elemental = true;
+    assert_eq!(
+        play_card(&mut board, &mut (5, 5), 0, 0, Card::new(1, 5, 1, 1), 0, &rules, &elements),
+        vec![(1, 0)]
+    );
+}
+
+#[test]
+fn ranks_are_compared_unclamped_without_elemental() {
+    let mut board: Board = Default::default();
+    board[1][0] = opp(Card::new(1, 1, 1, 150));
+    let rules = RuleSet::default();
+    let flipped = play_card(
+        &mut board,
+        &mut (5, 5),
+        0,
+        0,
+        Card::new(1, 200, 1, 1),
+        0,
+        &rules,
+        &Default::default(),
+    );
+    assert_eq!(flipped, vec![(1, 0)]);
+}
+
+#[test]
+fn plus_sums_high_ranks_without_overflow() {
+    let mut board: Board = Default::default();
+    board[1][1] = opp(Card::new(1, 1, 1, 100));
+    board[0][2] = opp(Card::new(150, 1, 1, 1));
+    let rules = RuleSet {
+        plus: true,
+        ..Default::default()
+    };
+    // 200 + 100 and 150 + 150 both make 300
+    let flipped = play_card(
+        &mut board,
+        &mut (5, 5),
+        0,
+        1,
+        Card::new(1, 200, 150, 1),
+        0,
+        &rules,
+        &Default::default(),
+    );
+    assert_eq!(flipped, vec![(1, 1), (0, 2)]);
+}
+
+#[test]
+fn advance_turn_counts_rounds_on_wrap() {
+    let (mut turn, mut round) = (0u8, 0u8);
+    advance_turn(&mut turn, &mut round);
+    assert_eq!((turn, round), (1, 0));
+    advance_turn(&mut turn, &mut round);
+    assert_eq!((turn, round), (0, 1));
+}
+
+#[test]
+fn captures_respect_board_size() {
+    // On 3x3, (2,0) is the right edge: nothing beyond it, and Same Wall sees a wall there.
+    let mut board = Board::new(BoardSize::new(3, 3));
+    board[2][1] = opp(Card::new(5, 1, 1, 1));
+    board[1][0] = opp(Card::new(1, 4, 1, 1));
+    let rules = RuleSet {
+        same: true,
+        same_wall: true,
+        ..Default::default()
+    };
+    let flipped = play_card(
+        &mut board,
+        &mut (5, 5),
+        2,
+        0,
+        Card::new(WALL_RANK, WALL_RANK, 5, 1),
+        0,
+        &rules,
+        &Default::default(),
+    );
+    // top and right walls plus the matching bottom edge trigger Same
+    assert_eq!(flipped, vec![(2, 1)]);
+
+    let mut big = Board::new(BoardSize::new(5, 5));
+    big[4][3] = opp(Card::new(1, 1, 1, 1));
+    let flipped = play_card(
+        &mut big,
+        &mut (5, 5),
+        4,
+        4,
+        Card::new(2, 2, 2, 2),
+        0,
+        &RuleSet::default(),
+        &Default::default(),
+    );
+    assert_eq!(flipped, vec![(4, 3)]);
+}
+
+#[test]
+fn board_iter_and_fullness_follow_playable_area() {
+    let mut board = Board::new(BoardSize::new(3, 4));
+    assert_eq!(board.iter().count(), 3);
+    assert_eq!(board.iter().flatten().count(), 12);
+    for x in 0..3 {
+        for y in 0..4 {
+            assert!(!board.is_full());
+            board[x][y] = Some(Card::new(1, 1, 1, 1));
+        }
+    }
+    assert!(board.is_full());
+    assert!(!board.in_bounds(3, 0));
+    assert!(!BoardSize::new(2, 4).is_valid());
+    assert!(!BoardSize::new(4, 6).is_valid());
+    assert!(BoardSize::new(3, 5).is_valid());
+}
+
+#[test]
+fn legacy_board_keeps_cells() {
+    let mut old: [[Option<Card>; 4]; 4] = Default::default();
+    old[3][2] = Some(Card::new(1, 2, 3, 4));
+    let board = Board::from_legacy(old);
+    assert_eq!(board.size, BoardSize::new(4, 4));
+    assert_eq!(board[3][2], Some(Card::new(1, 2, 3, 4)));
+}
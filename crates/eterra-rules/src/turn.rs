@@ -0,0 +1,51 @@
+use crate::board::Board;
+use crate::card::{Card, Possession};
+use crate::rules::{resolve_captures, Elements, RuleSet};
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Place an arbitrary card at `(place_index_x, place_index_y)`.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Clone, Debug)]
+pub struct Move {
+    pub place_index_x: u8,
+    pub place_index_y: u8,
+    pub place_card: Card,
+}
+
+/// Play a card from hand at (x,y)
+#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
+pub struct Action {
+    pub hand_index: u8, // 0..hand size
+    pub x: u8,          // 0..width
+    pub y: u8,          // 0..height
+}
+
+/// Put `card` on the board at `(x, y)` owned by `player_ix`.
+pub fn place_card(board: &mut Board, x: usize, y: usize, card: Card, player_ix: u8) {
+    board[x][y] = Some(card.with_possession(Possession::of(player_ix)));
+}
+
+/// Place `card` for `player_ix` and resolve captures. Returns the flipped cells.
+#[allow(clippy::too_many_arguments)]
+pub fn play_card(
+    board: &mut Board,
+    scores: &mut (u8, u8),
+    x: usize,
+    y: usize,
+    card: Card,
+    player_ix: u8,
+    rules: &RuleSet,
+    elements: &Elements,
+) -> Vec<(u8, u8)> {
+    place_card(board, x, y, card, player_ix);
+    resolve_captures(board, scores, x, y, player_ix, rules, elements)
+}
+
+/// Hand the turn to the other player; a round completes when seat 0 is up again.
+pub fn advance_turn(player_turn: &mut u8, round: &mut u8) {
+    *player_turn = (*player_turn + 1) % 2;
+    if *player_turn == 0 {
+        *round = round.saturating_add(1);
+    }
+}
@@ -163,7 +163,11 @@ fn testnet_genesis(
             "payoutAmount": payout_amount,
         },
         "eterraGameAuthority": {
-            "initialServers": initial_servers
+            "initialServers": initial_servers.clone()
+        },
+        // The game servers also run the offchain AI and sign its PvE moves.
+        "eterraMonteCarloAi": {
+            "initialSigners": initial_servers
         }
     })
 }
@@ -0,0 +1,116 @@
+//! Benchmarking setup for pallet-eterra-daily-slots
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as EterraDailySlots;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+/// `entries` weight entries for one reel. Only the last entry carries weight, so a spin walks
+/// the whole list and always lands on the ticket symbol.
+fn reel_entries(entries: u32) -> Vec<(u32, u32)> {
+    (0..entries)
+        .map(|i| if i + 1 == entries { (7, 1) } else { (i, 0) })
+        .collect()
+}
+
+/// Every reel at `MaxWeightEntries`, each weighted onto the ticket symbol.
+fn set_worst_case_reels<T: Config>() {
+    for reel in 0..T::MaxSlotLength::get() {
+        let weights: BoundedVec<_, T::MaxWeightEntries> = reel_entries(T::MaxWeightEntries::get())
+            .try_into()
+            .expect("exactly MaxWeightEntries entries; qed");
+        ReelWeights::<T>::insert(reel, weights);
+    }
+}
+
+/// A full roll history for `who`, so recording a spin has to rotate it.
+fn fill_history<T: Config>(who: &T::AccountId) {
+    let history: BoundedVec<_, T::MaxRollHistoryLength> = (0..T::MaxRollHistoryLength::get())
+        .map(|_| RollResult::<T> {
+            timestamp: 0,
+            result: vec![0u32; T::MaxSlotLength::get() as usize]
+                .try_into()
+                .expect("exactly MaxSlotLength symbols; qed"),
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("exactly MaxRollHistoryLength entries; qed");
+    RollHistory::<T>::insert(who, history);
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// Every reel at `MaxWeightEntries`, checked before the roll is queued.
+    #[benchmark]
+    fn roll() {
+        let caller: T::AccountId = whitelisted_caller();
+        set_worst_case_reels::<T>();
+
+        #[extrinsic_call]
+        roll(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(PendingRolls::<T>::get().len(), 1);
+    }
+
+    /// `n` rolls from different players made in an earlier block, each a winning spin over
+    /// full reels that rotates a full history.
+    #[benchmark]
+    fn resolve_rolls(n: Linear<0, { <PendingRollLimit as Get<u32>>::get() }>) {
+        set_worst_case_reels::<T>();
+        let now = frame_system::Pallet::<T>::block_number();
+        for i in 0..n {
+            let player: T::AccountId = account("player", i, 0);
+            fill_history::<T>(&player);
+            PendingRolls::<T>::try_append(PendingRoll {
+                player,
+                requested_at: now,
+                spin: 0,
+                timestamp: 0,
+            })
+            .expect("at most PendingRollLimit rolls; qed");
+        }
+        let drawn = now + 1u32.into();
+
+        #[block]
+        {
+            Pallet::<T>::resolve_rolls(drawn);
+        }
+
+        assert!(PendingRolls::<T>::get().is_empty());
+        assert_eq!(TotalTickets::<T>::get(), n * T::MaxSlotLength::get());
+    }
+
+    #[benchmark]
+    fn set_reel_weights(w: Linear<1, { T::MaxWeightEntries::get() }>) {
+        #[extrinsic_call]
+        set_reel_weights(RawOrigin::Root, 0, reel_entries(w));
+
+        assert_eq!(ReelWeights::<T>::get(0).map(|v| v.len() as u32), Some(w));
+    }
+
+    #[benchmark]
+    fn set_all_reel_weights(
+        r: Linear<1, { T::MaxSlotLength::get() }>,
+        w: Linear<1, { T::MaxWeightEntries::get() }>,
+    ) {
+        let all_weights = (0..r).map(|reel| (reel, reel_entries(w))).collect();
+
+        #[extrinsic_call]
+        set_all_reel_weights(RawOrigin::Root, all_weights);
+
+        assert_eq!(
+            ReelWeights::<T>::get(r - 1).map(|v| v.len() as u32),
+            Some(w)
+        );
+    }
+
+    impl_benchmark_test_suite!(
+        EterraDailySlots,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
@@ -4,10 +4,9 @@
 
 use frame_support::{
     pallet_prelude::*,
-    traits::{Currency, UnixTime},
+    traits::{Currency, Randomness, UnixTime},
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::traits::Hash;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -26,6 +25,9 @@ const BLOCKS_PER_WINDOW: u64 = 3_600;
 pub mod pallet {
     use super::*;
 
+    // Max number of rolls waiting for their reels to be spun
+    pub type PendingRollLimit = ConstU32<256>;
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
@@ -60,6 +62,13 @@ pub mod pallet {
         /// Number of entries per reel
         #[pallet::constant]
         type MaxWeightEntries: Get<u32>;
+
+        /// Source of the randomness reels and weekly drawings are rolled from. Both are decided
+        /// in the first block whose randomness is later than the roll or drawing call.
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     // ─── STORAGE ────────────────────────────────────────────────────────────────
@@ -71,6 +80,20 @@ pub mod pallet {
         pub result: BoundedVec<u32, T::MaxSlotLength>,
     }
 
+    /// A roll whose reels are spun once randomness from a later block is in.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PendingRoll<T: Config> {
+        pub player: T::AccountId,
+        /// Block the roll was made in.
+        pub requested_at: BlockNumberFor<T>,
+        /// How many rolls the player had already made in the window, so that two rolls in one
+        /// block spin differently.
+        pub spin: u32,
+        /// Time the roll was made, kept for the history entry.
+        pub timestamp: u64,
+    }
+
     /// (window_index, count_in_window)
     #[pallet::storage]
     #[pallet::getter(fn rolls_this_window_for)]
@@ -136,11 +159,26 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn pending_rolls)]
+    /// Rolls still waiting for their reels to be spun, oldest first.
+    pub type PendingRolls<T: Config> =
+        StorageValue<_, BoundedVec<PendingRoll<T>, PendingRollLimit>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn drawing_requested_at)]
+    /// Block the next weekly drawing was called in, if one is waiting to be drawn.
+    pub type DrawingRequestedAt<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
     // ─── EVENTS & ERRORS ───────────────────────────────────────────────────────
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
+        /// `player` rolled; the reels are spun in a later block.
+        RollRequested {
+            player: T::AccountId,
+        },
         SlotRolled {
             player: T::AccountId,
             result: Vec<u32>,
@@ -161,15 +199,18 @@ pub mod pallet {
         ExceedRollsPerRound,
         InvalidConfiguration,
         NoTicketsAvailable,
+        /// Too many rolls are waiting to be spun; try again next block.
+        TooManyPendingRolls,
     }
 
     // ─── DISPATCHABLE CALLS ───────────────────────────────────────────────────
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Roll the slot machine for the caller, producing a set of symbols.
+        /// Roll the slot machine for the caller. The symbols are spun in a later block, from
+        /// randomness nobody can know while the roll is being signed.
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::roll())]
         pub fn roll(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -190,9 +231,122 @@ pub mod pallet {
             let used = if stored_win == window_index { used } else { 0 };
             ensure!(used < max_rolls, Error::<T>::ExceedRollsPerRound);
 
-            // Keep `now_secs` for entropy and history timestamps:
+            // Keep `now_secs` for the history timestamp:
             let now_secs = T::TimeProvider::now().as_secs();
 
+            // Every reel needs usable weights before the roll is taken
+            for reel_index in 0..slot_len {
+                let weights =
+                    ReelWeights::<T>::get(reel_index).ok_or(Error::<T>::InvalidConfiguration)?;
+                let total_weight = weights.iter().map(|(_, w)| *w).sum::<u32>();
+                ensure!(total_weight > 0, Error::<T>::InvalidConfiguration);
+            }
+
+            // ─── QUEUE THE SPIN ─────────────────
+            // The reels are spun from randomness that only exists after this block
+            PendingRolls::<T>::try_append(PendingRoll {
+                player: who.clone(),
+                requested_at: frame_system::Pallet::<T>::block_number(),
+                spin: used,
+                timestamp: now_secs,
+            })
+            .map_err(|_| Error::<T>::TooManyPendingRolls)?;
+
+            // ─── UPDATE STATE ───────────────────
+            // bump that user’s count for *this* window
+            RollsThisWindow::<T>::insert(&who, (window_index, used + 1));
+            LastRollTime::<T>::insert(&who, now_secs);
+
+            Self::deposit_event(Event::RollRequested { player: who });
+
+            Ok(())
+        }
+
+        /// Set the weights for one reel (indexed by `reel`).
+        /// To bias results, ensure all reels (from 0 to MaxSlotLength - 1) are updated.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_reel_weights(weights.len() as u32))]
+        pub fn set_reel_weights(
+            origin: OriginFor<T>,
+            reel: u32,
+            weights: Vec<(u32, u32)>,
+        ) -> DispatchResult {
+            ensure_root(origin)?; // or ensure_signed(origin)? with checks
+
+            Self::update_reel_weights(reel, weights)?;
+
+            Ok(())
+        }
+
+        /// Allows a root origin to update multiple reels' weights in one call.
+        #[pallet::call_index(2)]
+        #[pallet::weight({
+            let reels = all_weights.len() as u32;
+            let widest = all_weights.iter().map(|(_, w)| w.len() as u32).max().unwrap_or(0);
+            T::WeightInfo::set_all_reel_weights(reels, widest)
+        })]
+        pub fn set_all_reel_weights(
+            origin: OriginFor<T>,
+            all_weights: Vec<(u32, Vec<(u32, u32)>)>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            for (reel, weights) in all_weights {
+                Self::update_reel_weights(reel, weights)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    // ─── INTERNAL ───────────────────────────────────────────────────────────────
+
+    impl<T: Config> Pallet<T> {
+        /// Internal helper to update reel weights, converting and inserting into storage.
+        fn update_reel_weights(reel: u32, weights: Vec<(u32, u32)>) -> Result<(), Error<T>> {
+            // Reject empty weight lists
+            if weights.is_empty() {
+                return Err(Error::<T>::InvalidConfiguration);
+            }
+
+            // Clone weights for logging after move into BoundedVec
+            let weights_for_log = weights.clone();
+            let bounded: BoundedVec<_, T::MaxWeightEntries> = weights
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidConfiguration)?;
+
+            ReelWeights::<T>::insert(reel, bounded);
+            info!(
+                "[daily_slots] Set weights for reel {}: {:?}",
+                reel, weights_for_log
+            );
+            Ok(())
+        }
+
+        /// Spin every pending roll made before `drawn`, the block the randomness source was last
+        /// refreshed in. Returns how many rolls were spun.
+        pub fn resolve_rolls(drawn: BlockNumberFor<T>) -> u32 {
+            let mut resolved = 0;
+            PendingRolls::<T>::mutate(|pending| {
+                pending.retain(|roll| {
+                    if roll.requested_at >= drawn {
+                        return true;
+                    }
+                    if let Err(e) = Self::spin(roll) {
+                        log::warn!("(eterra-daily-slots) dropped roll: {:?}", e);
+                    }
+                    resolved += 1;
+                    false
+                });
+            });
+            resolved
+        }
+
+        /// Spin the reels for `roll`, then award its tickets and payout and record it.
+        fn spin(roll: &PendingRoll<T>) -> Result<(), Error<T>> {
+            let who = &roll.player;
+            let slot_len = T::MaxSlotLength::get();
+
             // ─── DO THE SLOTS ───────────────────
             let mut result = Vec::with_capacity(slot_len as usize);
             for reel_index in 0..slot_len {
@@ -205,14 +359,14 @@ pub mod pallet {
                 );
 
                 // Create unique input per reel
-                let entropy = (
-                    now_secs,
-                    &who,
+                let subject = (
+                    b"daily-slots/reel",
+                    who,
+                    roll.requested_at,
+                    roll.spin,
                     reel_index,
-                    frame_system::Pallet::<T>::block_number(),
-                    window_index,
                 );
-                let hash = T::Hashing::hash_of(&entropy);
+                let (hash, _) = T::Randomness::random(&subject.encode());
 
                 // Weighted selection logic
                 let total_weight = weights.iter().map(|(_, w)| *w).sum::<u32>();
@@ -240,16 +394,11 @@ pub mod pallet {
                 result.push(chosen_symbol);
             }
 
-            // ─── UPDATE STATE ───────────────────
-            // bump that user’s count for *this* window
-            RollsThisWindow::<T>::insert(&who, (window_index, used + 1));
-            LastRollTime::<T>::insert(&who, now_secs);
-
             // ─── AWARD TICKETS ──────────────────
             let ticket_symbol = 7u32;
             let tickets = result.iter().filter(|&&v| v == ticket_symbol).count() as u32;
             if tickets > 0 {
-                TicketsPerUser::<T>::mutate(&who, |t| *t = t.saturating_add(tickets));
+                TicketsPerUser::<T>::mutate(who, |t| *t = t.saturating_add(tickets));
                 TotalTickets::<T>::mutate(|t| *t = t.saturating_add(tickets));
             }
 
@@ -257,7 +406,7 @@ pub mod pallet {
             if Self::is_win(&result) {
                 let amt = T::RewardPerWin::get();
                 // Mint to the winner (inflationary faucet-style)
-                T::Currency::deposit_creating(&who, amt);
+                T::Currency::deposit_creating(who, amt);
                 Self::deposit_event(Event::WinRewarded {
                     player: who.clone(),
                     amount: amt,
@@ -271,16 +420,15 @@ pub mod pallet {
 
             // Save the roll result
             let bounded_result: BoundedVec<_, T::MaxSlotLength> = result
-                .clone()
                 .try_into()
                 .map_err(|_| Error::<T>::InvalidConfiguration)?;
 
             let roll_entry = RollResult::<T> {
-                timestamp: now_secs,
+                timestamp: roll.timestamp,
                 result: bounded_result,
             };
 
-            RollHistory::<T>::mutate(&who, |history| {
+            RollHistory::<T>::mutate(who, |history| {
                 if history.len() as u32 >= T::MaxRollHistoryLength::get() {
                     history.remove(0);
                 }
@@ -290,70 +438,14 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Set the weights for one reel (indexed by `reel`).
-        /// To bias results, ensure all reels (from 0 to MaxSlotLength - 1) are updated.
-        #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
-        pub fn set_reel_weights(
-            origin: OriginFor<T>,
-            reel: u32,
-            weights: Vec<(u32, u32)>,
-        ) -> DispatchResult {
-            ensure_root(origin)?; // or ensure_signed(origin)? with checks
-
-            Self::update_reel_weights(reel, weights)?;
-
-            Ok(())
-        }
-
-        /// Allows a root origin to update multiple reels' weights in one call.
-        #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
-        pub fn set_all_reel_weights(
-            origin: OriginFor<T>,
-            all_weights: Vec<(u32, Vec<(u32, u32)>)>,
-        ) -> DispatchResult {
-            ensure_root(origin)?;
-
-            for (reel, weights) in all_weights {
-                Self::update_reel_weights(reel, weights)?;
-            }
-
-            Ok(())
-        }
-    }
-
-    // ─── INTERNAL ───────────────────────────────────────────────────────────────
-
-    impl<T: Config> Pallet<T> {
-        /// Internal helper to update reel weights, converting and inserting into storage.
-        fn update_reel_weights(reel: u32, weights: Vec<(u32, u32)>) -> Result<(), Error<T>> {
-            // Reject empty weight lists
-            if weights.is_empty() {
-                return Err(Error::<T>::InvalidConfiguration);
-            }
-
-            // Clone weights for logging after move into BoundedVec
-            let weights_for_log = weights.clone();
-            let bounded: BoundedVec<_, T::MaxWeightEntries> = weights
-                .try_into()
-                .map_err(|_| Error::<T>::InvalidConfiguration)?;
-
-            ReelWeights::<T>::insert(reel, bounded);
-            info!(
-                "[daily_slots] Set weights for reel {}: {:?}",
-                reel, weights_for_log
-            );
-            Ok(())
-        }
-
-        fn perform_weekly_drawing() -> Result<(), Error<T>> {
+        /// Draw the weekly winner for the drawing called in block `requested_at`.
+        fn perform_weekly_drawing(requested_at: BlockNumberFor<T>) -> Result<(), Error<T>> {
             let total = TotalTickets::<T>::get();
             if total == 0 {
                 return Err(Error::<T>::NoTicketsAvailable);
             }
             let now = T::TimeProvider::now().as_secs();
-            let seed = T::Hashing::hash_of(&(now, frame_system::Pallet::<T>::block_number()));
+            let (seed, _) = T::Randomness::random(&(b"daily-slots/draw", requested_at).encode());
             let pick = (seed.as_ref()[0] as u32) % total;
 
             let mut cum = 0;
@@ -390,9 +482,9 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
             // Only on the first block
-            if _n == 1u32.into() {
+            if n == 1u32.into() {
                 let default_weights = vec![
                     (0, vec![(0, 5), (1, 3), (2, 2)]),
                     (1, vec![(0, 1), (1, 1), (2, 8)]),
@@ -408,6 +500,22 @@ pub mod pallet {
                 }
             }
 
+            // Spin the rolls made before the randomness was last refreshed
+            let (_, drawn) = T::Randomness::random(b"daily-slots");
+            let weight = Weight::from_parts(10_000, 0)
+                .saturating_add(T::WeightInfo::resolve_rolls(Self::resolve_rolls(drawn)));
+
+            // A drawing called in an earlier block is drawn once later randomness is in
+            if let Some(requested_at) = DrawingRequestedAt::<T>::get() {
+                if requested_at < drawn {
+                    DrawingRequestedAt::<T>::kill();
+                    if let Err(e) = Self::perform_weekly_drawing(requested_at) {
+                        log::warn!("(eterra-daily-slots) weekly drawing failed: {:?}", e);
+                    }
+                }
+                return weight;
+            }
+
             // Grab “now” once:
             let now_secs = T::TimeProvider::now().as_secs();
 
@@ -426,27 +534,29 @@ pub mod pallet {
             let is_after_6pm = secs_today >= EVENING_THRESHOLD;
             if !(is_sunday && is_after_6pm) {
                 // bail out early, no drawing
-                return Weight::from_parts(10_000, 0);
+                return weight;
             }
 
             // If we’ve already done a drawing in the last 24 h, bail again:
             let last = LastDrawingTime::<T>::get();
             if now_secs.saturating_sub(last) < 24 * 3600 {
-                return Weight::from_parts(10_000, 0);
+                return weight;
             }
 
-            // Now we really do a weekly drawing
-            if let Err(e) = Self::perform_weekly_drawing() {
-                log::warn!("(eterra-daily-slots) weekly drawing failed: {:?}", e);
-            }
-
-            Weight::from_parts(10_000, 0)
+            // Now we really call a weekly drawing; the winner is drawn in a later block
+            DrawingRequestedAt::<T>::put(n);
+            weight
         }
     }
 }
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
 /// Mock & tests live here
 #[cfg(test)]
 mod mock;
@@ -6,7 +6,7 @@ use crate::Config;
 use frame_support::BoundedVec;
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{ConstU128, ConstU16, ConstU32, Everything, UnixTime},
+    traits::{ConstU128, ConstU16, ConstU32, ConstU64, Everything, UnixTime},
 };
 use frame_system as system;
 use frame_system::RawOrigin;
@@ -55,6 +55,7 @@ construct_runtime!(
         System: system,
         Balances: pallet_balances,
         EterraDailySlots: pallet_eterra_daily_slots,
+        EterraRandomness: pallet_eterra_randomness,
     }
 );
 
@@ -157,6 +158,14 @@ impl pallet_eterra_daily_slots::Config for Test {
     type MaxWeightEntries = MaxWeightEntries;
     type Currency = Balances;
     type RewardPerWin = ConstU128<1_000>;
+    type Randomness = EterraRandomness;
+    type WeightInfo = ();
+}
+
+impl pallet_eterra_randomness::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RevealWindow = ConstU64<10>;
+    type WeightInfo = ();
 }
 
 // =====================================================
@@ -7,8 +7,8 @@ use crate::ReelWeights;
 use crate::RollsThisBlock;
 use crate::RollsThisWindow;
 use crate::{
-    Config, Error, Event, LastDrawingTime, LastRollTime, Pallet, RollHistory, TicketsPerUser,
-    TotalTickets,
+    Config, DrawingRequestedAt, Error, Event, LastDrawingTime, LastRollTime, Pallet, PendingRolls,
+    RollHistory, TicketsPerUser, TotalTickets,
 };
 use frame_support::traits::Hooks;
 use frame_support::BoundedVec;
@@ -33,6 +33,20 @@ fn roll_n_times<T: crate::pallet::Config>(who: &T::AccountId, n: u32) {
     }
 }
 
+/// Spin every pending roll as the next block would, without moving the block number.
+fn resolve_rolls() {
+    Pallet::<TestRuntime>::resolve_rolls(frame_system::Pallet::<TestRuntime>::block_number() + 1);
+}
+
+/// Run the hooks of every block in `blocks`. The randomness pallet runs after this one, as in the
+/// runtime, so a request is resolved two blocks after it was made.
+fn run_blocks(blocks: std::ops::RangeInclusive<u64>) {
+    for n in blocks {
+        frame_system::Pallet::<TestRuntime>::set_block_number(n);
+        <AllPalletsWithSystem as frame_support::traits::OnInitialize<u64>>::on_initialize(n);
+    }
+}
+
 // 6h window at 6s/block → 3_600 blocks
 const BLOCKS_PER_WINDOW: u64 = 3_600;
 
@@ -133,7 +147,14 @@ fn test_slot_rolled_event_emitted() {
 
         let evts = frame_system::Pallet::<TestRuntime>::events();
         assert_eq!(evts.len(), 1);
-        match &evts[0].event {
+        assert!(matches!(
+            evts[0].event,
+            RuntimeEvent::EterraDailySlots(Event::RollRequested { player: 1 })
+        ));
+
+        resolve_rolls();
+        let evts = frame_system::Pallet::<TestRuntime>::events();
+        match &evts.last().expect("the spin emits events").event {
             RuntimeEvent::EterraDailySlots(Event::SlotRolled { player, result }) => {
                 assert_eq!(*player, 1);
                 assert_eq!(result.len(), 3);
@@ -151,6 +172,7 @@ fn test_slot_rolled_event_emitted_correctly() {
         assert_ok!(Pallet::<TestRuntime>::roll(
             frame_system::RawOrigin::Signed(1).into()
         ));
+        resolve_rolls();
 
         let found = frame_system::Pallet::<TestRuntime>::events()
             .iter()
@@ -173,6 +195,7 @@ fn test_ticket_awarded_on_special_symbol() {
         assert_ok!(Pallet::<TestRuntime>::roll(
             frame_system::RawOrigin::Signed(1).into()
         ));
+        resolve_rolls();
         assert_eq!(TicketsPerUser::<TestRuntime>::get(1), 0);
     });
 }
@@ -210,7 +233,7 @@ fn test_no_weekly_drawing_with_no_tickets() {
         frame_system::Pallet::<TestRuntime>::set_block_number(1001);
         frame_system::Pallet::<TestRuntime>::reset_events();
 
-        Pallet::<TestRuntime>::on_initialize(1001);
+        run_blocks(1001..=1003);
 
         assert_eq!(TotalTickets::<TestRuntime>::get(), 0);
         let fired = frame_system::Pallet::<TestRuntime>::events()
@@ -235,7 +258,7 @@ fn test_weekly_drawing_selects_winner() {
         frame_system::Pallet::<TestRuntime>::set_block_number(1001);
         frame_system::Pallet::<TestRuntime>::reset_events();
 
-        Pallet::<TestRuntime>::on_initialize(1001);
+        run_blocks(1001..=1003);
 
         assert_eq!(TotalTickets::<TestRuntime>::get(), 0);
         let fired = frame_system::Pallet::<TestRuntime>::events()
@@ -260,8 +283,7 @@ fn test_weekly_drawing_only_once_per_week() {
         frame_system::Pallet::<TestRuntime>::set_block_number(1001);
         frame_system::Pallet::<TestRuntime>::reset_events();
 
-        Pallet::<TestRuntime>::on_initialize(1001);
-        Pallet::<TestRuntime>::on_initialize(1002);
+        run_blocks(1001..=1005);
 
         let count = frame_system::Pallet::<TestRuntime>::events()
             .iter()
@@ -286,7 +308,7 @@ fn test_weekly_winner_event_emitted_correctly() {
         frame_system::Pallet::<TestRuntime>::set_block_number(1001);
         frame_system::Pallet::<TestRuntime>::reset_events();
 
-        Pallet::<TestRuntime>::on_initialize(1001);
+        run_blocks(1001..=1003);
 
         let found = frame_system::Pallet::<TestRuntime>::events()
             .iter()
@@ -307,6 +329,8 @@ fn roll_creates_history_entry() {
         assert_eq!(RollHistory::<Test>::get(user).len(), 0);
 
         assert_ok!(Pallet::<Test>::roll(RawOrigin::Signed(user).into()));
+        assert_eq!(RollHistory::<Test>::get(user).len(), 0);
+        resolve_rolls();
         let history = RollHistory::<Test>::get(user);
         assert_eq!(history.len(), 1);
         let entry = &history[0];
@@ -326,6 +350,7 @@ fn roll_history_respects_max_length() {
 
         // Roll up to allowed limit
         roll_n_times::<Test>(&user, roll_limit);
+        resolve_rolls();
 
         let history = RollHistory::<Test>::get(user);
         assert!(history.len() as u32 <= roll_limit);
@@ -346,6 +371,7 @@ fn test_set_reel_weights_and_roll_with_weights() {
         assert_eq!(crate::ReelWeights::<Test>::get(0).unwrap(), expected);
         // Perform a roll and ensure the result includes the weighted symbol
         assert_ok!(Pallet::<Test>::roll(RawOrigin::Signed(1).into()));
+        resolve_rolls();
         let history = RollHistory::<Test>::get(1);
         assert!(!history.is_empty());
     });
@@ -389,6 +415,7 @@ fn test_outcomes_follow_weights_distribution() {
                 let b = frame_system::Pallet::<Test>::block_number();
                 frame_system::Pallet::<Test>::set_block_number(b + 1);
                 assert_ok!(Pallet::<Test>::roll(RawOrigin::Signed(user).into()));
+                resolve_rolls();
 
                 let result = RollHistory::<Test>::get(user)
                     .last()
@@ -500,6 +527,7 @@ fn test_ticket_counter_does_not_overflow() {
         }
 
         assert_ok!(Pallet::<Test>::roll(RawOrigin::Signed(1).into()));
+        resolve_rolls();
         assert_eq!(TicketsPerUser::<Test>::get(1), u32::MAX);
         assert_eq!(TotalTickets::<Test>::get(), u32::MAX);
     });
@@ -610,3 +638,47 @@ fn test_advancing_less_than_window_does_not_reset() {
         assert_noop!(fourth, Error::<TestRuntime>::ExceedRollsPerRound);
     });
 }
+
+#[test]
+fn rolls_are_spun_in_a_later_block() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<TestRuntime>::set_block_number(1);
+        assert_ok!(Pallet::<TestRuntime>::roll(RawOrigin::Signed(1).into()));
+
+        // Nothing about the spin is known in the block the roll was made in
+        assert_eq!(PendingRolls::<TestRuntime>::get().len(), 1);
+        assert!(RollHistory::<TestRuntime>::get(1).is_empty());
+
+        // The randomness pallet runs after this one, so the spin lands two blocks later
+        run_blocks(2..=2);
+        assert_eq!(PendingRolls::<TestRuntime>::get().len(), 1);
+        run_blocks(3..=3);
+        assert!(PendingRolls::<TestRuntime>::get().is_empty());
+        assert_eq!(RollHistory::<TestRuntime>::get(1).len(), 1);
+        assert!(frame_system::Pallet::<TestRuntime>::events()
+            .iter()
+            .any(|r| matches!(
+                r.event,
+                RuntimeEvent::EterraDailySlots(Event::SlotRolled { player: 1, .. })
+            )));
+    });
+}
+
+#[test]
+fn weekly_drawing_is_made_in_a_later_block() {
+    new_test_ext().execute_with(|| {
+        set_mock_time_to_sunday_6pm();
+        TicketsPerUser::<TestRuntime>::insert(1, 5);
+        TotalTickets::<TestRuntime>::put(5);
+        LastDrawingTime::<TestRuntime>::put(0);
+
+        // The drawing is only called in the first block of the evening
+        run_blocks(1001..=1002);
+        assert_eq!(DrawingRequestedAt::<TestRuntime>::get(), Some(1001));
+        assert_eq!(TotalTickets::<TestRuntime>::get(), 5);
+
+        run_blocks(1003..=1003);
+        assert_eq!(DrawingRequestedAt::<TestRuntime>::get(), None);
+        assert_eq!(TotalTickets::<TestRuntime>::get(), 0);
+    });
+}
@@ -0,0 +1,115 @@
+//! Weights for pallet_eterra_daily_slots
+//!
+//! These values are estimates, not benchmark results. They were worked out by hand from
+//! the storage each call touches, sized for the worst cases set up in `benchmarking.rs`.
+//! Replace them with measured weights from reference hardware before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_eterra_daily_slots.
+pub trait WeightInfo {
+	fn roll() -> Weight;
+	fn set_reel_weights(w: u32) -> Weight;
+	fn set_all_reel_weights(r: u32, w: u32) -> Weight;
+	fn resolve_rolls(n: u32) -> Weight;
+}
+
+/// Estimated weights for pallet_eterra_daily_slots.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: EterraDailySlots RollsThisWindow (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: EterraDailySlots ReelWeights (r:3 w:0)
+	/// Storage: EterraDailySlots PendingRolls (r:1 w:1)
+	/// Storage: EterraDailySlots LastRollTime (r:0 w:1)
+	fn roll() -> Weight {
+		Weight::from_parts(38_000_000, 8871)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: EterraDailySlots ReelWeights (r:0 w:1)
+	fn set_reel_weights(w: u32) -> Weight {
+		// The range of component `w` is `[1, 10]`.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(310_000, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraDailySlots ReelWeights (r:0 w:3)
+	fn set_all_reel_weights(r: u32, w: u32) -> Weight {
+		// The range of component `r` is `[1, 3]`.
+		// The range of component `w` is `[1, 10]`.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(Weight::from_parts(5_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(890_000, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	/// Storage: EterraRandomness Seed (r:1 w:0)
+	/// Storage: EterraRandomness SeededAt (r:1 w:0)
+	/// Storage: EterraDailySlots PendingRolls (r:1 w:1)
+	/// Storage: EterraDailySlots ReelWeights (r:768 w:0)
+	/// Storage: EterraDailySlots TicketsPerUser (r:256 w:256)
+	/// Storage: EterraDailySlots TotalTickets (r:256 w:256)
+	/// Storage: System Account (r:256 w:256)
+	/// Storage: EterraDailySlots RollHistory (r:256 w:256)
+	fn resolve_rolls(n: u32) -> Weight {
+		// The range of component `n` is `[0, 256]`.
+		Weight::from_parts(7_000_000, 4041)
+			.saturating_add(Weight::from_parts(72_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: EterraDailySlots RollsThisWindow (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: EterraDailySlots ReelWeights (r:3 w:0)
+	/// Storage: EterraDailySlots PendingRolls (r:1 w:1)
+	/// Storage: EterraDailySlots LastRollTime (r:0 w:1)
+	fn roll() -> Weight {
+		Weight::from_parts(38_000_000, 8871)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: EterraDailySlots ReelWeights (r:0 w:1)
+	fn set_reel_weights(w: u32) -> Weight {
+		// The range of component `w` is `[1, 10]`.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(310_000, 0).saturating_mul(w.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraDailySlots ReelWeights (r:0 w:3)
+	fn set_all_reel_weights(r: u32, w: u32) -> Weight {
+		// The range of component `r` is `[1, 3]`.
+		// The range of component `w` is `[1, 10]`.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(Weight::from_parts(5_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(890_000, 0).saturating_mul(w.into()))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	/// Storage: EterraRandomness Seed (r:1 w:0)
+	/// Storage: EterraRandomness SeededAt (r:1 w:0)
+	/// Storage: EterraDailySlots PendingRolls (r:1 w:1)
+	/// Storage: EterraDailySlots ReelWeights (r:768 w:0)
+	/// Storage: EterraDailySlots TicketsPerUser (r:256 w:256)
+	/// Storage: EterraDailySlots TotalTickets (r:256 w:256)
+	/// Storage: System Account (r:256 w:256)
+	/// Storage: EterraDailySlots RollHistory (r:256 w:256)
+	fn resolve_rolls(n: u32) -> Weight {
+		// The range of component `n` is `[0, 256]`.
+		Weight::from_parts(7_000_000, 4041)
+			.saturating_add(Weight::from_parts(72_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+}
@@ -0,0 +1,22 @@
+//! Runtime API for asking the Monte-Carlo AI for moves.
+//!
+//! Lets clients get the move `suggest_move` would pick without submitting a transaction. The
+//! search is seeded from the state, game and block it runs at, so answering it writes nothing
+//! and the same query at the same block always gets the same move.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    pub trait MonteCarloAiApi<State, Action, GameKey>
+    where
+        State: Codec,
+        Action: Codec,
+        GameKey: Codec,
+    {
+        /// Move the AI picks in `state` at `difficulty` (0..=100), with the search seeded for
+        /// `game` at the queried block. `None` if `state` has no legal moves. Read-only.
+        fn suggest(state: State, game: Option<GameKey>, difficulty: u8) -> Option<Action>;
+    }
+}
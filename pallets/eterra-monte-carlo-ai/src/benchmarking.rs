@@ -0,0 +1,99 @@
+//! Benchmarking setup for pallet-eterra-monte-carlo-ai
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as EterraMonteCarloAi;
+use frame_benchmarking::v2::*;
+use frame_system::offchain::AppCrypto;
+use frame_system::RawOrigin;
+use sp_runtime::{
+    app_crypto::RuntimeAppPublic,
+    traits::{Hash, IdentifyAccount},
+};
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// Iterations grow linearly with `d`; the helper's state keeps every playout running to
+    /// `MaxPlayoutDepth`.
+    #[benchmark]
+    fn suggest_move(d: Linear<0, { u8::MAX as u32 }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let state = T::BenchmarkHelper::worst_case_state();
+
+        #[extrinsic_call]
+        suggest_move(RawOrigin::Signed(caller), state, d as u8);
+
+        assert_eq!(frame_system::Pallet::<T>::event_count(), 1);
+    }
+
+    /// Validation and the provider's move both run against the game the provider set up.
+    #[benchmark]
+    fn submit_ai_move() {
+        let game = T::TurnProvider::set_up_ai_turn();
+        let state = T::TurnProvider::ai_state(&game).expect("game waits on the AI; qed");
+        let state_hash = T::Hashing::hash_of(&state);
+        let block = frame_system::Pallet::<T>::block_number();
+        let seed = Pallet::<T>::seed_for(&state_hash, Some(&game), block);
+        let action =
+            Pallet::<T>::search::<T::Adapter>(&state, 1, seed).expect("state has moves; qed");
+        let key =
+            <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(
+                None,
+            );
+        let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+            key.into();
+        let public: T::Public = generic.into();
+        AiSigners::<T>::insert(public.clone().into_account(), ());
+        let payload = AiMovePayload {
+            public: public.clone(),
+            game: game.clone(),
+            state_hash,
+            block,
+            action,
+        };
+        let signature =
+            <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::sign(&payload.encode(), public)
+                .expect("key was just generated; qed");
+
+        #[extrinsic_call]
+        submit_ai_move(RawOrigin::None, payload, signature);
+
+        assert!(T::TurnProvider::ai_state(&game).is_none());
+    }
+
+    #[benchmark]
+    fn add_ai_signer() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let signer: T::AccountId = account("signer", 0, 0);
+
+        #[extrinsic_call]
+        add_ai_signer(origin as T::RuntimeOrigin, signer.clone());
+
+        assert!(AiSigners::<T>::contains_key(&signer));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_ai_signer() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let signer: T::AccountId = account("signer", 0, 0);
+        AiSigners::<T>::insert(&signer, ());
+
+        #[extrinsic_call]
+        remove_ai_signer(origin as T::RuntimeOrigin, signer.clone());
+
+        assert!(!AiSigners::<T>::contains_key(&signer));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        EterraMonteCarloAi,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
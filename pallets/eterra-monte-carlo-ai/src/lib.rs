@@ -7,8 +7,55 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod mcts;
+pub mod solver;
+pub mod weights;
+pub use weights::*;
+
+use frame_support::Parameter;
+use frame_system::offchain::{SignedPayload, SigningTypes};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, DispatchResult, KeyTypeId, PerThing, Percent};
+use sp_std::vec::Vec;
+
+/// Most legal actions a [`GameAdapter`] may list for one state. Every action buffer of the
+/// search is this long, so `Config::MaxActions` must not exceed it.
+pub const MAX_ACTIONS: usize = 256;
+
+/// Key type of the keys the offchain worker signs AI moves with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"eai!");
+
+/// sr25519 keys for signing AI moves. Insert one into the node's keystore under `eai!` and
+/// add its account to `AiSigners`.
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_core::sr25519::Signature as Sr25519Signature;
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        traits::Verify,
+        MultiSignature, MultiSigner,
+    };
+    app_crypto!(sr25519, KEY_TYPE);
+
+    pub struct AuthorityId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+
+    impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+        for AuthorityId
+    {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
 
 /// A generic, no_std-friendly adapter for any 2-player, turn-based, perfect-information game.
 pub trait GameAdapter {
@@ -55,6 +102,94 @@ pub trait GameAdapter {
     /// Uniform-ish random legal action for playouts (return None if none).
     /// Use `seed` deterministically to stay consensus-safe on-chain.
     fn random_action(state: &Self::State, seed: u64) -> Option<Self::Action>;
+
+    /// Exact best move for the player to move, or `None` if `state` can't be solved within
+    /// `budget` visited positions. Asked once fewer than `SolverThreshold` actions are left,
+    /// or by `solve_or_suggest` sooner, and never when the rest of the game clearly takes
+    /// more than `budget`; adapters whose endgames are small enough can forward to
+    /// [`solver::solve`].
+    fn solve(_state: &Self::State, _budget: u32) -> Option<Self::Action> {
+        None
+    }
+}
+
+/// Games whose next move belongs to the AI. The offchain worker searches these and submits
+/// its moves back through `submit_ai_move`.
+pub trait AiTurnProvider<State, Action> {
+    /// Identifies a game across blocks.
+    type GameKey: Parameter + MaxEncodedLen;
+
+    /// Up to `max` games currently waiting on the AI, with the state to search from.
+    fn awaiting_ai(max: u32) -> Vec<(Self::GameKey, State)>;
+
+    /// Search state of `game` if it is still waiting on the AI.
+    fn ai_state(game: &Self::GameKey) -> Option<State>;
+
+    /// Play `action` for the AI in `game`, with the same checks a player's move gets.
+    fn apply_ai_action(game: &Self::GameKey, action: &Action) -> DispatchResult;
+
+    /// Share of `OffchainIterations` to search `game` with.
+    fn search_budget(_game: &Self::GameKey) -> Percent {
+        Percent::one()
+    }
+
+    /// Leave a game waiting on the AI in the position with the most legal moves.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_up_ai_turn() -> Self::GameKey;
+}
+
+/// No games ever wait on the AI.
+impl<State, Action> AiTurnProvider<State, Action> for () {
+    type GameKey = ();
+
+    fn awaiting_ai(_max: u32) -> Vec<((), State)> {
+        Vec::new()
+    }
+
+    fn ai_state(_game: &()) -> Option<State> {
+        None
+    }
+
+    fn apply_ai_action(_game: &(), _action: &Action) -> DispatchResult {
+        Err(DispatchError::Other("no games wait on the AI"))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_up_ai_turn() {}
+}
+
+/// A move the offchain worker found for `game`, signed by one of the `AiSigners`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct AiMovePayload<Public, GameKey, Hash, BlockNumber, Action> {
+    pub public: Public,
+    pub game: GameKey,
+    /// Hash of the state the search started from; the move is stale once the game moves on.
+    pub state_hash: Hash,
+    /// Block the search was seeded with. Together with the state and game, anyone can replay
+    /// the search and check the move.
+    pub block: BlockNumber,
+    pub action: Action,
+}
+
+impl<T, GameKey, Hash, BlockNumber, Action> SignedPayload<T>
+    for AiMovePayload<T::Public, GameKey, Hash, BlockNumber, Action>
+where
+    T: SigningTypes,
+    GameKey: Encode,
+    Hash: Encode,
+    BlockNumber: Encode,
+    Action: Encode,
+{
+    fn public(&self) -> T::Public {
+        self.public.clone()
+    }
+}
+
+/// Supplies the search inputs benchmarks need from the runtime's adapter.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<State> {
+    /// A non-terminal state with as many legal actions and as long a playout as the adapter allows.
+    fn worst_case_state() -> State;
 }
 
 #[frame_support::pallet]
@@ -62,21 +197,41 @@ pub mod pallet {
     use super::*;
     use frame_support::sp_runtime::traits::Hash as HashTrait;
     use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
+    use frame_system::offchain::{SendTransactionTypes, SendUnsignedTransaction, Signer};
     use frame_system::pallet_prelude::*;
     use parity_scale_codec::Encode;
-    use sp_runtime::traits::Hash;
+    use sp_runtime::offchain::storage::StorageValueRef;
+    use sp_runtime::traits::{Hash, IdentifyAccount};
+
+    /// Blocks a submitted move stays in the pool before the offchain worker searches again.
+    const RESUBMIT_AFTER: u32 = 5;
+
+    pub type StateOf<T> = <<T as Config>::Adapter as GameAdapter>::State;
+    pub type ActionOf<T> = <<T as Config>::Adapter as GameAdapter>::Action;
+    pub type GameKeyOf<T> =
+        <<T as Config>::TurnProvider as AiTurnProvider<StateOf<T>, ActionOf<T>>>::GameKey;
+    pub type AiMovePayloadOf<T> = AiMovePayload<
+        <T as SigningTypes>::Public,
+        GameKeyOf<T>,
+        <T as frame_system::Config>::Hash,
+        BlockNumberFor<T>,
+        ActionOf<T>,
+    >;
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config:
+        frame_system::Config + SigningTypes + SendTransactionTypes<Call<Self>>
+    {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// Provide the concrete adapter to use for AI.
         type Adapter: GameAdapter;
 
         /// Maximum branching factor the adapter promises (upper-bound on legal actions).
+        /// At most [`MAX_ACTIONS`].
         #[pallet::constant]
         type MaxActions: Get<u32>;
 
@@ -88,14 +243,54 @@ pub mod pallet {
         #[pallet::constant]
         type MaxPlayoutDepth: Get<u16>;
 
-        /// Seed used for deterministic PRNG inside the pallet.
+        /// UCB1 exploration constant of the tree search, in thousandths (`1414` ≈ √2).
+        /// Higher values spread iterations over more moves, lower ones dig into the best.
+        #[pallet::constant]
+        type ExplorationConstant: Get<u32>;
+
+        /// Nodes the search tree may hold; bounds the memory of a single search.
+        #[pallet::constant]
+        type MaxTreeNodes: Get<u32>;
+
+        /// Positions with fewer legal actions than this are solved exactly by the adapter
+        /// instead of sampled. `0` always samples.
         #[pallet::constant]
-        type RandomnessSeed: Get<u64>;
+        type SolverThreshold: Get<u32>;
+
+        /// Games the offchain worker plays the AI's moves in.
+        type TurnProvider: AiTurnProvider<StateOf<Self>, ActionOf<Self>>;
+
+        /// Key the offchain worker signs its moves with.
+        type AuthorityId: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>;
+
+        /// Origin allowed to manage `AiSigners`.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Iterations the offchain worker spends per move. Not bound by block weight, so this
+        /// can be far above what `BaseIterations` allows on-chain.
+        #[pallet::constant]
+        type OffchainIterations: Get<u32>;
+
+        /// Most games the offchain worker searches per block.
+        #[pallet::constant]
+        type MaxOffchainGames: Get<u32>;
+
+        /// Priority of the unsigned transactions carrying AI moves.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+
+        /// Builds the states `suggest_move` is benchmarked with.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<<Self::Adapter as GameAdapter>::State>;
     }
 
+    /// Accounts whose keys may sign AI moves.
     #[pallet::storage]
-    /// Simple deterministic nonce for PRNG.
-    pub type Nonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::getter(fn is_ai_signer)]
+    pub type AiSigners<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -108,19 +303,110 @@ pub mod pallet {
             /// The suggested action (SCALE-encoded by RPC users if needed).
             action: <T::Adapter as GameAdapter>::Action,
         },
+        /// The offchain worker's move was played for the AI.
+        AiMovePlayed {
+            game: GameKeyOf<T>,
+            action: ActionOf<T>,
+        },
+        AiSignerAdded(T::AccountId),
+        AiSignerRemoved(T::AccountId),
     }
 
     #[pallet::error]
     pub enum Error<T> {
         NoLegalMoves,
+        /// The game is not waiting on the AI.
+        NotAwaitingAi,
+        /// The game changed since the move was searched.
+        StaleState,
+        /// The action is not legal in the game's current state.
+        IllegalAction,
+        AlreadyAiSigner,
+        NotAiSigner,
+    }
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub initial_signers: Vec<T::AccountId>,
+    }
+
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self {
+                initial_signers: Vec::new(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for signer in &self.initial_signers {
+                AiSigners::<T>::insert(signer, ());
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Search every game waiting on the AI and submit the chosen moves.
+        fn offchain_worker(n: BlockNumberFor<T>) {
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            if !signer.can_sign() {
+                return;
+            }
+
+            for (game, state) in T::TurnProvider::awaiting_ai(T::MaxOffchainGames::get()) {
+                let state_hash = <T::Hashing as HashTrait>::hash_of(&state);
+
+                // Skip games we already answered and whose move may still be in the pool.
+                let key = (b"eterra-mc-ai::sent", &game).encode();
+                let sent = StorageValueRef::persistent(&key);
+                if let Ok(Some((hash, at))) = sent.get::<(T::Hash, BlockNumberFor<T>)>() {
+                    if hash == state_hash && n < at.saturating_add(RESUBMIT_AFTER.into()) {
+                        continue;
+                    }
+                }
+
+                let iterations =
+                    T::TurnProvider::search_budget(&game).mul_floor(T::OffchainIterations::get());
+                let seed = Self::seed_for(&state_hash, Some(&game), n);
+                let action = match Self::search::<T::Adapter>(&state, iterations, seed) {
+                    Some(a) => a,
+                    None => continue,
+                };
+                let result = signer.send_unsigned_transaction(
+                    |account| AiMovePayload {
+                        public: account.public.clone(),
+                        game: game.clone(),
+                        state_hash,
+                        block: n,
+                        action: action.clone(),
+                    },
+                    |payload, signature| Call::submit_ai_move { payload, signature },
+                );
+                match result {
+                    Some((_, Ok(()))) => sent.set(&(state_hash, n)),
+                    _ => log::warn!("Failed to submit AI move for game {:?}", game),
+                }
+            }
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::MaxActions::get() as usize <= MAX_ACTIONS,
+                "MaxActions must not exceed MAX_ACTIONS"
+            );
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Ask the AI to suggest the best action by Monte-Carlo (rollout) search.
-        /// `difficulty` in 0..=100 scales the iterations.
+        /// Ask the AI to suggest the best action by Monte-Carlo tree search.
+        /// `difficulty` in 0..=100 scales the iterations. The search is seeded from the state and
+        /// the current block, so the same state gets the same move within a block.
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::suggest_move(*difficulty as u32))]
         pub fn suggest_move(
             origin: OriginFor<T>,
             state: <T::Adapter as GameAdapter>::State,
@@ -128,10 +414,15 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?; // optionally allow unsigned
 
-            let action =
-                Self::suggest::<T::Adapter>(&state, difficulty).ok_or(Error::<T>::NoLegalMoves)?;
-
             let state_hash: T::Hash = <T::Hashing as HashTrait>::hash_of(&state);
+            let seed = Self::seed_for(
+                &state_hash,
+                None::<&GameKeyOf<T>>,
+                frame_system::Pallet::<T>::block_number(),
+            );
+            let action = Self::suggest::<T::Adapter>(&state, difficulty, seed)
+                .ok_or(Error::<T>::NoLegalMoves)?;
+
             let iters = Self::scaled_iterations::<T>(difficulty);
             Self::deposit_event(Event::Suggested {
                 state_hash,
@@ -142,6 +433,79 @@ pub mod pallet {
 
             Ok(Pays::No.into())
         }
+
+        /// Play a move the offchain worker searched for the AI. The signature is checked in
+        /// `validate_unsigned`; the move is checked again here against the current state.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::submit_ai_move())]
+        pub fn submit_ai_move(
+            origin: OriginFor<T>,
+            payload: AiMovePayloadOf<T>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            Self::check_ai_move(&payload)?;
+            T::TurnProvider::apply_ai_action(&payload.game, &payload.action)?;
+            Self::deposit_event(Event::AiMovePlayed {
+                game: payload.game,
+                action: payload.action,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::add_ai_signer())]
+        pub fn add_ai_signer(origin: OriginFor<T>, signer: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                !AiSigners::<T>::contains_key(&signer),
+                Error::<T>::AlreadyAiSigner
+            );
+            AiSigners::<T>::insert(&signer, ());
+            Self::deposit_event(Event::AiSignerAdded(signer));
+            Ok(())
+        }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::remove_ai_signer())]
+        pub fn remove_ai_signer(origin: OriginFor<T>, signer: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                AiSigners::<T>::contains_key(&signer),
+                Error::<T>::NotAiSigner
+            );
+            AiSigners::<T>::remove(&signer);
+            Self::deposit_event(Event::AiSignerRemoved(signer));
+            Ok(())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let (payload, signature) = match call {
+                Call::submit_ai_move { payload, signature } => (payload, signature),
+                _ => return InvalidTransaction::Call.into(),
+            };
+            if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+                return InvalidTransaction::BadProof.into();
+            }
+            if !AiSigners::<T>::contains_key(payload.public.clone().into_account()) {
+                return InvalidTransaction::BadSigner.into();
+            }
+            if Self::check_ai_move(payload).is_err() {
+                return InvalidTransaction::Stale.into();
+            }
+
+            ValidTransaction::with_tag_prefix("EterraMonteCarloAi")
+                .priority(T::UnsignedPriority::get())
+                .and_provides((payload.game.clone(), payload.state_hash))
+                .longevity(RESUBMIT_AFTER as u64)
+                .propagate(true)
+                .build()
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -153,44 +517,178 @@ pub mod pallet {
             (base * mult_num) / 100
         }
 
-        #[inline]
-        pub fn prng_u64<C: Config>(salt: u64) -> u64 {
-            // Deterministic, cheap PRNG for no_std.
-            let n = Nonce::<C>::get();
-            let seed = C::RandomnessSeed::get();
-            let mix = seed ^ n ^ salt;
+        /// Seed of the searches for the state hashing to `state_hash` in `game` at `block`.
+        /// Searches draw from an in-memory PRNG seeded with this once, so they read and write
+        /// no storage and the same inputs always give the same move.
+        pub fn seed_for<G: Encode>(
+            state_hash: &T::Hash,
+            game: Option<&G>,
+            block: BlockNumberFor<T>,
+        ) -> u64 {
+            let hash =
+                <T::Hashing as HashTrait>::hash_of(&(b"mc-ai/seed", state_hash, game, block));
+            u64::decode(&mut hash.as_ref()).unwrap_or_default()
+        }
+
+        /// The move `suggest_move` would pick for `state` in `game` at the current block,
+        /// without emitting anything or touching storage. Backs the runtime API.
+        pub fn suggestion(
+            state: &StateOf<T>,
+            game: Option<&GameKeyOf<T>>,
+            difficulty: u8,
+        ) -> Option<ActionOf<T>> {
+            let state_hash = <T::Hashing as HashTrait>::hash_of(state);
+            let seed = Self::seed_for(&state_hash, game, frame_system::Pallet::<T>::block_number());
+            Self::suggest::<T::Adapter>(state, difficulty, seed)
+        }
+
+        /// Monte-Carlo tree search suggestor, with the budget scaled by `difficulty`.
+        pub fn suggest<A: GameAdapter>(
+            state: &A::State,
+            difficulty: u8,
+            seed: u64,
+        ) -> Option<A::Action> {
+            Self::search::<A>(state, Self::scaled_iterations::<T>(difficulty), seed)
+        }
+
+        /// Run `iterations` rounds of UCT from `state` and return the most visited move. Near
+        /// the end of the game the adapter's exact solver is tried first, given as many
+        /// positions as the playouts would have visited.
+        pub fn search<A: GameAdapter>(
+            state: &A::State,
+            iterations: u32,
+            seed: u64,
+        ) -> Option<A::Action> {
+            if Self::in_endgame::<A>(state) {
+                if let Some(action) = Self::try_solve::<A>(state, iterations) {
+                    return Some(action);
+                }
+            }
+            Self::uct::<A>(state, iterations, seed)
+        }
+
+        /// Exact move if the adapter can solve `state` within the budget `difficulty` gives the
+        /// search, however many actions are left; the search's move otherwise. The solver runs
+        /// at most once.
+        pub fn solve_or_suggest<A: GameAdapter>(
+            state: &A::State,
+            difficulty: u8,
+            seed: u64,
+        ) -> Option<A::Action> {
+            let iterations = Self::scaled_iterations::<T>(difficulty);
+            Self::try_solve::<A>(state, iterations)
+                .or_else(|| Self::uct::<A>(state, iterations, seed))
+        }
 
-            // SplitMix64-ish
-            let mut z = mix.wrapping_add(0x9E3779B97F4A7C15);
-            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
-            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
-            let out = z ^ (z >> 31);
+        /// The adapter's exact move for `state`, given as many positions as `iterations`
+        /// playouts would visit. Skipped when the rest of the game clearly can't fit that.
+        fn try_solve<A: GameAdapter>(state: &A::State, iterations: u32) -> Option<A::Action> {
+            let budget = iterations.saturating_mul(T::MaxPlayoutDepth::get() as u32);
+            if !Self::might_fit::<A>(state, budget) {
+                return None;
+            }
+            A::solve(state, budget)
+        }
 
-            Nonce::<C>::put(n.wrapping_add(1));
-            out
+        /// Whether solving `state` might take no more than `budget` positions. However well
+        /// alpha-beta prunes, settling a value visits every move of one side on each of its
+        /// turns, so this follows the first listed moves to the end of the game and gives up
+        /// once the plies left, or either side's moves multiplied over its turns, exceed
+        /// `budget`.
+        fn might_fit<A: GameAdapter>(state: &A::State, budget: u32) -> bool {
+            let me = A::current_player(state);
+            let (mut mine, mut theirs) = (1u32, 1u32);
+            let mut state = state.clone();
+            let mut actions: [Option<A::Action>; MAX_ACTIONS] = core::array::from_fn(|_| None);
+            for _ in 0..budget {
+                if A::is_terminal(&state) {
+                    return true;
+                }
+                let n = A::list_actions::<MAX_ACTIONS>(&state, &mut actions);
+                let Some(first) = actions.iter().take(n).flatten().next() else {
+                    return true;
+                };
+                let side = if A::current_player(&state) == me {
+                    &mut mine
+                } else {
+                    &mut theirs
+                };
+                *side = side.saturating_mul(n as u32);
+                if *side > budget {
+                    return false;
+                }
+                state = A::apply(&state, first);
+            }
+            false
         }
 
-        /// Monte-Carlo rollout suggestor (per-action averaging).
-        pub fn suggest<A: GameAdapter>(state: &A::State, difficulty: u8) -> Option<A::Action> {
+        /// `iterations` rounds of UCT from `state`, returning the most visited move.
+        fn uct<A: GameAdapter>(state: &A::State, iterations: u32, seed: u64) -> Option<A::Action> {
+            let params = mcts::Params {
+                iterations,
+                exploration: T::ExplorationConstant::get(),
+                max_nodes: T::MaxTreeNodes::get(),
+                max_playout_depth: T::MaxPlayoutDepth::get(),
+                seed,
+            };
+            mcts::search::<A>(state, &params)
+        }
+
+        /// Any legal move in `state`, drawn with `seed`.
+        pub fn random_move<A: GameAdapter>(state: &A::State, seed: u64) -> Option<A::Action> {
             if A::is_terminal(state) {
                 return None;
             }
+            A::random_action(state, seed)
+        }
 
-            // Collect legal actions into a fixed-size buffer
-            const MAX_BUF: usize = 128;
-            // Ensure compile-time buffer bound is >= runtime bound
-            // (Build-time safety: MaxActions <= 128 in tests/runtime config.)
-            let mut actions: [Option<A::Action>; MAX_BUF] = core::array::from_fn(|_| None);
+        /// The move that leaves the player to move with the best `score` right away, without
+        /// looking at the reply. Ties go to the first listed move.
+        pub fn greedy_move<A: GameAdapter>(state: &A::State) -> Option<A::Action> {
+            if A::is_terminal(state) {
+                return None;
+            }
+            let me = A::current_player(state);
+            let mut actions: [Option<A::Action>; MAX_ACTIONS] = core::array::from_fn(|_| None);
+            let n = A::list_actions::<MAX_ACTIONS>(state, &mut actions);
+
+            let mut best = None;
+            let mut best_score = i32::MIN;
+            for action in actions.into_iter().take(n).flatten() {
+                let score = A::score(&A::apply(state, &action), me);
+                if best.is_none() || score > best_score {
+                    best_score = score;
+                    best = Some(action);
+                }
+            }
+            best
+        }
+
+        /// Flat Monte-Carlo: about `iterations` rollouts split evenly across the legal actions,
+        /// with no tree. Kept as the baseline `search` is measured against.
+        pub fn flat_search<A: GameAdapter>(
+            state: &A::State,
+            iterations: u32,
+            seed: u64,
+        ) -> Option<A::Action> {
+            if A::is_terminal(state) {
+                return None;
+            }
+
+            // Collect legal actions into a fixed-size buffer; `integrity_test` keeps
+            // MaxActions within it
+            let mut actions: [Option<A::Action>; MAX_ACTIONS] = core::array::from_fn(|_| None);
 
-            let n = A::list_actions::<MAX_BUF>(state, &mut actions);
+            let n = A::list_actions::<MAX_ACTIONS>(state, &mut actions);
             if n == 0 {
                 return None;
             }
 
-            let iters = Self::scaled_iterations::<T>(difficulty).max(n as u32);
+            let iters = iterations.max(n as u32);
             let sims_per_action = (iters / n as u32).max(1);
 
             let me = A::current_player(state);
+            let mut rng = seed;
 
             let mut best_idx = 0usize;
             let mut best_score = i64::MIN;
@@ -198,8 +696,8 @@ pub mod pallet {
             for i in 0..n {
                 let action = actions[i].as_ref().unwrap();
                 let mut accum: i64 = 0;
-                for j in 0..sims_per_action {
-                    let seed = Self::prng_u64::<T>((i as u64) << 32 | j as u64);
+                for _ in 0..sims_per_action {
+                    let seed = mcts::next(&mut rng);
                     let s1 = A::apply(state, action);
                     let outcome = Self::random_playout::<A>(&s1, me, seed);
                     accum += outcome as i64;
@@ -214,6 +712,38 @@ pub mod pallet {
             actions[best_idx].clone()
         }
 
+        /// Whether `state` has fewer legal actions than `SolverThreshold`, but still some.
+        fn in_endgame<A: GameAdapter>(state: &A::State) -> bool {
+            if A::is_terminal(state) {
+                return false;
+            }
+            let mut actions: [Option<A::Action>; MAX_ACTIONS] = core::array::from_fn(|_| None);
+            let n = A::list_actions::<MAX_ACTIONS>(state, &mut actions) as u32;
+            n > 0 && n < T::SolverThreshold::get()
+        }
+
+        /// Whether `action` is one of the adapter's legal actions in `state`.
+        pub fn is_legal(state: &StateOf<T>, action: &ActionOf<T>) -> bool {
+            let mut actions: [Option<ActionOf<T>>; MAX_ACTIONS] = core::array::from_fn(|_| None);
+            let n = T::Adapter::list_actions::<MAX_ACTIONS>(state, &mut actions);
+            actions[..n].iter().any(|a| a.as_ref() == Some(action))
+        }
+
+        /// The game still waits on the AI in the searched state, and the move is legal there.
+        fn check_ai_move(payload: &AiMovePayloadOf<T>) -> DispatchResult {
+            let state =
+                T::TurnProvider::ai_state(&payload.game).ok_or(Error::<T>::NotAwaitingAi)?;
+            ensure!(
+                <T::Hashing as HashTrait>::hash_of(&state) == payload.state_hash,
+                Error::<T>::StaleState
+            );
+            ensure!(
+                Self::is_legal(&state, &payload.action),
+                Error::<T>::IllegalAction
+            );
+            Ok(())
+        }
+
         fn random_playout<A: GameAdapter>(start: &A::State, me: A::Player, mut seed: u64) -> i32 {
             let mut s = start.clone();
             let mut depth = 0u16;
@@ -0,0 +1,233 @@
+//! UCT: Monte-Carlo tree search with UCB1 selection, over any [`GameAdapter`].
+//!
+//! The tree lives in a single arena allocated once and never grown past `max_nodes`; when it
+//! is full, iterations keep sampling below the leaves already there. Nodes don't hold states,
+//! each iteration replays the actions on its path from the root instead. All arithmetic is
+//! integer, so the same inputs pick the same move everywhere.
+
+use crate::{GameAdapter, MAX_ACTIONS};
+use sp_std::vec::Vec;
+
+/// Fixed-point scale of UCB values.
+const SCALE: u64 = 1 << 16;
+/// `ln 2` at `SCALE`.
+const LN_2: u64 = 45_426;
+
+/// Knobs for one [`search`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Iterations to run. Each one expands at most one node and plays out one game.
+    pub iterations: u32,
+    /// Exploration constant `c` of UCB1, in thousandths (`1414` ≈ √2).
+    pub exploration: u32,
+    /// Capacity of the node arena. The root's children always fit.
+    pub max_nodes: u32,
+    /// Playouts stop after this many moves and score the position they reached.
+    pub max_playout_depth: u16,
+    /// Seeds the playouts.
+    pub seed: u64,
+}
+
+struct Node<A: GameAdapter> {
+    /// Move from the parent into this node; `None` only at the root.
+    action: Option<A::Action>,
+    /// Player who made `action`. Unused at the root.
+    mover: A::Player,
+    parent: u32,
+    /// Children sit next to each other from here on, once the node is expanded.
+    first_child: u32,
+    children: u32,
+    expanded: bool,
+    visits: u32,
+    /// Half-points earned by `mover` in the playouts through here: 2 a win, 1 a draw.
+    reward: u64,
+}
+
+/// The most visited move at `root` after `params.iterations` rounds of selection, expansion,
+/// simulation and backpropagation. `None` if `root` has no legal moves.
+pub fn search<A: GameAdapter>(root: &A::State, params: &Params) -> Option<A::Action> {
+    if A::is_terminal(root) {
+        return None;
+    }
+    let me = A::current_player(root);
+    let cap = (params.max_nodes as usize).max(1 + MAX_ACTIONS);
+    // Every iteration adds at most one node's children, so a short search needs less.
+    let needed = (params.iterations as usize)
+        .saturating_add(1)
+        .saturating_mul(MAX_ACTIONS)
+        .saturating_add(1);
+    let mut tree: Vec<Node<A>> = Vec::with_capacity(cap.min(needed));
+    tree.push(Node {
+        action: None,
+        mover: me,
+        parent: 0,
+        first_child: 0,
+        children: 0,
+        expanded: false,
+        visits: 0,
+        reward: 0,
+    });
+    if !expand::<A>(&mut tree, 0, root, cap) {
+        return None;
+    }
+
+    let c = (params.exploration as u64).saturating_mul(SCALE) / 1000;
+    let mut rng = params.seed;
+
+    for _ in 0..params.iterations.max(1) {
+        let mut state = root.clone();
+        let mut node = 0usize;
+
+        // Selection: follow UCB1 down to a node that isn't expanded yet.
+        while tree[node].expanded && tree[node].children > 0 {
+            node = select(&tree, node, c);
+            state = A::apply(&state, tree[node].action.as_ref()?);
+        }
+
+        // Expansion: add all its children and step into the first, which is unvisited.
+        if !tree[node].expanded && expand::<A>(&mut tree, node, &state, cap) {
+            node = tree[node].first_child as usize;
+            state = A::apply(&state, tree[node].action.as_ref()?);
+        }
+
+        // Simulation.
+        let points = playout::<A>(&state, me, &mut rng, params.max_playout_depth);
+
+        // Backpropagation, crediting each node to the player who moved into it.
+        loop {
+            let n = &mut tree[node];
+            n.visits = n.visits.saturating_add(1);
+            n.reward = n
+                .reward
+                .saturating_add(if n.mover == me { points } else { 2 - points });
+            if node == 0 {
+                break;
+            }
+            node = n.parent as usize;
+        }
+    }
+
+    let root = &tree[0];
+    let first = root.first_child as usize;
+    tree[first..first + root.children as usize]
+        .iter()
+        .max_by(|a, b| (a.visits, a.reward).cmp(&(b.visits, b.reward)))
+        .and_then(|n| n.action.clone())
+}
+
+/// Append one child per legal action of `state` to `node`. Returns `false`, leaving `node` as
+/// a leaf, when there are none or they don't all fit in the arena.
+fn expand<A: GameAdapter>(
+    tree: &mut Vec<Node<A>>,
+    node: usize,
+    state: &A::State,
+    cap: usize,
+) -> bool {
+    let mut actions: [Option<A::Action>; MAX_ACTIONS] = core::array::from_fn(|_| None);
+    let n = if A::is_terminal(state) {
+        0
+    } else {
+        A::list_actions::<MAX_ACTIONS>(state, &mut actions)
+    };
+    if n == 0 {
+        tree[node].expanded = true;
+        return false;
+    }
+    if tree.len() + n > cap {
+        return false;
+    }
+
+    let mover = A::current_player(state);
+    let first = tree.len() as u32;
+    for action in actions.into_iter().take(n) {
+        tree.push(Node {
+            action,
+            mover,
+            parent: node as u32,
+            first_child: 0,
+            children: 0,
+            expanded: false,
+            visits: 0,
+            reward: 0,
+        });
+    }
+    let parent = &mut tree[node];
+    parent.first_child = first;
+    parent.children = n as u32;
+    parent.expanded = true;
+    true
+}
+
+/// Child of `node` with the highest UCB1 score; unvisited children come first.
+fn select<A: GameAdapter>(tree: &[Node<A>], node: usize, c: u64) -> usize {
+    let parent = &tree[node];
+    let first = parent.first_child as usize;
+    let ln_n = ln(parent.visits.max(1) as u64);
+
+    let mut best = first;
+    let mut best_ucb = 0u64;
+    for i in first..first + parent.children as usize {
+        let child = &tree[i];
+        if child.visits == 0 {
+            return i;
+        }
+        let visits = child.visits as u64;
+        let exploit = child.reward.saturating_mul(SCALE) / (2 * visits);
+        let explore = c.saturating_mul(isqrt(ln_n.saturating_mul(SCALE) / visits)) / SCALE;
+        let ucb = exploit.saturating_add(explore);
+        if ucb > best_ucb {
+            best_ucb = ucb;
+            best = i;
+        }
+    }
+    best
+}
+
+/// Random moves from `state` until the game ends or `max_depth` moves were made. Returns the
+/// half-points `me` scores there: 2 if ahead, 1 if level, 0 if behind.
+fn playout<A: GameAdapter>(state: &A::State, me: A::Player, rng: &mut u64, max_depth: u16) -> u64 {
+    let mut s = state.clone();
+    let mut depth = 0u16;
+    while depth < max_depth && !A::is_terminal(&s) {
+        match A::random_action(&s, next(rng)) {
+            Some(a) => s = A::apply(&s, &a),
+            None => break,
+        }
+        depth += 1;
+    }
+    match A::score(&s, me) {
+        x if x > 0 => 2,
+        0 => 1,
+        _ => 0,
+    }
+}
+
+/// SplitMix64 step; the PRNG every search draws from, seeded once per call.
+pub(crate) fn next(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Natural log of `n >= 1` at `SCALE`, interpolating linearly between powers of two.
+fn ln(n: u64) -> u64 {
+    let k = 63 - n.leading_zeros() as u64;
+    let frac = ((n - (1 << k)) << 16) >> k;
+    (k * SCALE + frac) * LN_2 / SCALE
+}
+
+/// Integer square root.
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
@@ -3,9 +3,12 @@ use frame_support::{parameter_types, traits::Everything};
 use frame_system as system;
 use sp_runtime::BuildStorage;
 
+use core::cell::RefCell;
 use sp_core::H256;
 use sp_io::TestExternalities;
+use sp_runtime::testing::{TestSignature, TestXt, UintAuthorityId};
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{DispatchError, DispatchResult};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -26,7 +29,9 @@ parameter_types! {
     pub const MaxActionsConst: u32 = 8;
     pub const BaseIterationsConst: u32 = 200; // base rollouts
     pub const MaxPlayoutDepthConst: u16 = 32;
-    pub const RandomnessSeedConst: u64 = 0xDEAD_BEEF_CAFE_BABE;
+    pub const ExplorationConstantConst: u32 = 1_000;
+    pub const MaxTreeNodesConst: u32 = 4_096;
+    pub const SolverThresholdConst: u32 = 21;
 }
 
 impl system::Config for Test {
@@ -145,6 +150,85 @@ impl pallet_eterra_monte_carlo_ai::GameAdapter for NimAdapter {
 
 parameter_types! {
     pub const SS: u8 = 0;
+    pub const OffchainIterationsConst: u32 = 2_000;
+    pub const MaxOffchainGamesConst: u32 = 4;
+    pub const UnsignedPriorityConst: u64 = 1 << 20;
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+pub struct TestAuthId;
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+}
+
+thread_local! {
+    static AWAITING: RefCell<Vec<(u32, NimState)>> = RefCell::new(Vec::new());
+    static PLAYED: RefCell<Vec<(u32, NimAction)>> = RefCell::new(Vec::new());
+}
+
+/// Nim games waiting on the AI, keyed by a plain id.
+pub struct NimTurns;
+
+impl NimTurns {
+    pub fn set_awaiting(games: Vec<(u32, NimState)>) {
+        AWAITING.with(|a| *a.borrow_mut() = games);
+    }
+
+    pub fn played() -> Vec<(u32, NimAction)> {
+        PLAYED.with(|p| p.borrow().clone())
+    }
+}
+
+impl pallet_eterra_monte_carlo_ai::AiTurnProvider<NimState, NimAction> for NimTurns {
+    type GameKey = u32;
+
+    fn awaiting_ai(max: u32) -> Vec<(u32, NimState)> {
+        AWAITING.with(|a| a.borrow().iter().take(max as usize).cloned().collect())
+    }
+
+    fn ai_state(game: &u32) -> Option<NimState> {
+        AWAITING.with(|a| {
+            a.borrow()
+                .iter()
+                .find(|(id, _)| id == game)
+                .map(|(_, s)| s.clone())
+        })
+    }
+
+    fn apply_ai_action(game: &u32, action: &NimAction) -> DispatchResult {
+        Self::ai_state(game).ok_or(DispatchError::Other("not awaiting"))?;
+        AWAITING.with(|a| a.borrow_mut().retain(|(id, _)| id != game));
+        PLAYED.with(|p| p.borrow_mut().push((*game, action.clone())));
+        Ok(())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_up_ai_turn() -> u32 {
+        Self::set_awaiting(vec![(
+            0,
+            NimState {
+                pile: u8::MAX,
+                to_move: 1,
+            },
+        )]);
+        0
+    }
 }
 
 impl pallet_eterra_monte_carlo_ai::Config for Test {
@@ -153,7 +237,28 @@ impl pallet_eterra_monte_carlo_ai::Config for Test {
     type MaxActions = MaxActionsConst;
     type BaseIterations = BaseIterationsConst;
     type MaxPlayoutDepth = MaxPlayoutDepthConst;
-    type RandomnessSeed = RandomnessSeedConst;
+    type ExplorationConstant = ExplorationConstantConst;
+    type MaxTreeNodes = MaxTreeNodesConst;
+    type SolverThreshold = SolverThresholdConst;
+    type TurnProvider = NimTurns;
+    type AuthorityId = TestAuthId;
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type OffchainIterations = OffchainIterationsConst;
+    type MaxOffchainGames = MaxOffchainGamesConst;
+    type UnsignedPriority = UnsignedPriorityConst;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = NimAdapter;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_eterra_monte_carlo_ai::BenchmarkHelper<NimState> for NimAdapter {
+    fn worst_case_state() -> NimState {
+        NimState {
+            pile: u8::MAX,
+            to_move: 0,
+        }
+    }
 }
 
 pub fn new_test_ext() -> TestExternalities {
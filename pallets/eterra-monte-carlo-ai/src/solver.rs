@@ -0,0 +1,132 @@
+//! Exact alpha-beta search with a transposition table, for the last plies of a game.
+//!
+//! Adapters opt in by calling [`solve`] from [`GameAdapter::solve`]. It needs `score` to be
+//! exact at terminal states; non-terminal states are never scored, the search always runs to
+//! the end of the game.
+
+use crate::{GameAdapter, MAX_ACTIONS};
+use parity_scale_codec::Encode;
+use sp_std::vec::Vec;
+
+/// Most transposition table entries, however large the budget.
+const MAX_TABLE: usize = 1 << 16;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bound {
+    Exact,
+    /// The value is at least this.
+    Lower,
+    /// The value is at most this.
+    Upper,
+}
+
+#[derive(Clone, Copy)]
+struct Entry {
+    key: u64,
+    value: i32,
+    bound: Bound,
+}
+
+struct Solver<A: GameAdapter> {
+    /// Values are `A::score` from this player's side.
+    me: A::Player,
+    table: Vec<Option<Entry>>,
+    /// Positions left to visit before giving up.
+    budget: u32,
+}
+
+/// Best move for the player to move in `state` by exact minimax over `A::score`, or `None` if
+/// that takes more than `budget` positions. Ties go to the first listed move.
+pub fn solve<A: GameAdapter>(state: &A::State, budget: u32) -> Option<A::Action> {
+    if A::is_terminal(state) {
+        return None;
+    }
+    let mut actions: [Option<A::Action>; MAX_ACTIONS] = core::array::from_fn(|_| None);
+    let n = A::list_actions::<MAX_ACTIONS>(state, &mut actions);
+
+    let mut table = Vec::new();
+    table.resize((budget as usize).clamp(1, MAX_TABLE), None);
+    let mut solver = Solver::<A> {
+        me: A::current_player(state),
+        table,
+        budget,
+    };
+
+    let mut best = None;
+    let mut best_value = i32::MIN;
+    for action in actions.iter().take(n).flatten() {
+        let value = solver.value(&A::apply(state, action), best_value, i32::MAX)?;
+        if best.is_none() || value > best_value {
+            best_value = value;
+            best = Some(action.clone());
+        }
+    }
+    best
+}
+
+impl<A: GameAdapter> Solver<A> {
+    /// Minimax value of `state`, exact if it lies strictly between `alpha` and `beta` and a
+    /// bound on the side it falls otherwise. `None` once the budget runs out.
+    fn value(&mut self, state: &A::State, mut alpha: i32, mut beta: i32) -> Option<i32> {
+        self.budget = self.budget.checked_sub(1)?;
+        if A::is_terminal(state) {
+            return Some(A::score(state, self.me));
+        }
+        let mut actions: [Option<A::Action>; MAX_ACTIONS] = core::array::from_fn(|_| None);
+        let n = A::list_actions::<MAX_ACTIONS>(state, &mut actions);
+        if n == 0 {
+            return Some(A::score(state, self.me));
+        }
+
+        let key = state.using_encoded(fnv1a);
+        let slot = (key % self.table.len() as u64) as usize;
+        if let Some(entry) = self.table[slot].filter(|e| e.key == key) {
+            match entry.bound {
+                Bound::Exact => return Some(entry.value),
+                Bound::Lower => alpha = alpha.max(entry.value),
+                Bound::Upper => beta = beta.min(entry.value),
+            }
+            if alpha >= beta {
+                return Some(entry.value);
+            }
+        }
+
+        let (alpha0, beta0) = (alpha, beta);
+        let maximizing = A::current_player(state) == self.me;
+        let mut best = if maximizing { i32::MIN } else { i32::MAX };
+        for action in actions.iter().take(n).flatten() {
+            let value = self.value(&A::apply(state, action), alpha, beta)?;
+            if maximizing {
+                best = best.max(value);
+                alpha = alpha.max(best);
+            } else {
+                best = best.min(value);
+                beta = beta.min(best);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best <= alpha0 {
+            Bound::Upper
+        } else if best >= beta0 {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        self.table[slot] = Some(Entry {
+            key,
+            value: best,
+            bound,
+        });
+        Some(best)
+    }
+}
+
+/// FNV-1a hash of a state's encoding.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |h, b| {
+        (h ^ *b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
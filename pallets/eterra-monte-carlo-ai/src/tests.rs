@@ -1,10 +1,16 @@
 use super::*;
+use crate::mock::RuntimeOrigin;
 use crate::pallet::Config;
 use crate::pallet::Pallet as EterraAi;
 use frame_support::{assert_ok, traits::OnInitialize};
 
 struct AdapterShim;
 
+thread_local! {
+    /// Times `AdapterShim::solve` has run on this thread.
+    static SOLVES: core::cell::Cell<u32> = const { core::cell::Cell::new(0) };
+}
+
 impl crate::GameAdapter for AdapterShim {
     type State = eterra_card_ai_adapter::eterra_adapter::State;
     type Action = eterra_card_ai_adapter::eterra_adapter::Action;
@@ -48,6 +54,11 @@ impl crate::GameAdapter for AdapterShim {
         let idx = (seed as usize) % n;
         buf[idx].clone()
     }
+
+    fn solve(s: &Self::State, budget: u32) -> Option<Self::Action> {
+        SOLVES.with(|n| n.set(n.get() + 1));
+        crate::solver::solve::<Self>(s, budget)
+    }
 }
 
 #[test]
@@ -60,11 +71,11 @@ fn nim_ai_picks_optimal_at_high_difficulty() {
             pile: 3,
             to_move: 0,
         }; // optimal is Take1 (random reply; Take2 gives opponent forced win)
-        let a = EterraAi::<Test>::suggest::<crate::mock::NimAdapter>(&s, 95).expect("action");
+        let a = EterraAi::<Test>::suggest::<crate::mock::NimAdapter>(&s, 95, 0).expect("action");
         assert_eq!(a, NimAction::Take1);
 
         // Lower difficulty may still pick optimal, but let's ensure it returns a legal action.
-        let a2 = EterraAi::<Test>::suggest::<crate::mock::NimAdapter>(&s, 10).expect("action");
+        let a2 = EterraAi::<Test>::suggest::<crate::mock::NimAdapter>(&s, 10, 0).expect("action");
         assert!(a2 == NimAction::Take1 || a2 == NimAction::Take2);
     });
 }
@@ -96,6 +107,39 @@ fn extrinsic_emits_suggested_event() {
     });
 }
 
+#[test]
+fn searches_are_replayable_and_touch_no_storage() {
+    use crate::mock::{NimState, Test};
+    use sp_runtime::traits::{BlakeTwo256, Hash};
+
+    crate::mock::new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(5);
+        let root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+
+        // The same state, game and block always seed the same search.
+        let s = eterra_position(3);
+        let hash = BlakeTwo256::hash_of(&s);
+        let seed = EterraAi::<Test>::seed_for(&hash, Some(&1u32), 5);
+        let a = EterraAi::<Test>::suggest::<AdapterShim>(&s, 60, seed);
+        assert!(a.is_some());
+        assert_eq!(EterraAi::<Test>::suggest::<AdapterShim>(&s, 60, seed), a);
+        assert_ne!(EterraAi::<Test>::seed_for(&hash, Some(&2u32), 5), seed);
+        assert_ne!(EterraAi::<Test>::seed_for(&hash, Some(&1u32), 6), seed);
+        assert_ne!(EterraAi::<Test>::seed_for(&hash, None::<&u32>, 5), seed);
+
+        // What the runtime API serves emits nothing and leaves storage as it was.
+        let nim = NimState {
+            pile: 7,
+            to_move: 0,
+        };
+        let suggestion = EterraAi::<Test>::suggestion(&nim, Some(&1), 80);
+        assert!(suggestion.is_some());
+        assert_eq!(EterraAi::<Test>::suggestion(&nim, Some(&1), 80), suggestion);
+        assert!(frame_system::Pallet::<Test>::events().is_empty());
+        assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root);
+    });
+}
+
 #[test]
 fn nim_terminal_has_no_suggestion() {
     let mut ext = crate::mock::new_test_ext();
@@ -105,7 +149,7 @@ fn nim_terminal_has_no_suggestion() {
             pile: 0,
             to_move: 0,
         };
-        let a = EterraAi::<Test>::suggest::<crate::mock::NimAdapter>(&terminal, 50);
+        let a = EterraAi::<Test>::suggest::<crate::mock::NimAdapter>(&terminal, 50, 0);
         assert!(a.is_none());
     });
 }
@@ -129,24 +173,20 @@ fn eterra_adapter_ai_returns_legal_move_and_applies() {
         };
 
         // Build simple hands (5 entries each). Tweak values as needed.
-        let hand0 = Hand {
-            entries: [
-                mk(5, 4, 5, 4),
-                mk(4, 6, 4, 6),
-                mk(7, 3, 7, 3),
-                mk(4, 4, 4, 4),
-                mk(6, 2, 6, 2),
-            ],
-        };
-        let hand1 = Hand {
-            entries: [
-                mk(4, 5, 4, 5),
-                mk(6, 4, 6, 4),
-                mk(3, 7, 3, 7),
-                mk(4, 4, 4, 4),
-                mk(2, 6, 2, 6),
-            ],
-        };
+        let hand0 = Hand::new(&[
+            mk(5, 4, 5, 4),
+            mk(4, 6, 4, 6),
+            mk(7, 3, 7, 3),
+            mk(4, 4, 4, 4),
+            mk(6, 2, 6, 2),
+        ]);
+        let hand1 = Hand::new(&[
+            mk(4, 5, 4, 5),
+            mk(6, 4, 6, 4),
+            mk(3, 7, 3, 7),
+            mk(4, 4, 4, 4),
+            mk(2, 6, 2, 6),
+        ]);
 
         let s0 = State {
             board,
@@ -155,10 +195,12 @@ fn eterra_adapter_ai_returns_legal_move_and_applies() {
             round: 0,
             max_rounds: 10,
             hands: [hand0, hand1],
+            rules: Default::default(),
+            elements: Default::default(),
         };
 
         // Ask AI for a suggestion at moderate difficulty
-        let a = crate::pallet::Pallet::<crate::mock::Test>::suggest::<AdapterShim>(&s0, 60)
+        let a = crate::pallet::Pallet::<crate::mock::Test>::suggest::<AdapterShim>(&s0, 60, 0)
             .expect("AI should suggest a legal move");
 
         // Check basic legality
@@ -195,12 +237,20 @@ fn adapter_list_actions_respects_max_bound() {
             used: false,
         };
         let base = mk(1, 1, 1, 1);
-        let hand0 = Hand {
-            entries: core::array::from_fn(|_| base.clone()),
-        };
-        let hand1 = Hand {
-            entries: core::array::from_fn(|_| base.clone()),
-        };
+        let hand0 = Hand::new(&[
+            base.clone(),
+            base.clone(),
+            base.clone(),
+            base.clone(),
+            base.clone(),
+        ]);
+        let hand1 = Hand::new(&[
+            base.clone(),
+            base.clone(),
+            base.clone(),
+            base.clone(),
+            base.clone(),
+        ]);
         let s = State {
             board,
             scores: (5, 5),
@@ -208,6 +258,8 @@ fn adapter_list_actions_respects_max_bound() {
             round: 0,
             max_rounds: 10,
             hands: [hand0, hand1],
+            rules: Default::default(),
+            elements: Default::default(),
         };
 
         // With an empty 4x4, maximum distinct actions is 16 cells * 5 unused cards = 80.
@@ -263,18 +315,20 @@ fn ai_prefers_capture_when_available_high_difficulty() {
             west: 1,
             used: false,
         };
-        let hand0 = Hand {
-            entries: [
-                strong_left,
-                dummy.clone(),
-                dummy.clone(),
-                dummy.clone(),
-                dummy.clone(),
-            ],
-        };
-        let hand1 = Hand {
-            entries: core::array::from_fn(|_| dummy.clone()),
-        };
+        let hand0 = Hand::new(&[
+            strong_left,
+            dummy.clone(),
+            dummy.clone(),
+            dummy.clone(),
+            dummy.clone(),
+        ]);
+        let hand1 = Hand::new(&[
+            dummy.clone(),
+            dummy.clone(),
+            dummy.clone(),
+            dummy.clone(),
+            dummy.clone(),
+        ]);
 
         let s0 = State {
             board,
@@ -283,10 +337,12 @@ fn ai_prefers_capture_when_available_high_difficulty() {
             round: 0,
             max_rounds: 10,
             hands: [hand0, hand1],
+            rules: Default::default(),
+            elements: Default::default(),
         };
 
         // Suggest at high difficulty – should favor the capturing move at x=0,y=1 using hand_index=0
-        let a = crate::pallet::Pallet::<crate::mock::Test>::suggest::<AdapterShim>(&s0, 95)
+        let a = crate::pallet::Pallet::<crate::mock::Test>::suggest::<AdapterShim>(&s0, 95, 0)
             .expect("AI should suggest a move");
 
         // Apply the suggestion using pure helper so we avoid trait mismatch issues
@@ -305,3 +361,437 @@ fn ai_prefers_capture_when_available_high_difficulty() {
         }
     });
 }
+
+#[test]
+fn greedy_move_takes_the_capture_and_random_move_is_legal() {
+    use eterra_card_ai_adapter::eterra_adapter::{
+        Adapter, Board, Card as ACard, Hand, HandEntry, Possession, State,
+    };
+
+    let mut ext = crate::mock::new_test_ext();
+    ext.execute_with(|| {
+        let mut board: Board = Default::default();
+        board[1][1] = Some(ACard {
+            top: 3,
+            right: 3,
+            bottom: 3,
+            left: 2,
+            possession: Some(Possession::PlayerTwo),
+        });
+        let strong_right = HandEntry {
+            north: 1,
+            east: 5,
+            south: 1,
+            west: 1,
+            used: false,
+        };
+        let dummy = HandEntry {
+            north: 1,
+            east: 1,
+            south: 1,
+            west: 1,
+            used: false,
+        };
+        let s0 = State {
+            board,
+            scores: (5, 5),
+            player_turn: 0,
+            round: 0,
+            max_rounds: 10,
+            hands: [
+                Hand::new(&[
+                    strong_right,
+                    dummy.clone(),
+                    dummy.clone(),
+                    dummy.clone(),
+                    dummy.clone(),
+                ]),
+                Hand::new(&[
+                    dummy.clone(),
+                    dummy.clone(),
+                    dummy.clone(),
+                    dummy.clone(),
+                    dummy.clone(),
+                ]),
+            ],
+            rules: Default::default(),
+            elements: Default::default(),
+        };
+
+        // The only capture is the strong card played left of the opponent's.
+        let a = EterraAi::<crate::mock::Test>::greedy_move::<AdapterShim>(&s0).expect("greedy");
+        let s1 = Adapter::apply_pure(&s0, &a);
+        assert!(s1.scores.0 > s0.scores.0);
+
+        let mut legal: [Option<_>; 128] = core::array::from_fn(|_| None);
+        let n = Adapter::list_actions_pure::<128>(&s0, &mut legal);
+        let r = EterraAi::<crate::mock::Test>::random_move::<AdapterShim>(&s0, 0).expect("random");
+        assert!(legal[..n].contains(&Some(r)));
+    });
+}
+
+/// An opening on an empty 4x4 board with both hands dealt edges 1-9 from `seed`.
+fn eterra_position(seed: u64) -> eterra_card_ai_adapter::eterra_adapter::State {
+    use eterra_card_ai_adapter::eterra_adapter::{Hand, HandEntry, State};
+
+    let mut x = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    let mut rank = || {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        (x % 9 + 1) as u8
+    };
+    let mut hand = || {
+        let entries: Vec<HandEntry> = (0..5)
+            .map(|_| HandEntry {
+                north: rank(),
+                east: rank(),
+                south: rank(),
+                west: rank(),
+                used: false,
+            })
+            .collect();
+        Hand::new(&entries)
+    };
+
+    State {
+        board: Default::default(),
+        scores: (5, 5),
+        player_turn: 0,
+        round: 0,
+        max_rounds: 5,
+        hands: [hand(), hand()],
+        rules: Default::default(),
+        elements: Default::default(),
+    }
+}
+
+#[test]
+fn uct_beats_flat_search_on_fixed_eterra_positions() {
+    use crate::mock::Test;
+    use eterra_card_ai_adapter::eterra_adapter::Adapter;
+    use frame_support::traits::Get;
+
+    let mut ext = crate::mock::new_test_ext();
+    ext.execute_with(|| {
+        // Same budget on both sides, and each position is played from both seats.
+        let iterations = <Test as Config>::BaseIterations::get();
+        let (mut wins, mut losses) = (0, 0);
+        for seed in 0..8 {
+            for uct_player in 0..2u8 {
+                let mut s = eterra_position(seed);
+                loop {
+                    let a = if s.player_turn == uct_player {
+                        EterraAi::<Test>::search::<AdapterShim>(&s, iterations, seed)
+                    } else {
+                        EterraAi::<Test>::flat_search::<AdapterShim>(&s, iterations, seed)
+                    };
+                    match a {
+                        Some(a) => s = Adapter::apply_pure(&s, &a),
+                        None => break,
+                    }
+                }
+                match AdapterShim::score(&s, uct_player) {
+                    x if x > 0 => wins += 1,
+                    x if x < 0 => losses += 1,
+                    _ => {}
+                }
+            }
+        }
+        assert!(
+            wins > 2 * losses,
+            "UCT won {} and lost {} against flat search",
+            wins,
+            losses
+        );
+    });
+}
+
+/// `eterra_position(seed)` after `plies` random moves.
+fn eterra_endgame(seed: u64, plies: u64) -> eterra_card_ai_adapter::eterra_adapter::State {
+    let mut s = eterra_position(seed);
+    for ply in 0..plies {
+        let a = AdapterShim::random_action(&s, seed.wrapping_mul(31).wrapping_add(ply))
+            .expect("the game lasts ten plies");
+        s = AdapterShim::apply(&s, &a);
+    }
+    s
+}
+
+/// Plain minimax value of `s` for `me`, without pruning or a table.
+fn minimax(s: &eterra_card_ai_adapter::eterra_adapter::State, me: u8) -> i32 {
+    let mut buf: [Option<eterra_card_ai_adapter::eterra_adapter::Action>; 128] =
+        core::array::from_fn(|_| None);
+    let n = if AdapterShim::is_terminal(s) {
+        0
+    } else {
+        AdapterShim::list_actions::<128>(s, &mut buf)
+    };
+    if n == 0 {
+        return AdapterShim::score(s, me);
+    }
+    let values = buf
+        .iter()
+        .take(n)
+        .flatten()
+        .map(|a| minimax(&AdapterShim::apply(s, a), me));
+    if s.player_turn == me {
+        values.max().unwrap()
+    } else {
+        values.min().unwrap()
+    }
+}
+
+#[test]
+fn solver_plays_eterra_endgames_perfectly() {
+    for seed in 0..4 {
+        for plies in 6..9 {
+            let s = eterra_endgame(seed, plies);
+            let me = s.player_turn;
+            let a = crate::solver::solve::<AdapterShim>(&s, 1 << 16).expect("endgame is solvable");
+            assert_eq!(
+                minimax(&AdapterShim::apply(&s, &a), me),
+                minimax(&s, me),
+                "seed {} after {} plies",
+                seed,
+                plies
+            );
+        }
+    }
+}
+
+#[test]
+fn solver_gives_up_past_its_budget() {
+    let s = eterra_endgame(0, 6);
+    assert!(crate::solver::solve::<AdapterShim>(&s, 8).is_none());
+}
+
+#[test]
+fn search_switches_to_the_solver_below_the_threshold() {
+    let mut ext = crate::mock::new_test_ext();
+    ext.execute_with(|| {
+        use crate::mock::Test;
+
+        // Two cards each over ten empty cells: 20 actions, under the mock's threshold of 21.
+        let s = eterra_endgame(1, 6);
+        let solved = crate::solver::solve::<AdapterShim>(&s, u32::MAX);
+        assert!(solved.is_some());
+        assert_eq!(EterraAi::<Test>::suggest::<AdapterShim>(&s, 50, 0), solved);
+
+        // Nim adapters can't solve, so they keep sampling.
+        let nim = crate::mock::NimState {
+            pile: 3,
+            to_move: 0,
+        };
+        assert_eq!(
+            EterraAi::<Test>::suggest::<crate::mock::NimAdapter>(&nim, 95, 0),
+            Some(crate::mock::NimAction::Take1)
+        );
+    });
+}
+
+#[test]
+fn solve_or_suggest_solves_once_and_only_when_the_game_fits() {
+    let mut ext = crate::mock::new_test_ext();
+    ext.execute_with(|| {
+        use crate::mock::Test;
+
+        // Below the threshold `search` would try the solver as well; it runs once.
+        let s = eterra_endgame(1, 6);
+        let solved = crate::solver::solve::<AdapterShim>(&s, u32::MAX);
+        SOLVES.with(|n| n.set(0));
+        assert_eq!(
+            EterraAi::<Test>::solve_or_suggest::<AdapterShim>(&s, 50, 0),
+            solved
+        );
+        assert_eq!(SOLVES.with(|n| n.get()), 1);
+
+        // A fresh game is far past the budget, so it goes straight to the search.
+        SOLVES.with(|n| n.set(0));
+        assert!(
+            EterraAi::<Test>::solve_or_suggest::<AdapterShim>(&eterra_position(1), 50, 0).is_some()
+        );
+        assert_eq!(SOLVES.with(|n| n.get()), 0);
+    });
+}
+
+fn signed_move(
+    signer: u64,
+    game: u32,
+    state: &crate::mock::NimState,
+    action: crate::mock::NimAction,
+) -> crate::pallet::Call<crate::mock::Test> {
+    use sp_runtime::traits::Hash;
+    use sp_runtime::{app_crypto::RuntimeAppPublic, testing::UintAuthorityId};
+    let payload = crate::AiMovePayload {
+        public: UintAuthorityId(signer),
+        game,
+        state_hash: sp_runtime::traits::BlakeTwo256::hash_of(state),
+        block: 1,
+        action,
+    };
+    let signature = UintAuthorityId(signer)
+        .sign(&payload.encode())
+        .expect("test keys always sign");
+    crate::pallet::Call::submit_ai_move { payload, signature }
+}
+
+#[test]
+fn offchain_worker_submits_signed_ai_move() {
+    use crate::mock::{NimAction, NimState, NimTurns, RuntimeCall};
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+    use sp_runtime::testing::UintAuthorityId;
+
+    let (offchain, _) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = crate::mock::new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    UintAuthorityId::set_all_keys(vec![7u64]);
+
+    ext.execute_with(|| {
+        let state = NimState {
+            pile: 3,
+            to_move: 1,
+        };
+        NimTurns::set_awaiting(vec![(9, state.clone())]);
+        EterraAi::<crate::mock::Test>::offchain_worker(1);
+
+        let tx = pool_state
+            .write()
+            .transactions
+            .pop()
+            .expect("one move submitted");
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = crate::mock::Extrinsic::decode(&mut &*tx).unwrap();
+        assert!(tx.signature.is_none());
+        match tx.call {
+            RuntimeCall::EterraAi(crate::pallet::Call::submit_ai_move { payload, .. }) => {
+                assert_eq!(payload.public, UintAuthorityId(7));
+                assert_eq!(payload.game, 9);
+                assert_eq!(payload.block, 1);
+                assert_eq!(payload.action, NimAction::Take1);
+            }
+            other => panic!("unexpected call {:?}", other),
+        }
+
+        // The same position is not searched again while the move may still be pending.
+        EterraAi::<crate::mock::Test>::offchain_worker(2);
+        assert!(pool_state.read().transactions.is_empty());
+    });
+}
+
+#[test]
+fn submitted_ai_move_is_validated_and_played() {
+    use crate::mock::{NimAction, NimState, NimTurns, Test};
+    use frame_support::{assert_noop, pallet_prelude::*};
+
+    crate::mock::new_test_ext().execute_with(|| {
+        let state = NimState {
+            pile: 3,
+            to_move: 1,
+        };
+        NimTurns::set_awaiting(vec![(9, state.clone())]);
+        let call = signed_move(7, 9, &state, NimAction::Take1);
+
+        // Unknown signers are turned away by the pool.
+        assert_eq!(
+            <EterraAi<Test> as ValidateUnsigned>::validate_unsigned(
+                TransactionSource::External,
+                &call
+            ),
+            InvalidTransaction::BadSigner.into()
+        );
+
+        assert_ok!(EterraAi::<Test>::add_ai_signer(RuntimeOrigin::root(), 7));
+        assert_ok!(<EterraAi<Test> as ValidateUnsigned>::validate_unsigned(
+            TransactionSource::External,
+            &call
+        ));
+
+        // Moves for another state are stale even when signed correctly.
+        let stale = signed_move(
+            7,
+            9,
+            &NimState {
+                pile: 2,
+                to_move: 1,
+            },
+            NimAction::Take1,
+        );
+        assert_eq!(
+            <EterraAi<Test> as ValidateUnsigned>::validate_unsigned(
+                TransactionSource::External,
+                &stale
+            ),
+            InvalidTransaction::Stale.into()
+        );
+
+        let crate::pallet::Call::submit_ai_move { payload, signature } = call else {
+            unreachable!()
+        };
+        assert_ok!(EterraAi::<Test>::submit_ai_move(
+            RuntimeOrigin::none(),
+            payload.clone(),
+            signature.clone()
+        ));
+        assert_eq!(NimTurns::played(), vec![(9, NimAction::Take1)]);
+
+        // Once played, the game no longer waits on the AI.
+        assert_noop!(
+            EterraAi::<Test>::submit_ai_move(RuntimeOrigin::none(), payload, signature),
+            crate::pallet::Error::<Test>::NotAwaitingAi
+        );
+    });
+}
+
+#[test]
+fn illegal_ai_move_is_rejected() {
+    use crate::mock::{NimAction, NimState, NimTurns, Test};
+    use frame_support::assert_noop;
+
+    crate::mock::new_test_ext().execute_with(|| {
+        let state = NimState {
+            pile: 1,
+            to_move: 1,
+        };
+        NimTurns::set_awaiting(vec![(3, state.clone())]);
+        let crate::pallet::Call::submit_ai_move { payload, signature } =
+            signed_move(7, 3, &state, NimAction::Take2)
+        else {
+            unreachable!()
+        };
+        assert_noop!(
+            EterraAi::<Test>::submit_ai_move(RuntimeOrigin::none(), payload, signature),
+            crate::pallet::Error::<Test>::IllegalAction
+        );
+        assert!(NimTurns::played().is_empty());
+    });
+}
+
+#[test]
+fn only_admin_manages_ai_signers() {
+    use crate::mock::Test;
+    use frame_support::assert_noop;
+    use sp_runtime::DispatchError;
+
+    crate::mock::new_test_ext().execute_with(|| {
+        assert_noop!(
+            EterraAi::<Test>::add_ai_signer(RuntimeOrigin::signed(1), 7),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(EterraAi::<Test>::add_ai_signer(RuntimeOrigin::root(), 7));
+        assert!(crate::pallet::AiSigners::<Test>::contains_key(7));
+        assert_noop!(
+            EterraAi::<Test>::add_ai_signer(RuntimeOrigin::root(), 7),
+            crate::pallet::Error::<Test>::AlreadyAiSigner
+        );
+        assert_ok!(EterraAi::<Test>::remove_ai_signer(RuntimeOrigin::root(), 7));
+        assert_noop!(
+            EterraAi::<Test>::remove_ai_signer(RuntimeOrigin::root(), 7),
+            crate::pallet::Error::<Test>::NotAiSigner
+        );
+    });
+}
@@ -0,0 +1,89 @@
+//! Weights for pallet_eterra_monte_carlo_ai
+//!
+//! These values are estimates, not benchmark results. They were worked out by hand from
+//! the storage each call touches, sized for the worst cases set up in `benchmarking.rs`.
+//! Replace them with measured weights from reference hardware before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_eterra_monte_carlo_ai.
+pub trait WeightInfo {
+	fn suggest_move(d: u32) -> Weight;
+	fn submit_ai_move() -> Weight;
+	fn add_ai_signer() -> Weight;
+	fn remove_ai_signer() -> Weight;
+}
+
+/// Estimated weights for pallet_eterra_monte_carlo_ai.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn suggest_move(d: u32) -> Weight {
+		// The range of component `d` is `[0, 255]`.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(420_000, 0).saturating_mul(d.into()))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameRules (r:1 w:0)
+	/// Storage: Eterra GameElements (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:2 w:1)
+	/// Storage: Eterra MoveLog (r:1 w:1)
+	/// Storage: Eterra TurnTimeouts (r:1 w:1)
+	/// Storage: EterraRating PveRecords (r:1 w:1)
+	fn submit_ai_move() -> Weight {
+		Weight::from_parts(58_000_000, 9510)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: EterraMonteCarloAi AiSigners (r:1 w:1)
+	fn add_ai_signer() -> Weight {
+		Weight::from_parts(12_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraMonteCarloAi AiSigners (r:1 w:1)
+	fn remove_ai_signer() -> Weight {
+		Weight::from_parts(12_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn suggest_move(d: u32) -> Weight {
+		// The range of component `d` is `[0, 255]`.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(420_000, 0).saturating_mul(d.into()))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameRules (r:1 w:0)
+	/// Storage: Eterra GameElements (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:2 w:1)
+	/// Storage: Eterra MoveLog (r:1 w:1)
+	/// Storage: Eterra TurnTimeouts (r:1 w:1)
+	/// Storage: EterraRating PveRecords (r:1 w:1)
+	fn submit_ai_move() -> Weight {
+		Weight::from_parts(58_000_000, 9510)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: EterraMonteCarloAi AiSigners (r:1 w:1)
+	fn add_ai_signer() -> Weight {
+		Weight::from_parts(12_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraMonteCarloAi AiSigners (r:1 w:1)
+	fn remove_ai_signer() -> Weight {
+		Weight::from_parts(12_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,58 @@
+//! Benchmarking setup for pallet-eterra-randomness
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as EterraRandomness;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{One, Saturating};
+
+const SECRET: [u8; 32] = [7; 32];
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// The caller replaces a commitment whose window has passed.
+    #[benchmark]
+    fn commit() {
+        let caller: T::AccountId = whitelisted_caller();
+        let stale = EterraRandomness::<T>::commitment(&caller, &[0; 32]);
+        Commitments::<T>::insert(&caller, (stale, BlockNumberFor::<T>::from(0u32)));
+        frame_system::Pallet::<T>::set_block_number(
+            T::RevealWindow::get().saturating_add(One::one()),
+        );
+        let commitment = EterraRandomness::<T>::commitment(&caller, &SECRET);
+
+        #[extrinsic_call]
+        commit(RawOrigin::Signed(caller.clone()), commitment);
+
+        assert_eq!(
+            Commitments::<T>::get(&caller).map(|(c, _)| c),
+            Some(commitment)
+        );
+    }
+
+    #[benchmark]
+    fn reveal() {
+        let caller: T::AccountId = whitelisted_caller();
+        let commitment = EterraRandomness::<T>::commitment(&caller, &SECRET);
+        Commitments::<T>::insert(&caller, (commitment, BlockNumberFor::<T>::from(1u32)));
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+        let seed = Seed::<T>::get();
+
+        #[extrinsic_call]
+        reveal(RawOrigin::Signed(caller.clone()), SECRET);
+
+        assert!(!Commitments::<T>::contains_key(&caller));
+        assert_ne!(Seed::<T>::get(), seed);
+    }
+
+    impl_benchmark_test_suite!(
+        EterraRandomness,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
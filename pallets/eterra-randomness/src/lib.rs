@@ -0,0 +1,171 @@
+//! # Eterra Randomness
+//!
+//! Commit-reveal randomness for the Eterra pallets, served through
+//! [`frame_support::traits::Randomness`].
+//!
+//! Any account can `commit` to the hash of a secret and `reveal` the secret from the next block
+//! until `RevealWindow` blocks later. Every revealed secret is folded into a running seed, which
+//! also absorbs the parent block hash each block, collective-flip style.
+//!
+//! The seed is public storage, so anyone can compute an output from it the moment it is
+//! drawn. What keeps a draw from being known in advance is when it is made, not how:
+//! consumers record the block a draw was requested in and take it only once `random` returns
+//! a later block. By then the seed has absorbed the hash of the requesting block and any
+//! reveals since, neither of which was known when the request was signed. The block author
+//! can still grind the parent hash, and the last revealer can choose to hold their secret
+//! back, so the seed is only as unpredictable as its most honest contributor.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{pallet_prelude::*, traits::Randomness};
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Hash, Saturating};
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Blocks after a commitment during which its secret can be revealed.
+        #[pallet::constant]
+        type RevealWindow: Get<BlockNumberFor<Self>>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Running seed every output is derived from.
+    #[pallet::storage]
+    #[pallet::getter(fn seed)]
+    pub type Seed<T: Config> = StorageValue<_, T::Hash, ValueQuery>;
+
+    /// Block `Seed` was last refreshed in; returned by `random` with every output.
+    #[pallet::storage]
+    #[pallet::getter(fn seeded_at)]
+    pub type SeededAt<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Block of the latest reveal folded into `Seed`.
+    #[pallet::storage]
+    #[pallet::getter(fn last_reveal)]
+    pub type LastReveal<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Pending commitment per account: the hash of `(account, secret)` and the block it was made in.
+    #[pallet::storage]
+    #[pallet::getter(fn commitment_of)]
+    pub type Commitments<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (T::Hash, BlockNumberFor<T>), OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `who` committed to a secret.
+        Committed { who: T::AccountId },
+        /// `who` revealed their secret and it was mixed into the seed.
+        Revealed { who: T::AccountId },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The caller already has a commitment that can still be revealed.
+        AlreadyCommitted,
+        /// The caller has nothing to reveal.
+        NoCommitment,
+        /// Secrets can only be revealed from the block after their commitment.
+        RevealTooEarly,
+        /// The reveal window of the commitment has passed; commit again.
+        CommitmentExpired,
+        /// The secret doesn't match the commitment.
+        BadReveal,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let parent = frame_system::Pallet::<T>::parent_hash();
+            Seed::<T>::mutate(|seed| *seed = T::Hashing::hash_of(&(*seed, parent)));
+            SeededAt::<T>::put(n);
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Commit to a secret by its [`Pallet::commitment`]. Replaces an expired commitment.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::commit())]
+        pub fn commit(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some((_, at)) = Commitments::<T>::get(&who) {
+                ensure!(
+                    now > at.saturating_add(T::RevealWindow::get()),
+                    Error::<T>::AlreadyCommitted
+                );
+            }
+            Commitments::<T>::insert(&who, (commitment, now));
+            Self::deposit_event(Event::Committed { who });
+            Ok(())
+        }
+
+        /// Reveal the secret behind the caller's commitment and mix it into the seed.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::reveal())]
+        pub fn reveal(origin: OriginFor<T>, secret: [u8; 32]) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let (commitment, at) = Commitments::<T>::get(&who).ok_or(Error::<T>::NoCommitment)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now > at, Error::<T>::RevealTooEarly);
+            ensure!(
+                now <= at.saturating_add(T::RevealWindow::get()),
+                Error::<T>::CommitmentExpired
+            );
+            ensure!(
+                Self::commitment(&who, &secret) == commitment,
+                Error::<T>::BadReveal
+            );
+
+            Commitments::<T>::remove(&who);
+            Seed::<T>::mutate(|seed| *seed = T::Hashing::hash_of(&(*seed, secret)));
+            LastReveal::<T>::put(now);
+            Self::deposit_event(Event::Revealed { who });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// What `who` commits to for `secret`. Binding the account stops others from replaying a
+        /// commitment and its reveal as their own.
+        pub fn commitment(who: &T::AccountId, secret: &[u8; 32]) -> T::Hash {
+            T::Hashing::hash_of(&(who, secret))
+        }
+    }
+
+    impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
+        /// `subject` hashed with the current seed, and the block the seed was refreshed in.
+        /// Only outputs whose block is later than the request they serve are safe to use.
+        fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+            (
+                T::Hashing::hash_of(&(Seed::<T>::get(), subject)),
+                SeededAt::<T>::get(),
+            )
+        }
+    }
+}
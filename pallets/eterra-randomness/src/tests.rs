@@ -0,0 +1,163 @@
+use crate::{mock::*, Commitments, Error, Event, LastReveal, Seed, SeededAt};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Hooks, Randomness},
+};
+use sp_core::H256;
+
+const SECRET: [u8; 32] = [7; 32];
+
+fn commit(who: u64, secret: &[u8; 32]) {
+    assert_ok!(EterraRandomness::commit(
+        RuntimeOrigin::signed(who),
+        EterraRandomness::commitment(&who, secret)
+    ));
+}
+
+#[test]
+fn reveal_mixes_the_secret_into_the_seed() {
+    new_test_ext().execute_with(|| {
+        commit(ALICE, &SECRET);
+        System::set_block_number(2);
+        let before = Seed::<Test>::get();
+
+        assert_ok!(EterraRandomness::reveal(
+            RuntimeOrigin::signed(ALICE),
+            SECRET
+        ));
+
+        assert_ne!(Seed::<Test>::get(), before);
+        assert_eq!(LastReveal::<Test>::get(), 2);
+        assert!(!Commitments::<Test>::contains_key(ALICE));
+        System::assert_last_event(Event::Revealed { who: ALICE }.into());
+    });
+}
+
+#[test]
+fn reveals_must_match_and_fall_inside_the_window() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EterraRandomness::reveal(RuntimeOrigin::signed(ALICE), SECRET),
+            Error::<Test>::NoCommitment
+        );
+
+        commit(ALICE, &SECRET);
+        assert_noop!(
+            EterraRandomness::reveal(RuntimeOrigin::signed(ALICE), SECRET),
+            Error::<Test>::RevealTooEarly
+        );
+
+        System::set_block_number(2);
+        assert_noop!(
+            EterraRandomness::reveal(RuntimeOrigin::signed(ALICE), [8; 32]),
+            Error::<Test>::BadReveal
+        );
+
+        System::set_block_number(12);
+        assert_noop!(
+            EterraRandomness::reveal(RuntimeOrigin::signed(ALICE), SECRET),
+            Error::<Test>::CommitmentExpired
+        );
+    });
+}
+
+#[test]
+fn commitments_are_bound_to_their_account() {
+    new_test_ext().execute_with(|| {
+        // Bob copies Alice's commitment but can't open it with her secret.
+        assert_ok!(EterraRandomness::commit(
+            RuntimeOrigin::signed(BOB),
+            EterraRandomness::commitment(&ALICE, &SECRET)
+        ));
+        System::set_block_number(2);
+        assert_noop!(
+            EterraRandomness::reveal(RuntimeOrigin::signed(BOB), SECRET),
+            Error::<Test>::BadReveal
+        );
+    });
+}
+
+#[test]
+fn a_live_commitment_blocks_a_new_one_until_it_expires() {
+    new_test_ext().execute_with(|| {
+        commit(ALICE, &SECRET);
+        System::set_block_number(11);
+        assert_noop!(
+            EterraRandomness::commit(
+                RuntimeOrigin::signed(ALICE),
+                EterraRandomness::commitment(&ALICE, &[8; 32])
+            ),
+            Error::<Test>::AlreadyCommitted
+        );
+
+        System::set_block_number(12);
+        commit(ALICE, &[8; 32]);
+        assert_eq!(Commitments::<Test>::get(ALICE).map(|(_, at)| at), Some(12));
+    });
+}
+
+#[test]
+fn outputs_follow_the_secrets_revealed() {
+    let output_after = |secret: [u8; 32]| {
+        new_test_ext().execute_with(|| {
+            commit(ALICE, &secret);
+            System::set_block_number(2);
+            assert_ok!(EterraRandomness::reveal(
+                RuntimeOrigin::signed(ALICE),
+                secret
+            ));
+            EterraRandomness::random(b"subject").0
+        })
+    };
+    let before = new_test_ext().execute_with(|| EterraRandomness::random(b"subject").0);
+
+    // Same chain, same block, same subject: only the secret differs.
+    let a = output_after([1; 32]);
+    let b = output_after([2; 32]);
+    assert_ne!(a, b);
+    assert_ne!(a, before);
+    assert_ne!(b, before);
+
+    new_test_ext().execute_with(|| {
+        assert_ne!(
+            EterraRandomness::random(b"subject").0,
+            EterraRandomness::random(b"other").0
+        );
+    });
+}
+
+#[test]
+fn each_block_mixes_in_its_parent_hash() {
+    new_test_ext().execute_with(|| {
+        System::set_parent_hash(H256::repeat_byte(1));
+        EterraRandomness::on_initialize(1);
+        let first = Seed::<Test>::get();
+        assert_ne!(first, H256::zero());
+
+        System::set_parent_hash(H256::repeat_byte(2));
+        EterraRandomness::on_initialize(2);
+        assert_ne!(Seed::<Test>::get(), first);
+    });
+}
+
+#[test]
+fn outputs_carry_the_block_the_seed_was_refreshed_in() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(EterraRandomness::random(b"subject").1, 0);
+
+        EterraRandomness::on_initialize(1);
+        assert_eq!(SeededAt::<Test>::get(), 1);
+        assert_eq!(EterraRandomness::random(b"subject").1, 1);
+
+        // Reveals change the seed but not the block: a draw requested in block 2 still has
+        // to wait for block 3.
+        commit(ALICE, &SECRET);
+        System::set_block_number(2);
+        EterraRandomness::on_initialize(2);
+        assert_ok!(EterraRandomness::reveal(
+            RuntimeOrigin::signed(ALICE),
+            SECRET
+        ));
+        assert_eq!(EterraRandomness::random(b"subject").1, 2);
+    });
+}
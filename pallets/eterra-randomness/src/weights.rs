@@ -0,0 +1,55 @@
+//! Weights for pallet_eterra_randomness
+//!
+//! These values are estimates, not benchmark results. They were worked out by hand from
+//! the storage each call touches, sized for the worst cases set up in `benchmarking.rs`.
+//! Replace them with measured weights from reference hardware before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_eterra_randomness.
+pub trait WeightInfo {
+	fn commit() -> Weight;
+	fn reveal() -> Weight;
+}
+
+/// Estimated weights for pallet_eterra_randomness.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: EterraRandomness Commitments (r:1 w:1)
+	fn commit() -> Weight {
+		Weight::from_parts(17_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraRandomness Commitments (r:1 w:1)
+	/// Storage: EterraRandomness Seed (r:1 w:1)
+	/// Storage: EterraRandomness LastReveal (r:0 w:1)
+	fn reveal() -> Weight {
+		Weight::from_parts(24_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: EterraRandomness Commitments (r:1 w:1)
+	fn commit() -> Weight {
+		Weight::from_parts(17_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraRandomness Commitments (r:1 w:1)
+	/// Storage: EterraRandomness Seed (r:1 w:1)
+	/// Storage: EterraRandomness LastReveal (r:0 w:1)
+	fn reveal() -> Weight {
+		Weight::from_parts(24_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
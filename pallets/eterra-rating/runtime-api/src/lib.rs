@@ -0,0 +1,22 @@
+//! Runtime API for reading Eterra player ratings.
+//!
+//! Lets clients fetch a player's Glicko-2 rating and records without decoding the pallet's
+//! storage. Players who never finished a game get the starting rating and empty records.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+
+pub use pallet_eterra_rating::{PlayerRating, Record};
+
+sp_api::decl_runtime_apis! {
+    pub trait RatingApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Glicko-2 rating and PvP record of `who`.
+        fn rating(who: AccountId) -> PlayerRating;
+        /// Record of `who` in games against the AI.
+        fn pve_record(who: AccountId) -> Record;
+    }
+}
@@ -0,0 +1,192 @@
+//! Glicko-2 (Glickman, "Example of the Glicko-2 system") with every game its own rating
+//! period. All values are integers scaled by [`ONE`], the accuracy of `FixedI64`, so every
+//! node computes the same ratings.
+
+/// `1.0`.
+pub const ONE: i128 = 1_000_000_000;
+/// Rating of new players.
+pub const INITIAL_RATING: i128 = 1_500 * ONE;
+/// Rating deviation of new players, and the most uncertain a rating gets.
+pub const INITIAL_DEVIATION: i128 = 350 * ONE;
+/// Volatility of new players.
+pub const INITIAL_VOLATILITY: i128 = 60_000_000;
+
+/// Ratio between ratings and the Glicko-2 scale, `400 / ln 10`.
+const SCALE: i128 = 173_717_792_761;
+const LN_2: i128 = 693_147_181;
+const PI_SQUARED: i128 = 9_869_604_401;
+/// How close the volatility iteration gets before it stops.
+const EPSILON: i128 = 1_000;
+/// Upper bound on the steps of each loop of the volatility iteration.
+const MAX_STEPS: u32 = 64;
+/// Lower bound on `g² E (1 - E)`, keeping the estimated variance finite between players
+/// rated too far apart for either result to say anything.
+const MIN_INFORMATION: i128 = 100_000;
+
+/// A player's rating, deviation and volatility, scaled by [`ONE`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Glicko {
+    pub rating: i128,
+    pub deviation: i128,
+    pub volatility: i128,
+}
+
+impl Default for Glicko {
+    fn default() -> Self {
+        Self {
+            rating: INITIAL_RATING,
+            deviation: INITIAL_DEVIATION,
+            volatility: INITIAL_VOLATILITY,
+        }
+    }
+}
+
+impl Glicko {
+    /// The player's rating after one game against `opponent`, scoring `score`: [`ONE`] for a
+    /// win, half of it for a draw and zero for a loss. `tau` constrains how fast volatility
+    /// changes.
+    pub fn after(&self, opponent: &Glicko, score: i128, tau: i128) -> Glicko {
+        let mu = div(self.rating - INITIAL_RATING, SCALE);
+        let phi = div(self.deviation, SCALE);
+        let mu_j = div(opponent.rating - INITIAL_RATING, SCALE);
+        let phi_j = div(opponent.deviation, SCALE);
+
+        let g = g(phi_j);
+        let e = expected(mu, mu_j, g);
+        let v = div(ONE, mul(mul(g, g), mul(e, ONE - e)).max(MIN_INFORMATION));
+        let delta = mul(v, mul(g, score - e));
+
+        let sigma = volatility(phi, self.volatility, delta, v, tau);
+        let phi_star = sqrt(mul(phi, phi) + mul(sigma, sigma));
+        let phi = div(ONE, sqrt(div(ONE, mul(phi_star, phi_star)) + div(ONE, v)));
+        let mu = mu + mul(mul(phi, phi), mul(g, score - e));
+
+        Glicko {
+            rating: INITIAL_RATING + mul(mu, SCALE),
+            deviation: mul(phi, SCALE).min(INITIAL_DEVIATION),
+            volatility: sigma,
+        }
+    }
+}
+
+/// Weight of a game against an opponent with deviation `phi`: less the less certain they are.
+fn g(phi: i128) -> i128 {
+    div(ONE, sqrt(ONE + div(3 * mul(phi, phi), PI_SQUARED)))
+}
+
+/// Expected score against an opponent at `mu_j`, weighted by `g`.
+fn expected(mu: i128, mu_j: i128, g: i128) -> i128 {
+    div(ONE, ONE + exp(-mul(g, mu - mu_j)))
+}
+
+/// New volatility, by the Illinois algorithm of step 5 of the paper.
+fn volatility(phi: i128, sigma: i128, delta: i128, v: i128, tau: i128) -> i128 {
+    let a = ln(mul(sigma, sigma));
+    let (delta2, phi2) = (mul(delta, delta), mul(phi, phi));
+    let tau2 = mul(tau, tau).max(1);
+    let f = |x: i128| {
+        let ex = exp(x);
+        let d = phi2 + v + ex;
+        div(mul(ex, delta2 - phi2 - v - ex), 2 * mul(d, d)) - div(x - a, tau2)
+    };
+
+    let mut big_a = a;
+    let mut big_b = if delta2 > phi2 + v {
+        ln(delta2 - phi2 - v)
+    } else {
+        let mut k = 1;
+        while f(a - k * tau) < 0 && k < MAX_STEPS as i128 {
+            k += 1;
+        }
+        a - k * tau
+    };
+
+    let (mut f_a, mut f_b) = (f(big_a), f(big_b));
+    for _ in 0..MAX_STEPS {
+        if (big_b - big_a).abs() <= EPSILON || f_b == f_a {
+            break;
+        }
+        let c = big_a + div(mul(big_a - big_b, f_a), f_b - f_a);
+        let f_c = f(c);
+        if f_c.signum() * f_b.signum() <= 0 {
+            big_a = big_b;
+            f_a = f_b;
+        } else {
+            f_a /= 2;
+        }
+        big_b = c;
+        f_b = f_c;
+    }
+    exp(big_a / 2)
+}
+
+fn mul(a: i128, b: i128) -> i128 {
+    a * b / ONE
+}
+
+fn div(a: i128, b: i128) -> i128 {
+    a * ONE / b
+}
+
+fn sqrt(x: i128) -> i128 {
+    if x <= 0 {
+        return 0;
+    }
+    let n = x * ONE;
+    let mut r = 1i128 << ((128 - n.leading_zeros()) / 2 + 1);
+    loop {
+        let next = (r + n / r) / 2;
+        if next >= r {
+            return r;
+        }
+        r = next;
+    }
+}
+
+/// `e^x`, from a Taylor series around the nearest multiple of `ln 2`.
+fn exp(x: i128) -> i128 {
+    if x < -40 * ONE {
+        return 0;
+    }
+    let x = x.min(40 * ONE);
+    let k = (x + x.signum() * LN_2 / 2) / LN_2;
+    let r = x - k * LN_2;
+    let (mut sum, mut term) = (ONE, ONE);
+    for n in 1..24 {
+        term = mul(term, r) / n;
+        if term == 0 {
+            break;
+        }
+        sum += term;
+    }
+    if k >= 0 {
+        sum << k
+    } else {
+        sum >> -k
+    }
+}
+
+/// Natural log of `x > 0`, from the series of `atanh` once `x` is scaled into `[1, 2)`.
+fn ln(x: i128) -> i128 {
+    if x <= 0 {
+        return -40 * ONE;
+    }
+    let (mut m, mut k) = (x, 0i128);
+    while m >= 2 * ONE {
+        m >>= 1;
+        k += 1;
+    }
+    while m < ONE {
+        m <<= 1;
+        k -= 1;
+    }
+    let y = div(m - ONE, m + ONE);
+    let y2 = mul(y, y);
+    let (mut sum, mut term, mut n) = (0, y, 1);
+    while term != 0 {
+        sum += term / n;
+        term = mul(term, y2);
+        n += 2;
+    }
+    2 * sum + k * LN_2
+}
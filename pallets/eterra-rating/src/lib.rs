@@ -0,0 +1,225 @@
+//! # Eterra Rating
+//!
+//! Glicko-2 ratings of Eterra players, fed by the results of finished games through
+//! [`OnGameFinished`].
+//!
+//! Every PvP game is its own rating period: both players' rating, deviation and volatility are
+//! updated against each other's values from before the game, alongside win, loss and draw
+//! counts and the current streak. Games against the AI say nothing about how players compare,
+//! so they only go into a separate PvE record.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod glicko;
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{FixedI64, FixedPointNumber};
+
+/// How a finished game went for one player.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl Outcome {
+    /// The same game seen from the other side.
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Win => Self::Loss,
+            Self::Draw => Self::Draw,
+            Self::Loss => Self::Win,
+        }
+    }
+
+    /// Glicko score of the outcome, scaled by [`glicko::ONE`].
+    fn score(self) -> i128 {
+        match self {
+            Self::Win => glicko::ONE,
+            Self::Draw => glicko::ONE / 2,
+            Self::Loss => 0,
+        }
+    }
+}
+
+/// Told about every finished game by the game pallet.
+pub trait OnGameFinished<AccountId> {
+    /// A game between two players ended with `outcome` for `a`.
+    fn on_pvp_finished(a: &AccountId, b: &AccountId, outcome: Outcome);
+    /// `player` finished a game against the AI with `outcome`.
+    fn on_pve_finished(player: &AccountId, outcome: Outcome);
+}
+
+impl<AccountId> OnGameFinished<AccountId> for () {
+    fn on_pvp_finished(_a: &AccountId, _b: &AccountId, _outcome: Outcome) {}
+    fn on_pve_finished(_player: &AccountId, _outcome: Outcome) {}
+}
+
+/// Finished games of a player and how their latest results ran.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug, Default)]
+pub struct Record {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    /// Consecutive wins if positive, consecutive losses if negative; a draw resets it.
+    pub streak: i32,
+    /// Most consecutive wins so far.
+    pub best_streak: u32,
+}
+
+impl Record {
+    /// Count one more game ending in `outcome`.
+    pub fn add(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Win => {
+                self.wins = self.wins.saturating_add(1);
+                self.streak = self.streak.max(0).saturating_add(1);
+                self.best_streak = self.best_streak.max(self.streak as u32);
+            }
+            Outcome::Draw => {
+                self.draws = self.draws.saturating_add(1);
+                self.streak = 0;
+            }
+            Outcome::Loss => {
+                self.losses = self.losses.saturating_add(1);
+                self.streak = self.streak.min(0).saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// A player's Glicko-2 rating with their PvP record. New players start at 1500 ± 350.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct PlayerRating {
+    pub rating: FixedI64,
+    /// How uncertain `rating` is; shrinks with every game and grows with `volatility`.
+    pub deviation: FixedI64,
+    /// How erratic the player's results are.
+    pub volatility: FixedI64,
+    pub record: Record,
+}
+
+impl Default for PlayerRating {
+    fn default() -> Self {
+        Self::from_glicko(glicko::Glicko::default(), Record::default())
+    }
+}
+
+impl PlayerRating {
+    fn glicko(&self) -> glicko::Glicko {
+        glicko::Glicko {
+            rating: self.rating.into_inner() as i128,
+            deviation: self.deviation.into_inner() as i128,
+            volatility: self.volatility.into_inner() as i128,
+        }
+    }
+
+    fn from_glicko(g: glicko::Glicko, record: Record) -> Self {
+        let fixed =
+            |x: i128| FixedI64::from_inner(x.clamp(i64::MIN as i128, i64::MAX as i128) as i64);
+        Self {
+            rating: fixed(g.rating),
+            deviation: fixed(g.deviation),
+            volatility: fixed(g.volatility),
+            record,
+        }
+    }
+
+    /// The rating after a game against `opponent` that ended with `outcome`.
+    pub fn after(&self, opponent: &PlayerRating, outcome: Outcome, tau: FixedI64) -> Self {
+        let g = self.glicko().after(
+            &opponent.glicko(),
+            outcome.score(),
+            tau.into_inner() as i128,
+        );
+        let mut record = self.record;
+        record.add(outcome);
+        Self::from_glicko(g, record)
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Glicko-2 system constant τ: how much volatility may change after a game. Glickman
+        /// suggests 0.3 to 1.2; lower values keep ratings steadier.
+        #[pallet::constant]
+        type Tau: Get<FixedI64>;
+    }
+
+    /// Rating and PvP record per player; players without one are rated 1500 ± 350.
+    #[pallet::storage]
+    #[pallet::getter(fn rating_of)]
+    pub type Ratings<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, PlayerRating, ValueQuery>;
+
+    /// Record of games against the AI per player. These don't move ratings.
+    #[pallet::storage]
+    #[pallet::getter(fn pve_record_of)]
+    pub type PveRecords<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Record, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A PvP game ended with `outcome` for `who`, whose rating is now `rating` ± `deviation`.
+        RatingUpdated {
+            who: T::AccountId,
+            outcome: Outcome,
+            rating: FixedI64,
+            deviation: FixedI64,
+        },
+        /// A game of `who` against the AI ended with `outcome`.
+        PveResultRecorded { who: T::AccountId, outcome: Outcome },
+    }
+
+    impl<T: Config> OnGameFinished<T::AccountId> for Pallet<T> {
+        fn on_pvp_finished(a: &T::AccountId, b: &T::AccountId, outcome: Outcome) {
+            if a == b {
+                return;
+            }
+            // Both sides are rated against the other's rating from before the game.
+            let (before_a, before_b) = (Ratings::<T>::get(a), Ratings::<T>::get(b));
+            for (who, me, opponent, outcome) in [
+                (a, &before_a, &before_b, outcome),
+                (b, &before_b, &before_a, outcome.reverse()),
+            ] {
+                let after = me.after(opponent, outcome, T::Tau::get());
+                Ratings::<T>::insert(who, after);
+                Self::deposit_event(Event::RatingUpdated {
+                    who: who.clone(),
+                    outcome,
+                    rating: after.rating,
+                    deviation: after.deviation,
+                });
+            }
+        }
+
+        fn on_pve_finished(player: &T::AccountId, outcome: Outcome) {
+            PveRecords::<T>::mutate(player, |record| record.add(outcome));
+            Self::deposit_event(Event::PveResultRecorded {
+                who: player.clone(),
+                outcome,
+            });
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{mock::*, Event, OnGameFinished, Outcome, PlayerRating, PveRecords, Ratings, Record};
+use sp_runtime::{FixedI64, FixedPointNumber};
+
+/// `x` rounded to two decimals, for comparing with values worked out in floating point.
+fn hundredths(x: FixedI64) -> i64 {
+    (x.into_inner() + 5_000_000) / 10_000_000
+}
+
+fn rated(rating: u32, deviation: u32) -> PlayerRating {
+    PlayerRating {
+        rating: FixedI64::from_u32(rating),
+        deviation: FixedI64::from_u32(deviation),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn pvp_results_move_both_ratings_and_records() {
+    new_test_ext().execute_with(|| {
+        EterraRating::on_pvp_finished(&ALICE, &BOB, Outcome::Win);
+
+        // Two new players: the winner gains what the loser drops, and both grow more certain.
+        let (alice, bob) = (Ratings::<Test>::get(ALICE), Ratings::<Test>::get(BOB));
+        assert_eq!(hundredths(alice.rating), 166_231);
+        assert_eq!(hundredths(bob.rating), 133_769);
+        assert_eq!(hundredths(alice.deviation), 29_032);
+        assert_eq!(alice.deviation, bob.deviation);
+        assert_eq!(alice.record.wins, 1);
+        assert_eq!(bob.record.losses, 1);
+        System::assert_has_event(
+            Event::RatingUpdated {
+                who: BOB,
+                outcome: Outcome::Loss,
+                rating: bob.rating,
+                deviation: bob.deviation,
+            }
+            .into(),
+        );
+
+        // PvP games leave the PvE record alone.
+        assert_eq!(PveRecords::<Test>::get(ALICE), Record::default());
+    });
+}
+
+#[test]
+fn updates_match_glicko_2_worked_out_in_floating_point() {
+    new_test_ext().execute_with(|| {
+        // The first game of the example in Glickman's paper, as its own rating period.
+        Ratings::<Test>::insert(ALICE, rated(1500, 200));
+        Ratings::<Test>::insert(BOB, rated(1400, 30));
+
+        EterraRating::on_pvp_finished(&ALICE, &BOB, Outcome::Win);
+
+        let alice = Ratings::<Test>::get(ALICE);
+        assert_eq!(hundredths(alice.rating), 156_356);
+        assert_eq!(hundredths(alice.deviation), 17_540);
+        assert_eq!(hundredths(alice.volatility), 6);
+
+        // The favourite's draw against a weaker player costs them.
+        Ratings::<Test>::insert(ALICE, rated(1500, 200));
+        Ratings::<Test>::insert(BOB, rated(1400, 30));
+        EterraRating::on_pvp_finished(&BOB, &ALICE, Outcome::Draw);
+        assert_eq!(hundredths(Ratings::<Test>::get(ALICE).rating), 147_541);
+        assert!(Ratings::<Test>::get(BOB).rating > FixedI64::from_u32(1400));
+    });
+}
+
+#[test]
+fn streaks_run_until_the_result_changes() {
+    let mut record = Record::default();
+    for outcome in [Outcome::Win, Outcome::Win, Outcome::Win, Outcome::Loss] {
+        record.add(outcome);
+    }
+    assert_eq!(record.streak, -1);
+    assert_eq!(record.best_streak, 3);
+    record.add(Outcome::Loss);
+    assert_eq!(record.streak, -2);
+    record.add(Outcome::Draw);
+    assert_eq!(record.streak, 0);
+    record.add(Outcome::Win);
+    assert_eq!((record.wins, record.losses, record.draws), (4, 2, 1));
+    assert_eq!((record.streak, record.best_streak), (1, 3));
+}
+
+#[test]
+fn pve_results_only_go_into_the_pve_record() {
+    new_test_ext().execute_with(|| {
+        EterraRating::on_pve_finished(&ALICE, Outcome::Win);
+        EterraRating::on_pve_finished(&ALICE, Outcome::Draw);
+
+        let record = PveRecords::<Test>::get(ALICE);
+        assert_eq!((record.wins, record.draws, record.streak), (1, 1, 0));
+        assert!(!Ratings::<Test>::contains_key(ALICE));
+        System::assert_last_event(
+            Event::PveResultRecorded {
+                who: ALICE,
+                outcome: Outcome::Draw,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn games_against_oneself_are_not_rated() {
+    new_test_ext().execute_with(|| {
+        EterraRating::on_pvp_finished(&ALICE, &ALICE, Outcome::Win);
+        assert!(!Ratings::<Test>::contains_key(ALICE));
+    });
+}
@@ -0,0 +1,24 @@
+//! Runtime API for reading Eterra matchmaking queues.
+//!
+//! Lets clients list the open queues with their settings and how many players wait in each,
+//! and find the queue a player is in, without decoding the pallet's storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_eterra_simple_matchmaker::{MatchStrategy, QueueId, QueueInfo, QueueView};
+
+sp_api::decl_runtime_apis! {
+    pub trait MatchmakerApi<AccountId, MatchParams>
+    where
+        AccountId: Codec,
+        MatchParams: Codec,
+    {
+        /// Every open queue and the number of players waiting in it, default queue first.
+        fn queues() -> Vec<QueueView<MatchParams>>;
+        /// The queue `who` is waiting in, if any.
+        fn queue_of(who: AccountId) -> Option<QueueId>;
+    }
+}
@@ -0,0 +1,247 @@
+//! Benchmarking setup for pallet-eterra-simple-matchmaker
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Matchmaker;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Hooks, weights::Weight};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{One, Zero};
+
+/// Occupy slots `0..n` of the default queue so that every slot but the last `live` ones holds
+/// an account that already left, forcing `pop_live` to walk past all of them.
+fn ring_with_dead_slots<T: Config>(n: u32, live: u32) -> Vec<T::AccountId> {
+    let queue = DEFAULT_QUEUE;
+    let mut queued = Vec::new();
+    for idx in 0..n {
+        let who: T::AccountId = account("queued", idx, 0);
+        Ring::<T>::insert(queue, idx, &who);
+        if idx >= n - live {
+            T::HandProvider::set_current_hand(&who);
+            InQueue::<T>::insert(&who, (queue, idx));
+            queued.push(who);
+        }
+    }
+    Head::<T>::insert(queue, 0);
+    Tail::<T>::insert(queue, n);
+    LiveSize::<T>::insert(queue, live);
+    queued
+}
+
+/// Configure the default queue with `capacity` slots and `strategy`.
+fn default_queue<T: Config>(capacity: u32, strategy: MatchStrategy) {
+    Queues::<T>::insert(
+        DEFAULT_QUEUE,
+        QueueInfo {
+            capacity,
+            strategy,
+            ..Default::default()
+        },
+    );
+}
+
+/// Seat a player who already accepted and the returned caller in proposal 0, with the
+/// queue empty.
+fn proposal_with_caller<T: Config>(deadline: BlockNumberFor<T>) -> (T::AccountId, T::AccountId) {
+    let accepted: T::AccountId = account("accepted", 0, 0);
+    let caller: T::AccountId = whitelisted_caller();
+    for who in [&accepted, &caller] {
+        T::HandProvider::set_current_hand(who);
+        JoinedAt::<T>::insert(who, BlockNumberFor::<T>::zero());
+        PendingProposal::<T>::insert(who, 0);
+    }
+    Proposals::<T>::insert(
+        0,
+        Proposal {
+            queue: DEFAULT_QUEUE,
+            players: [accepted.clone(), caller.clone()],
+            accepted: [true, false],
+            deadline,
+        },
+    );
+    NextProposalId::<T>::put(1);
+    (accepted, caller)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// Rated matching pairs the caller and `n - 1` equally rated players, creating a game for
+    /// every two slots.
+    #[benchmark]
+    fn join_queue(n: Linear<2, { T::QueueCapacity::get() }>) {
+        default_queue::<T>(T::QueueCapacity::get(), MatchStrategy::Rated);
+        ring_with_dead_slots::<T>(n - 1, n - 1);
+        let caller: T::AccountId = whitelisted_caller();
+        T::HandProvider::set_current_hand(&caller);
+
+        #[extrinsic_call]
+        join_queue(RawOrigin::Signed(caller.clone()), DEFAULT_QUEUE);
+
+        assert_eq!(LiveSize::<T>::get(DEFAULT_QUEUE), n % 2);
+    }
+
+    #[benchmark]
+    fn leave_queue() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::HandProvider::set_current_hand(&caller);
+        Matchmaker::<T>::join_queue(RawOrigin::Signed(caller.clone()).into(), DEFAULT_QUEUE)
+            .expect("caller has a hand and the queue is empty; qed");
+
+        #[extrinsic_call]
+        leave_queue(RawOrigin::Signed(caller.clone()));
+
+        assert!(!InQueue::<T>::contains_key(&caller));
+    }
+
+    /// Rated matching pairs `n` equally rated players.
+    #[benchmark]
+    fn process_queue(n: Linear<2, { T::QueueCapacity::get() }>) {
+        default_queue::<T>(T::QueueCapacity::get(), MatchStrategy::Rated);
+        ring_with_dead_slots::<T>(n, n);
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        process_queue(RawOrigin::Signed(caller), DEFAULT_QUEUE);
+
+        assert_eq!(LiveSize::<T>::get(DEFAULT_QUEUE), n % 2);
+    }
+
+    #[benchmark]
+    fn set_strategy() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        set_strategy(
+            origin as T::RuntimeOrigin,
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated,
+        );
+
+        assert_eq!(
+            Queues::<T>::get(DEFAULT_QUEUE).map(|info| info.strategy),
+            Some(MatchStrategy::Rated)
+        );
+        Ok(())
+    }
+
+    /// Shrinking a queue of `n` slots, all held by players who left, to two.
+    #[benchmark]
+    fn set_queue(n: Linear<3, { T::QueueCapacity::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        default_queue::<T>(n, MatchStrategy::Fifo);
+        ring_with_dead_slots::<T>(n, 0);
+        let info = QueueInfo {
+            capacity: 2,
+            ..Default::default()
+        };
+
+        #[extrinsic_call]
+        set_queue(origin as T::RuntimeOrigin, DEFAULT_QUEUE, info);
+
+        assert_eq!(Tail::<T>::get(DEFAULT_QUEUE), 0);
+        Ok(())
+    }
+
+    /// Removing a queue of `n` slots, all held by players who left.
+    #[benchmark]
+    fn remove_queue(n: Linear<2, { T::QueueCapacity::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        default_queue::<T>(n, MatchStrategy::Fifo);
+        ring_with_dead_slots::<T>(n, 0);
+
+        #[extrinsic_call]
+        remove_queue(origin as T::RuntimeOrigin, DEFAULT_QUEUE);
+
+        assert!(!Queues::<T>::contains_key(DEFAULT_QUEUE));
+        Ok(())
+    }
+
+    /// The second acceptance, which creates the game.
+    #[benchmark]
+    fn accept_match() {
+        let (accepted, caller) =
+            proposal_with_caller::<T>(frame_system::Pallet::<T>::block_number());
+
+        #[extrinsic_call]
+        accept_match(RawOrigin::Signed(caller.clone()));
+
+        assert!(!Proposals::<T>::contains_key(0));
+        assert!(!JoinedAt::<T>::contains_key(&accepted));
+    }
+
+    #[benchmark]
+    fn decline_match() {
+        let (accepted, caller) =
+            proposal_with_caller::<T>(frame_system::Pallet::<T>::block_number());
+
+        #[extrinsic_call]
+        decline_match(RawOrigin::Signed(caller.clone()));
+
+        assert!(CooldownUntil::<T>::contains_key(&caller));
+        assert!(InQueue::<T>::contains_key(&accepted));
+    }
+
+    /// An `on_idle` call with no lapsed proposal and an empty queue.
+    #[benchmark]
+    fn idle_base() {
+        let now = frame_system::Pallet::<T>::block_number();
+
+        #[block]
+        {
+            Matchmaker::<T>::on_idle(now, Weight::MAX);
+        }
+    }
+
+    /// One lapsed proposal: the player who accepted is requeued, the other cools down.
+    #[benchmark]
+    fn lapse_proposal() {
+        let (accepted, caller) = proposal_with_caller::<T>(BlockNumberFor::<T>::zero());
+        let now = BlockNumberFor::<T>::one();
+        frame_system::Pallet::<T>::set_block_number(now);
+
+        #[block]
+        {
+            Matchmaker::<T>::on_idle(now, Weight::MAX);
+        }
+
+        assert!(InQueue::<T>::contains_key(&accepted));
+        assert!(CooldownUntil::<T>::contains_key(&caller));
+    }
+
+    /// The default queue visited with one player still within the timeout at its head.
+    #[benchmark]
+    fn evict_queue() {
+        let queued = ring_with_dead_slots::<T>(1, 1);
+        let now = frame_system::Pallet::<T>::block_number();
+
+        #[block]
+        {
+            Matchmaker::<T>::on_idle(now, Weight::MAX);
+        }
+
+        assert!(InQueue::<T>::contains_key(&queued[0]));
+    }
+
+    /// One stale player evicted from the head of the queue.
+    #[benchmark]
+    fn evict_entry() {
+        let queued = ring_with_dead_slots::<T>(1, 1);
+        let now = T::QueueTimeout::get();
+        frame_system::Pallet::<T>::set_block_number(now);
+
+        #[block]
+        {
+            Matchmaker::<T>::on_idle(now, Weight::MAX);
+        }
+
+        assert!(!InQueue::<T>::contains_key(&queued[0]));
+    }
+
+    impl_benchmark_test_suite!(Matchmaker, crate::mock::new_test_ext(), crate::mock::Test);
+}
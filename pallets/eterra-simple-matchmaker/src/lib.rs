@@ -2,8 +2,11 @@
 
 pub use pallet::*;
 
-use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::Get};
+use frame_support::{
+    dispatch::DispatchResult, pallet_prelude::*, storage::with_storage_layer, traits::Get,
+};
 use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
 use sp_std::prelude::*;
 
 /// A lightweight bridge to verify that an account has configured a Current Hand
@@ -12,18 +15,110 @@ use sp_std::prelude::*;
 pub trait CurrentHandProvider<AccountId> {
     /// Returns true iff the account has a non-None current hand configured.
     fn has_current_hand(who: &AccountId) -> bool;
+
+    /// Give `who` a current hand so benchmarks can queue them.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_hand(_who: &AccountId) {}
 }
 
 /// A callback interface the runtime/game pallet implements so the matchmaker
 /// can create a game the moment two players are matched.
 pub trait GameCreator<AccountId> {
     /// The concrete GameId type of the game pallet.
-    type GameId;
+    type GameId: Parameter + Member + MaxEncodedLen;
+    /// Game settings a queue passes along for every game it creates: rules, stakes and
+    /// the like. The default is what the default queue plays with.
+    type MatchParams: Parameter + Member + MaxEncodedLen + Default;
     /// Create a new game for the given players. Implemented in the game pallet.
     fn create_from_matchmaking(
         p1: &AccountId,
         p2: &AccountId,
-    ) -> Result<Self::GameId, sp_runtime::DispatchError>;
+        params: &Self::MatchParams,
+    ) -> Result<Self::GameId, MatchError<AccountId>>;
+}
+
+/// Why the game pallet couldn't create a game for a matched pair.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MatchError<AccountId> {
+    /// The player who can't be placed in a game. They leave the queue while the other player
+    /// is requeued. `None` when the pair as a whole failed, and both are requeued.
+    pub culprit: Option<AccountId>,
+    pub reason: sp_runtime::DispatchError,
+}
+
+impl<AccountId> From<sp_runtime::DispatchError> for MatchError<AccountId> {
+    fn from(reason: sp_runtime::DispatchError) -> Self {
+        Self {
+            culprit: None,
+            reason,
+        }
+    }
+}
+
+/// Skill estimate the matchmaker pairs players by under `MatchStrategy::Rated`. The runtime
+/// implements this by reading the rating pallet; `()` rates everyone the same.
+pub trait RatingProvider<AccountId> {
+    /// Current rating of `who`, rounded to a whole number.
+    fn rating(who: &AccountId) -> u32;
+}
+
+impl<AccountId> RatingProvider<AccountId> for () {
+    fn rating(_who: &AccountId) -> u32 {
+        0
+    }
+}
+
+/// How `do_process` picks the players it pairs in a queue.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MatchStrategy {
+    /// First come, first served from the ring.
+    #[default]
+    Fifo,
+    /// Closest rating within a window that widens while a player waits; players who have
+    /// waited `FifoFallbackAfter` blocks are paired first come, first served.
+    Rated,
+}
+
+/// Identifies a matchmaking queue.
+pub type QueueId = u32;
+
+/// The queue players can join without any `set_queue`. Until configured it has
+/// `QueueCapacity` slots, pairs first come, first served and forwards default match
+/// parameters.
+pub const DEFAULT_QUEUE: QueueId = 0;
+
+/// Display name of a queue, e.g. `ranked` or `wager-100`.
+pub type QueueName = BoundedVec<u8, ConstU32<32>>;
+
+/// How one queue is run.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug, Default)]
+pub struct QueueInfo<Params> {
+    pub name: QueueName,
+    /// Ring slots, from 2 up to `QueueCapacity`.
+    pub capacity: u32,
+    pub strategy: MatchStrategy,
+    /// Forwarded to `GameCreator::create_from_matchmaking` for every game the queue creates.
+    pub params: Params,
+}
+
+/// A queue and how many players are waiting in it.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct QueueView<Params> {
+    pub queue: QueueId,
+    pub info: QueueInfo<Params>,
+    pub live: u32,
+}
+
+/// A pair waiting for both players to `accept_match`.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct Proposal<AccountId, BlockNumber> {
+    /// Queue the pair was found in; its parameters apply to the game.
+    pub queue: QueueId,
+    pub players: [AccountId; 2],
+    /// Which of `players` have accepted.
+    pub accepted: [bool; 2],
+    /// Last block `accept_match` is allowed in.
+    pub deadline: BlockNumber,
 }
 
 #[cfg(test)]
@@ -32,70 +127,192 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+pub mod weights;
+pub use weights::*;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
-        /// Constant that indicates how many players are needed to create a match.
+        /// Constant that indicates how many players are needed to create a match. Games are
+        /// created for pairs, so this must be 2.
         #[pallet::constant]
         type PlayersPerMatch: Get<u8>;
 
-        /// Capacity of the matchmaking queue.
+        /// Largest capacity a queue may have; also the capacity of the default queue. Must be
+        /// a power of two, like every queue's capacity.
         #[pallet::constant]
         type QueueCapacity: Get<u32>;
+        /// Most queues `set_queue` may configure, the default queue included once configured.
+        #[pallet::constant]
+        type MaxQueues: Get<u32>;
         /// A runtime hook used to check whether a player has a preset hand.
         /// Implement this in the runtime by delegating to your game/cards pallet.
         type HandProvider: super::CurrentHandProvider<Self::AccountId>;
         /// Hook to the game pallet that actually creates a game once two players are matched.
         type GameCreator: super::GameCreator<Self::AccountId>;
+        /// Ratings used by `MatchStrategy::Rated`.
+        type RatingProvider: super::RatingProvider<Self::AccountId>;
+        /// Origin allowed to configure queues and their matching strategy.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Largest rating gap a pair may have when neither player has waited.
+        #[pallet::constant]
+        type BaseRatingWindow: Get<u32>;
+        /// Rating points the acceptable gap grows by for every block a player waits.
+        #[pallet::constant]
+        type RatingWindowGrowth: Get<u32>;
+        /// Blocks after which a waiting player is paired first come, first served, whatever
+        /// the rating gap.
+        #[pallet::constant]
+        type FifoFallbackAfter: Get<BlockNumberFor<Self>>;
+        /// Blocks a player may wait in the queue before `on_idle` evicts them.
+        #[pallet::constant]
+        type QueueTimeout: Get<BlockNumberFor<Self>>;
+        /// Blocks both players of a pair have to `accept_match` before the game is created.
+        /// Zero skips the ready-check and creates the game as soon as the pair is found.
+        #[pallet::constant]
+        type AcceptWindow: Get<BlockNumberFor<Self>>;
+        /// Blocks a player who declined a match, or let one lapse, must wait before queueing.
+        #[pallet::constant]
+        type DeclineCooldown: Get<BlockNumberFor<Self>>;
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     pub type QIndex = u32;
 
+    pub type ProposalId = u32;
+
+    pub type ProposalOf<T> = Proposal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    pub type GameIdOf<T> =
+        <<T as Config>::GameCreator as GameCreator<<T as frame_system::Config>::AccountId>>::GameId;
+
+    pub type MatchParamsOf<T> = <<T as Config>::GameCreator as GameCreator<
+        <T as frame_system::Config>::AccountId,
+    >>::MatchParams;
+
+    pub type QueueInfoOf<T> = QueueInfo<MatchParamsOf<T>>;
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::storage]
     #[pallet::getter(fn head)]
-    pub type Head<T: Config> = StorageValue<_, QIndex, ValueQuery>;
+    pub type Head<T: Config> = StorageMap<_, Blake2_128Concat, QueueId, QIndex, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn tail)]
-    pub type Tail<T: Config> = StorageValue<_, QIndex, ValueQuery>;
+    pub type Tail<T: Config> = StorageMap<_, Blake2_128Concat, QueueId, QIndex, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn ring)]
-    pub type Ring<T: Config> = StorageMap<_, Blake2_128Concat, QIndex, T::AccountId, OptionQuery>;
+    pub type Ring<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        QueueId,
+        Blake2_128Concat,
+        QIndex,
+        T::AccountId,
+        OptionQuery,
+    >;
 
+    /// The queue each waiting player is in and the ring slot holding them. Any other slot
+    /// that still names the player is dead.
     #[pallet::storage]
     #[pallet::getter(fn in_queue)]
-    pub type InQueue<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+    pub type InQueue<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (QueueId, QIndex), OptionQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn live_size)]
-    pub type LiveSize<T: Config> = StorageValue<_, u32, ValueQuery>;
+    pub type LiveSize<T: Config> = StorageMap<_, Blake2_128Concat, QueueId, u32, ValueQuery>;
+
+    /// Block each queued player joined in, which their rating window widens from.
+    #[pallet::storage]
+    #[pallet::getter(fn joined_at)]
+    pub type JoinedAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
+
+    /// Queues set up with `set_queue`. `DEFAULT_QUEUE` is open without an entry; see
+    /// [`Pallet::queue_info`].
+    #[pallet::storage]
+    pub type Queues<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, QueueId, QueueInfoOf<T>, OptionQuery>;
+
+    /// Pairs waiting on the ready-check.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal)]
+    pub type Proposals<T: Config> =
+        StorageMap<_, Blake2_128Concat, ProposalId, ProposalOf<T>, OptionQuery>;
+
+    /// The proposal each player is part of.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_of)]
+    pub type PendingProposal<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ProposalId, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextProposalId<T: Config> = StorageValue<_, ProposalId, ValueQuery>;
+
+    /// Oldest proposal `on_idle` hasn't seen resolved yet. Every proposal gets the same
+    /// window, so they run out in id order.
+    #[pallet::storage]
+    pub type ExpiryCursor<T: Config> = StorageValue<_, ProposalId, ValueQuery>;
+
+    /// Block a player who declined or let a proposal lapse may queue again from.
+    #[pallet::storage]
+    #[pallet::getter(fn cooldown_until)]
+    pub type CooldownUntil<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        Joined { who: T::AccountId },
-        Left { who: T::AccountId },
-        Matched { players: [T::AccountId; 2] },
+        Joined { queue: QueueId, who: T::AccountId },
+        Left { queue: QueueId, who: T::AccountId },
+        Matched { queue: QueueId, players: [T::AccountId; 2], game_id: GameIdOf<T> },
         /// Emitted right after a join increases live size to at least the players-per-match threshold.
         TwoReadyToMatch { live_size: u32 },
         /// Emitted when `process_queue`/`join_queue` kicks off processing.
-        ProcessingStarted { live_size: u32, head: QIndex, tail: QIndex },
+        ProcessingStarted { queue: QueueId, live_size: u32, head: QIndex, tail: QIndex },
         /// Emitted when we have popped two candidates to pair.
         PairFound { a: T::AccountId, b: T::AccountId },
         /// Emitted immediately before calling into the game pallet to create a game.
         GameCreateAttempt { a: T::AccountId, b: T::AccountId },
-        /// Emitted when the second pop was unavailable and the first player was requeued.
+        /// Emitted when a player taken out for pairing went back into the queue, either because
+        /// no partner was available or because their game could not be created.
         Requeued { who: T::AccountId },
         /// Emitted after processing finishes for this call.
-        ProcessingCompleted { remaining_live: u32, head: QIndex, tail: QIndex },
+        ProcessingCompleted { queue: QueueId, remaining_live: u32, head: QIndex, tail: QIndex },
+        /// The game pallet refused a matched pair. `who` was dropped from the queue; the
+        /// rest of the pair was requeued at the front.
+        MatchFailed { who: Option<T::AccountId>, reason: DispatchError },
+        /// The matching strategy of `queue` was switched.
+        StrategySet { queue: QueueId, strategy: MatchStrategy },
+        /// `who` sat in `queue` for `QueueTimeout` blocks and was evicted.
+        Expired { queue: QueueId, who: T::AccountId },
+        /// A pair was found in `queue` and has until `deadline` to accept.
+        MatchProposed { id: ProposalId, queue: QueueId, players: [T::AccountId; 2], deadline: BlockNumberFor<T> },
+        MatchAccepted { id: ProposalId, who: T::AccountId },
+        MatchDeclined { id: ProposalId, who: T::AccountId },
+        /// The accept window of a proposal closed before both players accepted.
+        ProposalLapsed { id: ProposalId },
+        /// `who` can't queue again until block `until`.
+        CooldownStarted { who: T::AccountId, until: BlockNumberFor<T> },
+        /// `queue` was opened or reconfigured.
+        QueueSet { queue: QueueId },
+        /// `queue` was closed; for `DEFAULT_QUEUE` this restores its defaults.
+        QueueRemoved { queue: QueueId },
     }
 
     #[pallet::error]
@@ -103,18 +320,60 @@ pub mod pallet {
         QueueFull,
         AlreadyQueued,
         NotQueued,
+        /// A queue capacity must be a power of two from 2 up to `QueueCapacity`.
         BadCapacity,
         /// Player attempted to queue without having a preset hand configured.
         NoPresetHand,
+        /// The player has a proposed match to accept or decline first.
+        MatchPending,
+        /// The player declined or let a match lapse recently and must wait to queue again.
+        CoolingDown,
+        /// The player has no proposed match.
+        NoProposal,
+        /// The accept window of the proposal has closed.
+        ProposalExpired,
+        /// The player already accepted this proposal.
+        AlreadyAccepted,
+        /// No queue with this id has been set up.
+        UnknownQueue,
+        /// Players are waiting in the queue; its capacity can't change and it can't be removed.
+        QueueNotEmpty,
+        /// `MaxQueues` queues are already set up.
+        TooManyQueues,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let used = T::WeightInfo::idle_base();
+            if !remaining_weight.all_gte(used) {
+                return Weight::zero();
+            }
+            let used = Self::lapse_proposals(n, remaining_weight, used);
+            Self::evict_stale(n, remaining_weight, used)
+        }
+
+        fn integrity_test() {
+            assert_eq!(
+                T::PlayersPerMatch::get(),
+                2,
+                "the matchmaker only creates two-player games"
+            );
+            assert!(
+                T::QueueCapacity::get().is_power_of_two(),
+                "queue capacities must be powers of two"
+            );
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
-        pub fn join_queue(origin: OriginFor<T>) -> DispatchResult {
+        #[pallet::weight(T::WeightInfo::join_queue(T::QueueCapacity::get()))]
+        pub fn join_queue(origin: OriginFor<T>, queue: QueueId) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let cap = T::QueueCapacity::get();
+            let info = Self::queue_info(queue).ok_or(Error::<T>::UnknownQueue)?;
+            let cap = info.capacity;
             ensure!(cap > 1, Error::<T>::BadCapacity);
             ensure!(
                 InQueue::<T>::contains_key(&who) == false,
@@ -125,83 +384,266 @@ pub mod pallet {
                 T::HandProvider::has_current_hand(&who),
                 Error::<T>::NoPresetHand
             );
+            ensure!(
+                !PendingProposal::<T>::contains_key(&who),
+                Error::<T>::MatchPending
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(until) = CooldownUntil::<T>::get(&who) {
+                ensure!(now >= until, Error::<T>::CoolingDown);
+                CooldownUntil::<T>::remove(&who);
+            }
 
-            Head::<T>::mutate(|head| {
-                Tail::<T>::mutate(|tail| -> DispatchResult {
+            Head::<T>::mutate(queue, |head| {
+                Tail::<T>::mutate(queue, |tail| -> DispatchResult {
                     let size = Self::ring_size(*head, *tail, cap);
                     ensure!(size < cap, Error::<T>::QueueFull);
 
                     let idx = *tail % cap;
-                    Ring::<T>::insert(idx, &who);
+                    Ring::<T>::insert(queue, idx, &who);
                     *tail = tail.wrapping_add(1);
 
-                    InQueue::<T>::insert(&who, ());
-                    LiveSize::<T>::mutate(|n| *n = n.saturating_add(1));
+                    InQueue::<T>::insert(&who, (queue, idx));
+                    LiveSize::<T>::mutate(queue, |n| *n = n.saturating_add(1));
 
                     // If we now have enough players to match, emit a signal.
                     let threshold = T::PlayersPerMatch::get() as u32;
-                    let current = LiveSize::<T>::get();
+                    let current = LiveSize::<T>::get(queue);
                     if current >= threshold {
                         Self::deposit_event(Event::TwoReadyToMatch { live_size: current });
                     }
 
-                    Self::deposit_event(Event::Joined { who: who.clone() });
-                    Self::do_process(cap)?;
+                    Self::deposit_event(Event::Joined {
+                        queue,
+                        who: who.clone(),
+                    });
                     Ok(())
                 })
             })?;
+            JoinedAt::<T>::insert(&who, now);
 
-            Ok(())
+            // Pair only once `Head`/`Tail` are written back, so the pairing sees them.
+            Self::do_process(queue, &info)
         }
 
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::leave_queue())]
         pub fn leave_queue(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(InQueue::<T>::contains_key(&who), Error::<T>::NotQueued);
+            let (queue, _) = InQueue::<T>::take(&who).ok_or(Error::<T>::NotQueued)?;
 
-            InQueue::<T>::remove(&who);
-            LiveSize::<T>::mutate(|n| *n = n.saturating_sub(1));
-            Self::deposit_event(Event::Left { who });
+            JoinedAt::<T>::remove(&who);
+            LiveSize::<T>::mutate(queue, |n| *n = n.saturating_sub(1));
+            Self::deposit_event(Event::Left { queue, who });
             Ok(())
         }
 
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
-        pub fn process_queue(origin: OriginFor<T>) -> DispatchResult {
+        #[pallet::weight(T::WeightInfo::process_queue(T::QueueCapacity::get()))]
+        pub fn process_queue(origin: OriginFor<T>, queue: QueueId) -> DispatchResult {
             let _ = ensure_signed(origin).ok();
-            let cap = T::QueueCapacity::get();
-            ensure!(cap > 1, Error::<T>::BadCapacity);
+            let info = Self::queue_info(queue).ok_or(Error::<T>::UnknownQueue)?;
+            ensure!(info.capacity > 1, Error::<T>::BadCapacity);
             Self::deposit_event(Event::ProcessingStarted {
-                live_size: LiveSize::<T>::get(),
-                head: Head::<T>::get(),
-                tail: Tail::<T>::get(),
+                queue,
+                live_size: LiveSize::<T>::get(queue),
+                head: Head::<T>::get(queue),
+                tail: Tail::<T>::get(queue),
+            });
+            Self::do_process(queue, &info)
+        }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_strategy())]
+        pub fn set_strategy(
+            origin: OriginFor<T>,
+            queue: QueueId,
+            strategy: MatchStrategy,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let mut info = Self::queue_info(queue).ok_or(Error::<T>::UnknownQueue)?;
+            info.strategy = strategy;
+            Self::put_queue(queue, info)?;
+            Self::deposit_event(Event::StrategySet { queue, strategy });
+            Ok(())
+        }
+
+        /// Accept the match proposed to the caller. The game is created once both players of
+        /// the pair have accepted.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::accept_match())]
+        pub fn accept_match(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let id = PendingProposal::<T>::get(&who).ok_or(Error::<T>::NoProposal)?;
+            let mut proposal = Proposals::<T>::get(id).ok_or(Error::<T>::NoProposal)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= proposal.deadline,
+                Error::<T>::ProposalExpired
+            );
+            let seat = if proposal.players[0] == who { 0 } else { 1 };
+            ensure!(!proposal.accepted[seat], Error::<T>::AlreadyAccepted);
+            proposal.accepted[seat] = true;
+            Self::deposit_event(Event::MatchAccepted { id, who });
+
+            if proposal.accepted != [true, true] {
+                Proposals::<T>::insert(id, proposal);
+                return Ok(());
+            }
+            Self::close_proposal(id, &proposal);
+            let queue = proposal.queue;
+            let [a, b] = proposal.players;
+            let created = match Self::queue_info(queue) {
+                Some(info) => Self::create_match(queue, &a, &b, &info.params),
+                // The queue was removed while the pair was deciding; both are let go.
+                None => Err(None),
+            };
+            if let Err(culprit) = created {
+                for who in [b, a] {
+                    if culprit.as_ref() != Some(&who) {
+                        Self::requeue_front(queue, who);
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        /// Decline the match proposed to the caller. The caller is put on `DeclineCooldown` and
+        /// the other player goes back to the front of the queue.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::decline_match())]
+        pub fn decline_match(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let id = PendingProposal::<T>::get(&who).ok_or(Error::<T>::NoProposal)?;
+            let proposal = Proposals::<T>::get(id).ok_or(Error::<T>::NoProposal)?;
+            Self::close_proposal(id, &proposal);
+            Self::deposit_event(Event::MatchDeclined {
+                id,
+                who: who.clone(),
             });
-            Self::do_process(cap)
+            Self::start_cooldown(who.clone());
+            for other in proposal.players {
+                if other != who {
+                    Self::requeue_front(proposal.queue, other);
+                }
+            }
+            Ok(())
+        }
+
+        /// Open `queue`, or reconfigure it. Its capacity must be a power of two and can only
+        /// change while nobody is waiting in it.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_queue(T::QueueCapacity::get()))]
+        pub fn set_queue(
+            origin: OriginFor<T>,
+            queue: QueueId,
+            info: QueueInfoOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            // `Head` and `Tail` count up and wrap at `u32::MAX`; slots stay in order across the
+            // wrap only if the capacity divides 2^32.
+            ensure!(
+                (2..=T::QueueCapacity::get()).contains(&info.capacity)
+                    && info.capacity.is_power_of_two(),
+                Error::<T>::BadCapacity
+            );
+            let resized = Self::queue_info(queue)
+                .map(|current| current.capacity)
+                .filter(|&capacity| capacity != info.capacity);
+            if resized.is_some() {
+                ensure!(LiveSize::<T>::get(queue) == 0, Error::<T>::QueueNotEmpty);
+            }
+            Self::put_queue(queue, info)?;
+            if let Some(capacity) = resized {
+                // Slots are addressed modulo the capacity; start the ring over.
+                Self::clear_ring(queue, capacity);
+            }
+            Self::deposit_event(Event::QueueSet { queue });
+            Ok(())
+        }
+
+        /// Close an empty `queue`. Removing `DEFAULT_QUEUE` restores its defaults.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::remove_queue(T::QueueCapacity::get()))]
+        pub fn remove_queue(origin: OriginFor<T>, queue: QueueId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let info = Queues::<T>::get(queue).ok_or(Error::<T>::UnknownQueue)?;
+            ensure!(LiveSize::<T>::get(queue) == 0, Error::<T>::QueueNotEmpty);
+            Queues::<T>::remove(queue);
+            Self::clear_ring(queue, info.capacity);
+            Self::deposit_event(Event::QueueRemoved { queue });
+            Ok(())
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// How `queue` is run, or `None` if it isn't open.
+        pub fn queue_info(queue: QueueId) -> Option<QueueInfoOf<T>> {
+            Queues::<T>::get(queue).or_else(|| {
+                (queue == DEFAULT_QUEUE).then(|| QueueInfo {
+                    capacity: T::QueueCapacity::get(),
+                    ..Default::default()
+                })
+            })
+        }
+
+        /// Every open queue with the number of players waiting in it, default queue first.
+        pub fn queues() -> Vec<QueueView<MatchParamsOf<T>>> {
+            Self::queue_ids()
+                .filter_map(|queue| {
+                    Some(QueueView {
+                        queue,
+                        info: Self::queue_info(queue)?,
+                        live: LiveSize::<T>::get(queue),
+                    })
+                })
+                .collect()
+        }
+
+        fn queue_ids() -> impl Iterator<Item = QueueId> {
+            core::iter::once(DEFAULT_QUEUE)
+                .chain(Queues::<T>::iter_keys().filter(|queue| *queue != DEFAULT_QUEUE))
+        }
+
+        fn put_queue(queue: QueueId, info: QueueInfoOf<T>) -> DispatchResult {
+            ensure!(
+                Queues::<T>::contains_key(queue) || Queues::<T>::count() < T::MaxQueues::get(),
+                Error::<T>::TooManyQueues
+            );
+            Queues::<T>::insert(queue, info);
+            Ok(())
+        }
+
+        /// Drop the slots of an empty queue that had `capacity` slots.
+        fn clear_ring(queue: QueueId, capacity: u32) {
+            let _ = Ring::<T>::clear_prefix(queue, capacity, None);
+            Head::<T>::remove(queue);
+            Tail::<T>::remove(queue);
+        }
+
+        fn is_live(queue: QueueId, slot: QIndex, who: &T::AccountId) -> bool {
+            InQueue::<T>::get(who) == Some((queue, slot))
+        }
+
         fn ring_size(head: QIndex, tail: QIndex, _cap: QIndex) -> QIndex {
             tail.wrapping_sub(head)
         }
 
-        fn pop_live(cap: QIndex) -> Option<T::AccountId> {
-            Head::<T>::mutate(|head| {
+        fn pop_live(queue: QueueId, cap: QIndex) -> Option<T::AccountId> {
+            Head::<T>::mutate(queue, |head| {
                 // We’ll search up to `cap` slots (one full cycle) to find a live account.
                 // This makes the ring robust even if `head` previously advanced past older entries.
                 let mut h = *head;
-                let tail = Tail::<T>::get();
+                let tail = Tail::<T>::get(queue);
 
                 for _ in 0..cap {
                     let idx = h % cap;
                     h = h.wrapping_add(1);
 
-                    if let Some(acc) = Ring::<T>::take(idx) {
-                        if InQueue::<T>::contains_key(&acc) {
+                    if let Some(acc) = Ring::<T>::take(queue, idx) {
+                        if Self::is_live(queue, idx, &acc) {
                             *head = h;
                             InQueue::<T>::remove(&acc);
-                            LiveSize::<T>::mutate(|n| *n = n.saturating_sub(1));
+                            LiveSize::<T>::mutate(queue, |n| *n = n.saturating_sub(1));
                             return Some(acc);
                         }
                     }
@@ -217,32 +659,51 @@ pub mod pallet {
             })
         }
 
-        fn do_process(cap: QIndex) -> DispatchResult {
+        fn do_process(queue: QueueId, info: &QueueInfoOf<T>) -> DispatchResult {
             // Mirror the start event for calls coming from join_queue path.
             Self::deposit_event(Event::ProcessingStarted {
-                live_size: LiveSize::<T>::get(),
-                head: Head::<T>::get(),
-                tail: Tail::<T>::get(),
+                queue,
+                live_size: LiveSize::<T>::get(queue),
+                head: Head::<T>::get(queue),
+                tail: Tail::<T>::get(queue),
+            });
+            match info.strategy {
+                MatchStrategy::Fifo => Self::process_fifo(queue, info.capacity, &info.params),
+                MatchStrategy::Rated => Self::process_rated(queue, info.capacity, &info.params),
+            }
+            Self::deposit_event(Event::ProcessingCompleted {
+                queue,
+                remaining_live: LiveSize::<T>::get(queue),
+                head: Head::<T>::get(queue),
+                tail: Tail::<T>::get(queue),
             });
+            Ok(())
+        }
+
+        /// Put `who`, popped from the head of `queue`, back at its tail.
+        fn requeue_back(queue: QueueId, cap: QIndex, who: T::AccountId) {
+            let idx = Tail::<T>::mutate(queue, |tail| {
+                let idx = *tail % cap;
+                Ring::<T>::insert(queue, idx, &who);
+                *tail = tail.wrapping_add(1);
+                idx
+            });
+            Self::requeue(queue, idx, who);
+        }
+
+        fn process_fifo(queue: QueueId, cap: QIndex, params: &MatchParamsOf<T>) {
             loop {
-                if LiveSize::<T>::get() < 2 {
+                if LiveSize::<T>::get(queue) < 2 {
                     break;
                 }
-                let a = match Self::pop_live(cap) {
+                let a = match Self::pop_live(queue, cap) {
                     Some(x) => x,
                     None => break,
                 };
-                let b = match Self::pop_live(cap) {
+                let b = match Self::pop_live(queue, cap) {
                     Some(x) => x,
                     None => {
-                        Tail::<T>::mutate(|tail| {
-                            let idx = *tail % cap;
-                            Ring::<T>::insert(idx, &a);
-                            *tail = tail.wrapping_add(1);
-                        });
-                        InQueue::<T>::insert(&a, ());
-                        LiveSize::<T>::mutate(|n| *n = n.saturating_add(1));
-                        Self::deposit_event(Event::Requeued { who: a.clone() });
+                        Self::requeue_back(queue, cap, a);
                         break;
                     }
                 };
@@ -251,30 +712,365 @@ pub mod pallet {
                 if a == b {
                     // Extremely defensive: never match the same account with itself.
                     // Requeue `a` and stop this processing round.
-                    Tail::<T>::mutate(|tail| {
-                        let idx = *tail % cap;
-                        Ring::<T>::insert(idx, &a);
-                        *tail = tail.wrapping_add(1);
-                    });
-                    InQueue::<T>::insert(&a, ());
-                    LiveSize::<T>::mutate(|n| *n = n.saturating_add(1));
-                    Self::deposit_event(Event::Requeued { who: a.clone() });
+                    Self::requeue_back(queue, cap, a);
+                    break;
+                }
+
+                if !T::AcceptWindow::get().is_zero() {
+                    Self::propose(queue, a, b);
+                } else if let Err(culprit) = Self::create_match(queue, &a, &b, params) {
+                    // Put whoever can still play back at the front, `a` ahead of `b`. `pop_live`
+                    // emptied every slot it walked past, so there is room behind the head.
+                    for who in [b, a] {
+                        if culprit.as_ref() != Some(&who) {
+                            Self::requeue_front(queue, who);
+                        }
+                    }
+                    if culprit.is_none() {
+                        // The same pair would fail again; leave it for a later round.
+                        break;
+                    }
+                }
+            }
+        }
+
+        /// Pair each player, oldest first, with the closest-rated player still waiting if the
+        /// gap fits the wider of their two windows. Anyone left who has waited
+        /// `FifoFallbackAfter` blocks is then paired with whoever queued first.
+        fn process_rated(queue: QueueId, cap: QIndex, params: &MatchParamsOf<T>) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let queued = Self::queued_in_order(queue, cap);
+            // A player without a join block hasn't been seen waiting yet
+            let waited: Vec<u32> = queued
+                .iter()
+                .map(|(_, who)| {
+                    JoinedAt::<T>::try_get(who)
+                        .map_or(0, |at| now.saturating_sub(at).saturated_into())
+                })
+                .collect();
+            let ratings: Vec<u32> = queued
+                .iter()
+                .map(|(_, who)| T::RatingProvider::rating(who))
+                .collect();
+            let window = |i: usize| {
+                T::BaseRatingWindow::get()
+                    .saturating_add(T::RatingWindowGrowth::get().saturating_mul(waited[i]))
+            };
+
+            // Queue positions sorted by rating; equal ratings keep queue order.
+            let mut by_rating: Vec<usize> = (0..queued.len()).collect();
+            by_rating.sort_by_key(|&i| (ratings[i], i));
+            let mut rank = sp_std::vec![0; queued.len()];
+            for (r, &i) in by_rating.iter().enumerate() {
+                rank[i] = r;
+            }
+
+            let mut paired = sp_std::vec![false; queued.len()];
+            let mut pairs = Vec::new();
+            for i in 0..queued.len() {
+                if paired[i] {
+                    continue;
+                }
+                let gap = |j: usize| ratings[i].abs_diff(ratings[j]);
+                let below = by_rating[..rank[i]]
+                    .iter()
+                    .rev()
+                    .copied()
+                    .find(|&j| !paired[j]);
+                let above = by_rating[rank[i] + 1..]
+                    .iter()
+                    .copied()
+                    .find(|&j| !paired[j]);
+                let closest = match (below, above) {
+                    (Some(b), Some(a)) if gap(a) < gap(b) || (gap(a) == gap(b) && a < b) => Some(a),
+                    (below, above) => below.or(above),
+                };
+                if let Some(j) = closest.filter(|&j| gap(j) <= window(i).max(window(j))) {
+                    paired[i] = true;
+                    paired[j] = true;
+                    pairs.push((i, j));
+                }
+            }
+
+            let fallback: u32 = T::FifoFallbackAfter::get().saturated_into();
+            let mut rest: Vec<usize> = (0..queued.len()).filter(|&i| !paired[i]).collect();
+            while rest.len() >= 2 {
+                let Some(k) = rest.iter().position(|&i| waited[i] >= fallback) else {
                     break;
+                };
+                let i = rest.remove(k);
+                let j = rest.remove(0);
+                pairs.push((j.min(i), j.max(i)));
+            }
+
+            for (i, j) in pairs {
+                let pair = [&queued[i.min(j)], &queued[i.max(j)]];
+                for (_, who) in pair {
+                    InQueue::<T>::remove(who);
+                    LiveSize::<T>::mutate(queue, |n| *n = n.saturating_sub(1));
                 }
+                let (a, b) = (&pair[0].1, &pair[1].1);
+                Self::deposit_event(Event::PairFound { a: a.clone(), b: b.clone() });
+                if !T::AcceptWindow::get().is_zero() {
+                    Ring::<T>::remove(queue, pair[0].0);
+                    Ring::<T>::remove(queue, pair[1].0);
+                    Self::propose(queue, a.clone(), b.clone());
+                    continue;
+                }
+                let failed = Self::create_match(queue, a, b, params).err();
+                for (slot, who) in pair {
+                    match &failed {
+                        // Requeued players keep their slot, ahead of anyone who joined later.
+                        Some(culprit) if culprit.as_ref() != Some(who) => {
+                            Self::requeue(queue, *slot, who.clone())
+                        }
+                        _ => Ring::<T>::remove(queue, slot),
+                    }
+                }
+            }
+            Self::trim_head(queue, cap);
+        }
+
+        /// Ask the game pallet for a game between `a` and `b`, who have left `queue`, with the
+        /// queue's `params`. A failed attempt leaves no storage behind; its culprit is dropped
+        /// for good and returned so the caller can requeue the rest of the pair.
+        fn create_match(
+            queue: QueueId,
+            a: &T::AccountId,
+            b: &T::AccountId,
+            params: &MatchParamsOf<T>,
+        ) -> Result<(), Option<T::AccountId>> {
+            Self::deposit_event(Event::GameCreateAttempt { a: a.clone(), b: b.clone() });
+            match with_storage_layer(|| T::GameCreator::create_from_matchmaking(a, b, params)) {
+                Ok(game_id) => {
+                    JoinedAt::<T>::remove(a);
+                    JoinedAt::<T>::remove(b);
+                    Self::deposit_event(Event::Matched {
+                        queue,
+                        players: [a.clone(), b.clone()],
+                        game_id,
+                    });
+                    Ok(())
+                }
+                Err(MatchError { culprit, reason }) => {
+                    if let Some(who) = &culprit {
+                        JoinedAt::<T>::remove(who);
+                    }
+                    Self::deposit_event(Event::MatchFailed {
+                        who: culprit.clone(),
+                        reason,
+                    });
+                    Err(culprit)
+                }
+            }
+        }
 
-                Self::deposit_event(Event::GameCreateAttempt { a: a.clone(), b: b.clone() });
-                // Ask the game pallet to create a game for this pair. If it fails we still emit Matched.
-                let _ = T::GameCreator::create_from_matchmaking(&a, &b);
-                Self::deposit_event(Event::Matched {
+        /// Put `who` back at the front of `queue`, ahead of everyone waiting. If the ring has
+        /// no free slot, or the queue was removed, they are dropped as if they had left.
+        fn requeue_front(queue: QueueId, who: T::AccountId) {
+            let placed = Self::queue_info(queue).and_then(|info| {
+                Head::<T>::mutate(queue, |head| {
+                    let cap = info.capacity;
+                    if Self::ring_size(*head, Tail::<T>::get(queue), cap) >= cap {
+                        return None;
+                    }
+                    *head = head.wrapping_sub(1);
+                    let idx = *head % cap;
+                    Ring::<T>::insert(queue, idx, &who);
+                    Some(idx)
+                })
+            });
+            if let Some(idx) = placed {
+                Self::requeue(queue, idx, who);
+            } else {
+                JoinedAt::<T>::remove(&who);
+                Self::deposit_event(Event::Left { queue, who });
+            }
+        }
+
+        /// Hold `a` and `b`, who have left `queue`, until both `accept_match`.
+        fn propose(queue: QueueId, a: T::AccountId, b: T::AccountId) {
+            let id = NextProposalId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.wrapping_add(1);
+                id
+            });
+            let deadline =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::AcceptWindow::get());
+            PendingProposal::<T>::insert(&a, id);
+            PendingProposal::<T>::insert(&b, id);
+            Proposals::<T>::insert(
+                id,
+                Proposal {
+                    queue,
                     players: [a.clone(), b.clone()],
-                });
+                    accepted: [false, false],
+                    deadline,
+                },
+            );
+            Self::deposit_event(Event::MatchProposed {
+                id,
+                queue,
+                players: [a, b],
+                deadline,
+            });
+        }
+
+        fn close_proposal(id: ProposalId, proposal: &ProposalOf<T>) {
+            Proposals::<T>::remove(id);
+            for who in &proposal.players {
+                PendingProposal::<T>::remove(who);
             }
-            Self::deposit_event(Event::ProcessingCompleted {
-                remaining_live: LiveSize::<T>::get(),
-                head: Head::<T>::get(),
-                tail: Tail::<T>::get(),
+        }
+
+        fn start_cooldown(who: T::AccountId) {
+            let until =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::DeclineCooldown::get());
+            JoinedAt::<T>::remove(&who);
+            CooldownUntil::<T>::insert(&who, until);
+            Self::deposit_event(Event::CooldownStarted { who, until });
+        }
+
+        /// Close proposals whose accept window ended before `now`, as far as `limit` allows.
+        /// Players who accepted go back to the front of the queue; the others cool down.
+        fn lapse_proposals(now: BlockNumberFor<T>, limit: Weight, mut used: Weight) -> Weight {
+            let (start, next) = (ExpiryCursor::<T>::get(), NextProposalId::<T>::get());
+            let mut cursor = start;
+            while cursor != next {
+                let cost = T::WeightInfo::lapse_proposal();
+                if !limit.all_gte(used.saturating_add(cost)) {
+                    break;
+                }
+                if let Some(proposal) = Proposals::<T>::get(cursor) {
+                    if proposal.deadline >= now {
+                        break;
+                    }
+                    Self::close_proposal(cursor, &proposal);
+                    Self::deposit_event(Event::ProposalLapsed { id: cursor });
+                    let [a, b] = proposal.players;
+                    for (who, accepted) in [(b, proposal.accepted[1]), (a, proposal.accepted[0])] {
+                        if accepted {
+                            Self::requeue_front(proposal.queue, who);
+                        } else {
+                            Self::start_cooldown(who);
+                        }
+                    }
+                }
+                used = used.saturating_add(cost);
+                cursor = cursor.wrapping_add(1);
+            }
+            if cursor != start {
+                ExpiryCursor::<T>::put(cursor);
+            }
+            used
+        }
+
+        /// Evict stale players from every queue, default queue first, as far as `limit`
+        /// allows.
+        fn evict_stale(now: BlockNumberFor<T>, limit: Weight, mut used: Weight) -> Weight {
+            for queue in Self::queue_ids() {
+                let cost = T::WeightInfo::evict_queue();
+                if !limit.all_gte(used.saturating_add(cost)) {
+                    break;
+                }
+                used = used.saturating_add(cost);
+                if let Some(info) = Self::queue_info(queue) {
+                    used = Self::evict_stale_from(queue, info.capacity, now, limit, used);
+                }
+            }
+            used
+        }
+
+        /// Evict players who joined `queue` `QueueTimeout` or more blocks before `now`, walking
+        /// from the head as far as `limit` allows. Slots are in join order, so the walk stops
+        /// at the first player still within the timeout. Players whose join block isn't known
+        /// are skipped and keep their slot, so the head stops short of the first of them.
+        fn evict_stale_from(
+            queue: QueueId,
+            cap: QIndex,
+            now: BlockNumberFor<T>,
+            limit: Weight,
+            mut used: Weight,
+        ) -> Weight {
+            let tail = Tail::<T>::get(queue);
+            let timeout = T::QueueTimeout::get();
+            Head::<T>::mutate(queue, |head| {
+                let (mut at, mut kept) = (*head, false);
+                while at != tail {
+                    let cost = T::WeightInfo::evict_entry();
+                    if !limit.all_gte(used.saturating_add(cost)) {
+                        break;
+                    }
+                    let idx = at % cap;
+                    let mut keep = false;
+                    if let Some(who) = Ring::<T>::get(queue, idx) {
+                        if Self::is_live(queue, idx, &who) {
+                            match JoinedAt::<T>::try_get(&who) {
+                                Ok(joined) if joined.saturating_add(timeout) > now => break,
+                                Ok(_) => {
+                                    InQueue::<T>::remove(&who);
+                                    JoinedAt::<T>::remove(&who);
+                                    LiveSize::<T>::mutate(queue, |n| *n = n.saturating_sub(1));
+                                    Self::deposit_event(Event::Expired { queue, who });
+                                }
+                                Err(_) => keep = true,
+                            }
+                        }
+                    }
+                    at = at.wrapping_add(1);
+                    used = used.saturating_add(cost);
+                    if keep {
+                        kept = true;
+                    } else {
+                        Ring::<T>::remove(queue, idx);
+                        if !kept {
+                            *head = at;
+                        }
+                    }
+                }
+            });
+            used
+        }
+
+        /// Mark `who`, already back in ring `slot` of `queue`, as queued again.
+        fn requeue(queue: QueueId, slot: QIndex, who: T::AccountId) {
+            InQueue::<T>::insert(&who, (queue, slot));
+            LiveSize::<T>::mutate(queue, |n| *n = n.saturating_add(1));
+            Self::deposit_event(Event::Requeued { who });
+        }
+
+        /// Live entries of `queue` between its head and tail as `(slot, who)`, oldest first.
+        fn queued_in_order(queue: QueueId, cap: QIndex) -> Vec<(QIndex, T::AccountId)> {
+            let head = Head::<T>::get(queue);
+            let len = Self::ring_size(head, Tail::<T>::get(queue), cap).min(cap);
+            let mut queued: Vec<(QIndex, T::AccountId)> = Vec::new();
+            for offset in 0..len {
+                let idx = head.wrapping_add(offset) % cap;
+                if let Some(who) = Ring::<T>::get(queue, idx) {
+                    if Self::is_live(queue, idx, &who) {
+                        queued.push((idx, who));
+                    }
+                }
+            }
+            queued
+        }
+
+        /// Advance the head of `queue` past the slots rated matching emptied, so they stop
+        /// counting against its capacity.
+        fn trim_head(queue: QueueId, cap: QIndex) {
+            let tail = Tail::<T>::get(queue);
+            Head::<T>::mutate(queue, |head| {
+                while *head != tail {
+                    let idx = *head % cap;
+                    match Ring::<T>::get(queue, idx) {
+                        Some(who) if Self::is_live(queue, idx, &who) => break,
+                        _ => {
+                            Ring::<T>::remove(queue, idx);
+                            *head = head.wrapping_add(1);
+                        }
+                    }
+                }
             });
-            Ok(())
         }
     }
 }
@@ -0,0 +1,91 @@
+//! Storage migrations for `pallet-eterra-simple-matchmaker`.
+
+pub mod v1 {
+    //! v0 → v1: the single global queue becomes `DEFAULT_QUEUE` of the per-queue storage.
+    //! Players already waiting are recorded at their newest ring slot and get the upgrade
+    //! block as their `JoinedAt`, so their queue timeout and rating window start from there.
+
+    use crate::pallet::{Config, Head, InQueue, JoinedAt, LiveSize, Pallet, QIndex, Ring, Tail};
+    use crate::DEFAULT_QUEUE;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+    use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+    /// Storage items of the single queue, as laid out before v1.
+    pub mod old {
+        use super::*;
+
+        #[frame_support::storage_alias]
+        pub type Head<T: Config> = StorageValue<Pallet<T>, QIndex, ValueQuery>;
+
+        #[frame_support::storage_alias]
+        pub type Tail<T: Config> = StorageValue<Pallet<T>, QIndex, ValueQuery>;
+
+        #[frame_support::storage_alias]
+        pub type LiveSize<T: Config> = StorageValue<Pallet<T>, u32, ValueQuery>;
+
+        #[frame_support::storage_alias]
+        pub type Ring<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            QIndex,
+            <T as frame_system::Config>::AccountId,
+            OptionQuery,
+        >;
+    }
+
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let queue = DEFAULT_QUEUE;
+            let now = frame_system::Pallet::<T>::block_number();
+            let (mut reads, mut writes): (u64, u64) = (4, 6);
+
+            let (head, tail) = (old::Head::<T>::take(), old::Tail::<T>::take());
+            Head::<T>::insert(queue, head);
+            Tail::<T>::insert(queue, tail);
+            old::LiveSize::<T>::kill();
+            let ring: Vec<(QIndex, T::AccountId)> = old::Ring::<T>::drain().collect();
+            for (idx, who) in ring {
+                reads += 1;
+                writes += 2;
+                Ring::<T>::insert(queue, idx, who);
+            }
+
+            // A player who left and joined again is waiting at the later of their slots
+            let cap = T::QueueCapacity::get();
+            let mut slots = BTreeMap::new();
+            for offset in 0..tail.wrapping_sub(head).min(cap) {
+                let idx = head.wrapping_add(offset) % cap;
+                reads += 1;
+                if let Some(who) = Ring::<T>::get(queue, idx) {
+                    slots.insert(who, idx);
+                }
+            }
+
+            let mut live = 0u32;
+            InQueue::<T>::translate::<(), _>(|who, ()| {
+                reads += 1;
+                writes += 2;
+                let slot = *slots.get(&who)?;
+                live += 1;
+                JoinedAt::<T>::insert(&who, now);
+                Some((queue, slot))
+            });
+            LiveSize::<T>::insert(queue, live);
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+
+    /// Versioned wrapper to list in the runtime's `Migrations`.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
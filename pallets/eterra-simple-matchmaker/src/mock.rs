@@ -23,6 +23,13 @@ parameter_types! {
     pub const ExistentialDeposit: u64 = 0;
     pub const PlayersPerMatchConst: u8 = 2;      // For 1v1 matching
     pub const QueueCapacityConst: u32 = 64;      // Circular buffer capacity for tests
+    pub const MaxQueuesConst: u32 = 4;
+    pub const BaseRatingWindowConst: u32 = 100;
+    pub const RatingWindowGrowthConst: u32 = 10;
+    pub const FifoFallbackAfterConst: BlockNumber = 50;
+    pub const QueueTimeoutConst: BlockNumber = 100;
+    pub static AcceptWindow: BlockNumber = 0;        // Ready-check off unless a test sets it
+    pub const DeclineCooldownConst: BlockNumber = 10;
 }
 
 impl system::Config for Test {
@@ -67,22 +74,50 @@ thread_local! {
     static TL_HAND_SET: RefCell<BTreeSet<AccountId>> = RefCell::new(BTreeSet::new());
 }
 
+thread_local! {
+    static TL_RATINGS: RefCell<std::collections::BTreeMap<AccountId, u32>> =
+        RefCell::new(Default::default());
+}
+
 thread_local! {
     pub static CREATED_GAMES: std::cell::RefCell<Vec<(AccountId, AccountId)>> =
         std::cell::RefCell::new(Vec::new());
+    static GAME_PARAMS: RefCell<Vec<u32>> = RefCell::new(Vec::new());
     pub static NEXT_GAME_ID: std::cell::Cell<u64> = std::cell::Cell::new(1);
+    static UNPLAYABLE: RefCell<BTreeSet<AccountId>> = RefCell::new(BTreeSet::new());
+    static FAIL_PAIRS: std::cell::Cell<bool> = std::cell::Cell::new(false);
+}
+
+/// Helper: make game creation fail, blaming `who`, whenever they are matched.
+pub fn set_unplayable(who: AccountId) {
+    UNPLAYABLE.with(|s| s.borrow_mut().insert(who));
+}
+
+/// Helper: make game creation fail for every pair without blaming either player.
+pub fn set_fail_pairs(fail: bool) {
+    FAIL_PAIRS.with(|f| f.set(fail));
 }
 
 pub fn created_games() -> Vec<(AccountId, AccountId)> {
     CREATED_GAMES.with(|v| v.borrow().clone())
 }
 
+/// Match parameters each created game was given, in creation order.
+pub fn game_params() -> Vec<u32> {
+    GAME_PARAMS.with(|v| v.borrow().clone())
+}
+
 /// Test-only provider: consults a thread-local set to determine if an account has a hand.
 pub struct MockHandProvider;
 impl pallet_matchmaker::CurrentHandProvider<AccountId> for MockHandProvider {
     fn has_current_hand(who: &AccountId) -> bool {
         TL_HAND_SET.with(|s| s.borrow().contains(who))
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_hand(who: &AccountId) {
+        set_has_hand(*who, true);
+    }
 }
 
 /// Helper: mark/unmark an account as having a hand in this test thread.
@@ -102,16 +137,43 @@ pub fn clear_all_hands() {
     TL_HAND_SET.with(|s| s.borrow_mut().clear());
 }
 
+/// Test-only ratings: unrated accounts sit at 0.
+pub struct MockRatingProvider;
+impl pallet_matchmaker::RatingProvider<AccountId> for MockRatingProvider {
+    fn rating(who: &AccountId) -> u32 {
+        TL_RATINGS.with(|r| r.borrow().get(who).copied().unwrap_or_default())
+    }
+}
+
+/// Helper: set the rating `MockRatingProvider` reports for `who`.
+pub fn set_rating(who: AccountId, rating: u32) {
+    TL_RATINGS.with(|r| r.borrow_mut().insert(who, rating));
+}
+
 // --- Test-only GameCreator implementation for () ---
 impl pallet_matchmaker::GameCreator<AccountId> for () {
     type GameId = u32;
+    type MatchParams = u32;
 
     fn create_from_matchmaking(
         a: &AccountId,
         b: &AccountId,
-    ) -> Result<Self::GameId, DispatchError> {
+        params: &u32,
+    ) -> Result<Self::GameId, pallet_matchmaker::MatchError<AccountId>> {
+        for who in [a, b] {
+            if UNPLAYABLE.with(|s| s.borrow().contains(who)) {
+                return Err(pallet_matchmaker::MatchError {
+                    culprit: Some(*who),
+                    reason: DispatchError::Other("unplayable"),
+                });
+            }
+        }
+        if FAIL_PAIRS.with(|f| f.get()) {
+            return Err(DispatchError::Other("pair").into());
+        }
         // Record the created game pair for assertions.
         CREATED_GAMES.with(|v| v.borrow_mut().push((*a, *b)));
+        GAME_PARAMS.with(|v| v.borrow_mut().push(*params));
         // Bump a simple counter for the returned GameId.
         let id = NEXT_GAME_ID.with(|c| {
             let id = c.get();
@@ -126,8 +188,18 @@ impl pallet_matchmaker::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type PlayersPerMatch = PlayersPerMatchConst;
     type QueueCapacity = QueueCapacityConst;
+    type MaxQueues = MaxQueuesConst;
     type HandProvider = MockHandProvider;
     type GameCreator = ();
+    type RatingProvider = MockRatingProvider;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type BaseRatingWindow = BaseRatingWindowConst;
+    type RatingWindowGrowth = RatingWindowGrowthConst;
+    type FifoFallbackAfter = FifoFallbackAfterConst;
+    type QueueTimeout = QueueTimeoutConst;
+    type AcceptWindow = AcceptWindow;
+    type DeclineCooldown = DeclineCooldownConst;
+    type WeightInfo = ();
 }
 
 construct_runtime!(
@@ -149,8 +221,13 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     let mut ext = sp_io::TestExternalities::new(t);
     ext.execute_with(|| {
         clear_all_hands();
+        TL_RATINGS.with(|r| r.borrow_mut().clear());
         CREATED_GAMES.with(|v| v.borrow_mut().clear());
+        GAME_PARAMS.with(|v| v.borrow_mut().clear());
         NEXT_GAME_ID.with(|c| c.set(1));
+        UNPLAYABLE.with(|s| s.borrow_mut().clear());
+        FAIL_PAIRS.with(|f| f.set(false));
+        AcceptWindow::set(&0);
         System::set_block_number(1);
     });
     ext
@@ -3,12 +3,17 @@
 
 use super::*;
 
-use frame_support::{assert_noop, assert_ok, traits::OnFinalize};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Hooks, OnFinalize},
+    weights::Weight,
+};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::DispatchError;
 
 use crate::mock::{
-    clear_all_hands, created_games, new_test_ext, set_has_hand, Matchmaker, RuntimeEvent,
+    clear_all_hands, created_games, game_params, new_test_ext, run_to_block, set_fail_pairs,
+    set_has_hand, set_rating, set_unplayable, AcceptWindow, Matchmaker, RuntimeEvent,
     RuntimeOrigin as SystemOrigin, Test,
 };
 
@@ -38,7 +43,10 @@ fn filter_matchmaker(events: &[RuntimeEvent]) -> Vec<RuntimeEvent> {
 fn join_queue_emits_event_and_persists() {
     new_test_ext().execute_with(|| {
         set_has_hand(1, true);
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(1)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(1),
+            DEFAULT_QUEUE
+        ));
 
         // Collect all events and ensure a Joined{ who: 1 } was emitted,
         // ignoring any ProcessingStarted/ProcessingCompleted noise.
@@ -46,13 +54,13 @@ fn join_queue_emits_event_and_persists() {
         let joined_seen = evs.iter().any(|ev| {
             matches!(
                 ev,
-                RuntimeEvent::Matchmaker(Event::<Test>::Joined { who }) if *who == 1
+                RuntimeEvent::Matchmaker(Event::<Test>::Joined { who, .. }) if *who == 1
             )
         });
         assert!(joined_seen, "expected Joined event for who=1, got: {:?}", evs);
 
         // Also assert the state persisted: live size should be 1.
-        assert_eq!(LiveSize::<Test>::get(), 1);
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 1);
     });
 }
 
@@ -60,9 +68,12 @@ fn join_queue_emits_event_and_persists() {
 fn join_queue_rejects_duplicates() {
     new_test_ext().execute_with(|| {
         set_has_hand(1, true);
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(1)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(1),
+            DEFAULT_QUEUE
+        ));
         assert_noop!(
-            Matchmaker::join_queue(SystemOrigin::signed(1)),
+            Matchmaker::join_queue(SystemOrigin::signed(1), DEFAULT_QUEUE),
             Error::<Test>::AlreadyQueued
         );
     });
@@ -71,16 +82,21 @@ fn join_queue_rejects_duplicates() {
 #[test]
 fn queue_capacity_enforced() {
     new_test_ext().execute_with(|| {
-        // QueueCapacityConst is defined in mock.rs; fill it completely.
+        // QueueCapacityConst is defined in mock.rs; fill it completely with players rated too
+        // far apart to be paired off as they join.
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
         for who in 1..=mock::QueueCapacityConst::get() as u64 {
-            set_has_hand(who, true);
-            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+            join_rated(who, who as u32 * 1000);
         }
         // One more should fail (ensure the overflow player also has a preset hand so we hit QueueFull, not NoPresetHand)
         let overflow = mock::QueueCapacityConst::get() as u64 + 1;
         set_has_hand(overflow, true);
         assert_noop!(
-            Matchmaker::join_queue(SystemOrigin::signed(overflow)),
+            Matchmaker::join_queue(SystemOrigin::signed(overflow), DEFAULT_QUEUE),
             Error::<Test>::QueueFull
         );
     });
@@ -90,7 +106,10 @@ fn queue_capacity_enforced() {
 fn leave_queue_works_and_emits() {
     new_test_ext().execute_with(|| {
         set_has_hand(1, true);
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(1)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(1),
+            DEFAULT_QUEUE
+        ));
         assert_ok!(Matchmaker::leave_queue(SystemOrigin::signed(1)));
 
         // Event last should be QueueLeft
@@ -126,13 +145,16 @@ fn join_queue_requires_current_hand() {
 
         // Without a hand -> should fail
         assert_noop!(
-            Matchmaker::join_queue(SystemOrigin::signed(1)),
+            Matchmaker::join_queue(SystemOrigin::signed(1), DEFAULT_QUEUE),
             Error::<Test>::NoPresetHand
         );
 
         // Give account 1 a hand -> should succeed
         set_has_hand(1, true);
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(1)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(1),
+            DEFAULT_QUEUE
+        ));
     });
 }
 
@@ -144,7 +166,10 @@ fn try_match_noop_with_fewer_than_two() {
         assert_ok!(Matchmaker::try_match(SystemOrigin::signed(99)));
         // 1 player
         set_has_hand(1, true);
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(1)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(1),
+            DEFAULT_QUEUE
+        ));
         assert_ok!(Matchmaker::try_match(SystemOrigin::signed(99)));
         // No MatchFormed or Matched events
         let mm = filter_matchmaker(&take_events());
@@ -162,11 +187,20 @@ fn try_match_forms_two_and_removes_from_head_fifo() {
     new_test_ext().execute_with(|| {
         // Join three to check FIFO (1,2 should be matched; 3 remains)
         set_has_hand(1, true);
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(1)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(1),
+            DEFAULT_QUEUE
+        ));
         set_has_hand(2, true);
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(2)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(2),
+            DEFAULT_QUEUE
+        ));
         set_has_hand(3, true);
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(3)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(3),
+            DEFAULT_QUEUE
+        ));
 
         assert_ok!(Matchmaker::try_match(SystemOrigin::signed(99)));
 
@@ -191,7 +225,10 @@ fn multiple_try_match_calls_form_multiple_pairs_in_fifo_order() {
         // 1..=6 -> expect pairs (1,2), (3,4); then 5,6 remain until next call.
         for who in 1..=6 {
             set_has_hand(who, true);
-            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+            assert_ok!(Matchmaker::join_queue(
+                SystemOrigin::signed(who),
+                DEFAULT_QUEUE
+            ));
         }
 
         assert_ok!(Matchmaker::try_match(SystemOrigin::signed(7)));
@@ -226,7 +263,10 @@ fn leaving_middle_preserves_order() {
         // queue: [1,2,3,4]
         for who in 1..=4 {
             set_has_hand(who, true);
-            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+            assert_ok!(Matchmaker::join_queue(
+                SystemOrigin::signed(who),
+                DEFAULT_QUEUE
+            ));
         }
         // 2 leaves -> [1,3,4]
         assert_ok!(Matchmaker::leave_queue(SystemOrigin::signed(2)));
@@ -249,9 +289,15 @@ fn leaving_middle_preserves_order() {
 fn rejoin_after_leave_is_allowed() {
     new_test_ext().execute_with(|| {
         set_has_hand(10, true);
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(10)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(10),
+            DEFAULT_QUEUE
+        ));
         assert_ok!(Matchmaker::leave_queue(SystemOrigin::signed(10)));
-        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(10)));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(10),
+            DEFAULT_QUEUE
+        ));
     });
 }
 
@@ -260,7 +306,7 @@ fn calls_require_signed_origin() {
     new_test_ext().execute_with(|| {
         // join_queue
         assert!(matches!(
-            Matchmaker::join_queue(SystemOrigin::none()),
+            Matchmaker::join_queue(SystemOrigin::none(), DEFAULT_QUEUE),
             Err(DispatchError::BadOrigin)
         ));
         // leave_queue
@@ -286,7 +332,10 @@ fn finalize_blocks_does_not_break_queue() {
         // Add some players
         for who in 1..=3 {
             set_has_hand(who, true);
-            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who)));
+            assert_ok!(Matchmaker::join_queue(
+                SystemOrigin::signed(who),
+                DEFAULT_QUEUE
+            ));
         }
         // Simulate two blocks
         frame_system::Pallet::<Test>::set_block_number(1);
@@ -295,3 +344,733 @@ fn finalize_blocks_does_not_break_queue() {
         <Matchmaker as frame_support::traits::Hooks<BlockNumberFor<Test>>>::on_finalize(2);
     });
 }
+
+fn strategy() -> MatchStrategy {
+    Matchmaker::queue_info(DEFAULT_QUEUE)
+        .expect("the default queue is always open")
+        .strategy
+}
+
+/// Queue `who` with `rating` under the rated strategy.
+fn join_rated(who: u64, rating: u32) {
+    set_has_hand(who, true);
+    set_rating(who, rating);
+    assert_ok!(Matchmaker::join_queue(
+        SystemOrigin::signed(who),
+        DEFAULT_QUEUE
+    ));
+}
+
+#[test]
+fn set_strategy_requires_admin_and_defaults_to_fifo() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(strategy(), MatchStrategy::Fifo);
+        assert_noop!(
+            Matchmaker::set_strategy(SystemOrigin::signed(1), DEFAULT_QUEUE, MatchStrategy::Rated),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
+        assert_eq!(strategy(), MatchStrategy::Rated);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Matchmaker(Event::StrategySet {
+                queue: DEFAULT_QUEUE,
+                strategy: MatchStrategy::Rated
+            })
+        );
+    });
+}
+
+#[test]
+fn rated_strategy_pairs_closest_ratings() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
+        join_rated(1, 1500);
+        join_rated(2, 2400);
+        assert!(created_games().is_empty());
+
+        // 3 is closer to 1 than 2 is, even though 2 queued first.
+        join_rated(3, 1550);
+        assert_eq!(created_games(), vec![(1, 3)]);
+        assert!(InQueue::<Test>::contains_key(2));
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 1);
+        assert!(!JoinedAt::<Test>::contains_key(1));
+
+        // The slot 1 vacated at the head no longer counts against capacity.
+        assert_eq!(Head::<Test>::get(DEFAULT_QUEUE), 1);
+        assert_eq!(Ring::<Test>::get(DEFAULT_QUEUE, 1), Some(2));
+    });
+}
+
+#[test]
+fn rating_window_widens_with_wait() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
+        join_rated(1, 1500);
+        join_rated(2, 1800);
+
+        // A 300 point gap needs 100 + 10 * 20 blocks of waiting.
+        run_to_block(20);
+        assert_ok!(Matchmaker::process_queue(
+            SystemOrigin::signed(9),
+            DEFAULT_QUEUE
+        ));
+        assert!(created_games().is_empty());
+
+        run_to_block(21);
+        assert_ok!(Matchmaker::process_queue(
+            SystemOrigin::signed(9),
+            DEFAULT_QUEUE
+        ));
+        assert_eq!(created_games(), vec![(1, 2)]);
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 0);
+    });
+}
+
+#[test]
+fn rated_strategy_falls_back_to_fifo_after_long_wait() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
+        join_rated(1, 1000);
+        run_to_block(10);
+        join_rated(2, 3000);
+        join_rated(3, 5000);
+
+        run_to_block(50);
+        assert_ok!(Matchmaker::process_queue(
+            SystemOrigin::signed(9),
+            DEFAULT_QUEUE
+        ));
+        assert!(created_games().is_empty());
+
+        // 1 has now waited 50 blocks and is paired with the next in line.
+        run_to_block(51);
+        assert_ok!(Matchmaker::process_queue(
+            SystemOrigin::signed(9),
+            DEFAULT_QUEUE
+        ));
+        assert_eq!(created_games(), vec![(1, 2)]);
+        assert!(InQueue::<Test>::contains_key(3));
+    });
+}
+
+fn has_event(event: Event<Test>) -> bool {
+    take_events().contains(&RuntimeEvent::Matchmaker(event))
+}
+
+#[test]
+fn matched_carries_the_created_game_id() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=4 {
+            set_has_hand(who, true);
+            assert_ok!(Matchmaker::join_queue(
+                SystemOrigin::signed(who),
+                DEFAULT_QUEUE
+            ));
+        }
+        assert!(has_event(Event::Matched {
+            queue: DEFAULT_QUEUE,
+            players: [1, 2],
+            game_id: 1
+        }));
+        assert!(has_event(Event::Matched {
+            queue: DEFAULT_QUEUE,
+            players: [3, 4],
+            game_id: 2
+        }));
+    });
+}
+
+#[test]
+fn failed_match_drops_culprit_and_requeues_partner_first() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            set_has_hand(who, true);
+        }
+        set_unplayable(2);
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(1),
+            DEFAULT_QUEUE
+        ));
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(2),
+            DEFAULT_QUEUE
+        ));
+
+        assert!(has_event(Event::MatchFailed {
+            who: Some(2),
+            reason: DispatchError::Other("unplayable")
+        }));
+        assert!(created_games().is_empty());
+        assert!(InQueue::<Test>::contains_key(1));
+        assert!(!InQueue::<Test>::contains_key(2));
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 1);
+
+        // 1 is back at the front and is the next to be matched.
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(3),
+            DEFAULT_QUEUE
+        ));
+        assert_eq!(created_games(), vec![(1, 3)]);
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 0);
+    });
+}
+
+#[test]
+fn failed_pair_requeues_both_and_retries_later() {
+    new_test_ext().execute_with(|| {
+        set_fail_pairs(true);
+        for who in 1..=2 {
+            set_has_hand(who, true);
+            assert_ok!(Matchmaker::join_queue(
+                SystemOrigin::signed(who),
+                DEFAULT_QUEUE
+            ));
+        }
+        assert!(has_event(Event::MatchFailed {
+            who: None,
+            reason: DispatchError::Other("pair")
+        }));
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 2);
+        assert_eq!(JoinedAt::<Test>::get(1), 1);
+
+        set_fail_pairs(false);
+        run_to_block(2);
+        assert_ok!(Matchmaker::process_queue(
+            SystemOrigin::signed(9),
+            DEFAULT_QUEUE
+        ));
+        assert_eq!(created_games(), vec![(1, 2)]);
+    });
+}
+
+#[test]
+fn failed_rated_match_keeps_the_partners_slot() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
+        set_unplayable(1);
+        join_rated(1, 1500);
+        join_rated(2, 1500);
+        assert!(!InQueue::<Test>::contains_key(1));
+        assert_eq!(Ring::<Test>::get(DEFAULT_QUEUE, 1), Some(2));
+        assert_eq!(Head::<Test>::get(DEFAULT_QUEUE), 1);
+
+        join_rated(3, 1500);
+        assert_eq!(created_games(), vec![(2, 3)]);
+    });
+}
+
+fn idle(n: u64) {
+    run_to_block(n);
+    <Matchmaker as Hooks<u64>>::on_idle(n, Weight::MAX);
+}
+
+/// Queue 1 and 2 with the ready-check on, so they are proposed as a pair at block 1.
+fn propose_one_and_two() {
+    AcceptWindow::set(&5);
+    for who in 1..=2 {
+        set_has_hand(who, true);
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(who),
+            DEFAULT_QUEUE
+        ));
+    }
+    assert!(has_event(Event::MatchProposed {
+        id: 0,
+        queue: DEFAULT_QUEUE,
+        players: [1, 2],
+        deadline: 6
+    }));
+}
+
+#[test]
+fn stale_entries_are_evicted_on_idle() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
+        join_rated(1, 1000);
+        run_to_block(40);
+        join_rated(2, 5000);
+
+        // QueueTimeout is 100 blocks.
+        idle(100);
+        assert!(InQueue::<Test>::contains_key(1));
+
+        idle(101);
+        assert!(has_event(Event::Expired {
+            queue: DEFAULT_QUEUE,
+            who: 1
+        }));
+        assert!(!InQueue::<Test>::contains_key(1));
+        assert!(!JoinedAt::<Test>::contains_key(1));
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 1);
+        assert_eq!(
+            Ring::<Test>::get(DEFAULT_QUEUE, Head::<Test>::get(DEFAULT_QUEUE)),
+            Some(2)
+        );
+
+        idle(140);
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 0);
+        assert_eq!(
+            Head::<Test>::get(DEFAULT_QUEUE),
+            Tail::<Test>::get(DEFAULT_QUEUE)
+        );
+    });
+}
+
+#[test]
+fn players_without_a_join_block_are_skipped_by_eviction() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
+        join_rated(1, 1000);
+        // As if queued before join blocks were recorded
+        JoinedAt::<Test>::remove(1);
+        join_rated(2, 5000);
+
+        idle(500);
+        assert!(has_event(Event::Expired {
+            queue: DEFAULT_QUEUE,
+            who: 2
+        }));
+        assert!(InQueue::<Test>::contains_key(1));
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 1);
+        // 1 keeps the head slot; the one 2 held is freed behind it
+        assert_eq!(Head::<Test>::get(DEFAULT_QUEUE), 0);
+        assert_eq!(Ring::<Test>::get(DEFAULT_QUEUE, 0), Some(1));
+        assert_eq!(Ring::<Test>::get(DEFAULT_QUEUE, 1), None);
+    });
+}
+
+#[test]
+fn slots_left_behind_by_rejoining_are_dead() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
+        join_rated(3, 3000);
+        join_rated(1, 1000);
+        assert_ok!(Matchmaker::leave_queue(SystemOrigin::signed(1)));
+        join_rated(2, 5000);
+        run_to_block(2);
+        join_rated(1, 1000);
+        assert_eq!(Matchmaker::in_queue(1), Some((DEFAULT_QUEUE, 3)));
+
+        // 1's old slot doesn't hold up the eviction of 2 behind it
+        idle(101);
+        for who in [3, 2] {
+            assert!(has_event(Event::Expired {
+                queue: DEFAULT_QUEUE,
+                who
+            }));
+        }
+        assert!(InQueue::<Test>::contains_key(1));
+        assert_eq!(Head::<Test>::get(DEFAULT_QUEUE), 3);
+    });
+}
+
+#[test]
+fn ready_check_creates_game_once_both_accept() {
+    new_test_ext().execute_with(|| {
+        propose_one_and_two();
+        assert!(created_games().is_empty());
+        assert!(!InQueue::<Test>::contains_key(1));
+        assert_noop!(
+            Matchmaker::join_queue(SystemOrigin::signed(1), DEFAULT_QUEUE),
+            Error::<Test>::MatchPending
+        );
+
+        assert_ok!(Matchmaker::accept_match(SystemOrigin::signed(1)));
+        assert_noop!(
+            Matchmaker::accept_match(SystemOrigin::signed(1)),
+            Error::<Test>::AlreadyAccepted
+        );
+        assert!(created_games().is_empty());
+
+        assert_ok!(Matchmaker::accept_match(SystemOrigin::signed(2)));
+        assert_eq!(created_games(), vec![(1, 2)]);
+        assert!(!Proposals::<Test>::contains_key(0));
+        assert_eq!(Matchmaker::proposal_of(1), None);
+        assert_noop!(
+            Matchmaker::accept_match(SystemOrigin::signed(2)),
+            Error::<Test>::NoProposal
+        );
+    });
+}
+
+#[test]
+fn declining_cools_down_and_requeues_partner() {
+    new_test_ext().execute_with(|| {
+        propose_one_and_two();
+        assert_ok!(Matchmaker::decline_match(SystemOrigin::signed(2)));
+
+        assert!(has_event(Event::CooldownStarted { who: 2, until: 11 }));
+        assert!(InQueue::<Test>::contains_key(1));
+        assert!(!Proposals::<Test>::contains_key(0));
+        assert_noop!(
+            Matchmaker::join_queue(SystemOrigin::signed(2), DEFAULT_QUEUE),
+            Error::<Test>::CoolingDown
+        );
+
+        run_to_block(11);
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(2),
+            DEFAULT_QUEUE
+        ));
+        assert_eq!(Matchmaker::cooldown_until(2), None);
+        assert_eq!(Matchmaker::proposal_of(2), Some(1));
+    });
+}
+
+#[test]
+fn requeue_front_wraps_below_slot_zero() {
+    new_test_ext().execute_with(|| {
+        open_queue(1, 4, 0);
+        AcceptWindow::set(&5);
+        for who in 1..=3 {
+            set_has_hand(who, true);
+        }
+        for who in 1..=2 {
+            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who), 1));
+        }
+        // The ring is empty again; start its counters back at zero
+        Head::<Test>::insert(1, 0);
+        Tail::<Test>::insert(1, 0);
+
+        assert_ok!(Matchmaker::decline_match(SystemOrigin::signed(2)));
+        assert_eq!(Head::<Test>::get(1), u32::MAX);
+        assert_eq!(Matchmaker::in_queue(1), Some((1, 3)));
+        assert_eq!(Ring::<Test>::get(1, 3), Some(1));
+
+        AcceptWindow::set(&0);
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(3), 1));
+        assert_eq!(Ring::<Test>::get(1, 0), Some(3));
+        assert_eq!(created_games(), vec![(1, 3)]);
+    });
+}
+
+#[test]
+fn lapsed_proposal_requeues_acceptor_and_cools_down_the_other() {
+    new_test_ext().execute_with(|| {
+        propose_one_and_two();
+        assert_ok!(Matchmaker::accept_match(SystemOrigin::signed(1)));
+
+        idle(6);
+        assert!(Proposals::<Test>::contains_key(0));
+
+        run_to_block(7);
+        assert_noop!(
+            Matchmaker::accept_match(SystemOrigin::signed(2)),
+            Error::<Test>::ProposalExpired
+        );
+        idle(7);
+        assert!(has_event(Event::ProposalLapsed { id: 0 }));
+        assert!(InQueue::<Test>::contains_key(1));
+        assert_eq!(Matchmaker::cooldown_until(2), Some(17));
+        assert_eq!(Matchmaker::proposal_of(2), None);
+        assert_eq!(ExpiryCursor::<Test>::get(), 1);
+        assert!(created_games().is_empty());
+    });
+}
+
+/// Open `queue` as a FIFO queue of `capacity` slots whose games get `params`.
+fn open_queue(queue: QueueId, capacity: u32, params: u32) {
+    assert_ok!(Matchmaker::set_queue(
+        SystemOrigin::root(),
+        queue,
+        QueueInfo {
+            name: b"wager".to_vec().try_into().unwrap(),
+            capacity,
+            strategy: MatchStrategy::Fifo,
+            params,
+        }
+    ));
+}
+
+#[test]
+fn queues_pair_separately_and_forward_their_params() {
+    new_test_ext().execute_with(|| {
+        open_queue(1, 4, 7);
+        for who in 1..=4 {
+            set_has_hand(who, true);
+        }
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(1),
+            DEFAULT_QUEUE
+        ));
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(2), 1));
+        assert!(created_games().is_empty());
+        assert_eq!(Matchmaker::in_queue(2), Some((1, 0)));
+
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(3), 1));
+        assert!(has_event(Event::Matched {
+            queue: 1,
+            players: [2, 3],
+            game_id: 1
+        }));
+        assert_eq!(game_params(), vec![7]);
+
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(4),
+            DEFAULT_QUEUE
+        ));
+        assert_eq!(created_games(), vec![(2, 3), (1, 4)]);
+        assert_eq!(game_params(), vec![7, 0]);
+    });
+}
+
+#[test]
+fn queue_sizes_are_reported_per_queue() {
+    new_test_ext().execute_with(|| {
+        open_queue(5, 8, 1);
+        for who in 1..=2 {
+            set_has_hand(who, true);
+        }
+        assert_ok!(Matchmaker::join_queue(
+            SystemOrigin::signed(1),
+            DEFAULT_QUEUE
+        ));
+        assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(2), 5));
+
+        let sizes = |views: Vec<QueueView<u32>>| {
+            views
+                .into_iter()
+                .map(|view| (view.queue, view.info.capacity, view.live))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sizes(Matchmaker::queues()), vec![(0, 64, 1), (5, 8, 1)]);
+
+        assert_ok!(Matchmaker::leave_queue(SystemOrigin::signed(2)));
+        assert!(has_event(Event::Left { queue: 5, who: 2 }));
+        assert_eq!(sizes(Matchmaker::queues()), vec![(0, 64, 1), (5, 8, 0)]);
+    });
+}
+
+#[test]
+fn unknown_queues_are_rejected() {
+    new_test_ext().execute_with(|| {
+        set_has_hand(1, true);
+        assert_noop!(
+            Matchmaker::join_queue(SystemOrigin::signed(1), 9),
+            Error::<Test>::UnknownQueue
+        );
+        assert_noop!(
+            Matchmaker::process_queue(SystemOrigin::signed(1), 9),
+            Error::<Test>::UnknownQueue
+        );
+        assert_noop!(
+            Matchmaker::set_strategy(SystemOrigin::root(), 9, MatchStrategy::Rated),
+            Error::<Test>::UnknownQueue
+        );
+        assert_noop!(
+            Matchmaker::remove_queue(SystemOrigin::root(), 9),
+            Error::<Test>::UnknownQueue
+        );
+    });
+}
+
+#[test]
+fn set_queue_checks_origin_capacity_and_count() {
+    new_test_ext().execute_with(|| {
+        let info = |capacity| QueueInfo {
+            capacity,
+            ..Default::default()
+        };
+        assert_noop!(
+            Matchmaker::set_queue(SystemOrigin::signed(1), 1, info(4)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Matchmaker::set_queue(SystemOrigin::root(), 1, info(1)),
+            Error::<Test>::BadCapacity
+        );
+        assert_noop!(
+            Matchmaker::set_queue(SystemOrigin::root(), 1, info(65)),
+            Error::<Test>::BadCapacity
+        );
+        assert_noop!(
+            Matchmaker::set_queue(SystemOrigin::root(), 1, info(6)),
+            Error::<Test>::BadCapacity
+        );
+
+        // MaxQueues is 4.
+        for queue in 1..=4 {
+            open_queue(queue, 4, 0);
+        }
+        assert_noop!(
+            Matchmaker::set_queue(SystemOrigin::root(), 5, info(4)),
+            Error::<Test>::TooManyQueues
+        );
+        open_queue(4, 8, 3);
+        assert_eq!(Matchmaker::queue_info(4).map(|q| q.params), Some(3));
+    });
+}
+
+#[test]
+fn queue_capacity_only_changes_while_empty() {
+    new_test_ext().execute_with(|| {
+        open_queue(1, 2, 0);
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            1,
+            MatchStrategy::Rated
+        ));
+        join_rated_in(1, 1, 1000);
+        join_rated_in(1, 2, 5000);
+        set_has_hand(3, true);
+        assert_noop!(
+            Matchmaker::join_queue(SystemOrigin::signed(3), 1),
+            Error::<Test>::QueueFull
+        );
+        assert_noop!(
+            Matchmaker::set_queue(
+                SystemOrigin::root(),
+                1,
+                QueueInfo {
+                    capacity: 4,
+                    ..Default::default()
+                }
+            ),
+            Error::<Test>::QueueNotEmpty
+        );
+        assert_noop!(
+            Matchmaker::remove_queue(SystemOrigin::root(), 1),
+            Error::<Test>::QueueNotEmpty
+        );
+
+        for who in 1..=2 {
+            assert_ok!(Matchmaker::leave_queue(SystemOrigin::signed(who)));
+        }
+        open_queue(1, 4, 0);
+        assert_eq!(Head::<Test>::get(1), Tail::<Test>::get(1));
+        assert_eq!(Ring::<Test>::iter_prefix(1).count(), 0);
+
+        assert_ok!(Matchmaker::remove_queue(SystemOrigin::root(), 1));
+        assert!(has_event(Event::QueueRemoved { queue: 1 }));
+        assert_eq!(Matchmaker::queue_info(1), None);
+    });
+}
+
+/// Queue `who` in `queue` with `rating`.
+fn join_rated_in(queue: QueueId, who: u64, rating: u32) {
+    set_has_hand(who, true);
+    set_rating(who, rating);
+    assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who), queue));
+}
+
+#[test]
+fn removing_the_default_queue_restores_its_defaults() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Matchmaker::set_strategy(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE,
+            MatchStrategy::Rated
+        ));
+        assert!(Queues::<Test>::contains_key(DEFAULT_QUEUE));
+
+        assert_ok!(Matchmaker::remove_queue(
+            SystemOrigin::root(),
+            DEFAULT_QUEUE
+        ));
+        assert_eq!(strategy(), MatchStrategy::Fifo);
+        assert_noop!(
+            Matchmaker::remove_queue(SystemOrigin::root(), DEFAULT_QUEUE),
+            Error::<Test>::UnknownQueue
+        );
+    });
+}
+
+#[test]
+fn stale_entries_are_evicted_from_every_queue() {
+    new_test_ext().execute_with(|| {
+        open_queue(1, 4, 0);
+        for (who, queue) in [(1, DEFAULT_QUEUE), (2, 1)] {
+            set_has_hand(who, true);
+            assert_ok!(Matchmaker::join_queue(SystemOrigin::signed(who), queue));
+        }
+
+        idle(101);
+        assert!(has_event(Event::Expired {
+            queue: DEFAULT_QUEUE,
+            who: 1
+        }));
+        assert!(has_event(Event::Expired { queue: 1, who: 2 }));
+        assert_eq!(LiveSize::<Test>::get(1), 0);
+    });
+}
+
+#[test]
+fn v1_migration_moves_the_single_queue_into_the_default_queue() {
+    use crate::migrations::v1::{old, MigrateV0ToV1};
+    use frame_support::storage::unhashed;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        // The single queue as the pallet stored it before v1
+        StorageVersion::new(0).put::<Matchmaker>();
+        unhashed::put(&old::Head::<Test>::hashed_key(), &3u32);
+        unhashed::put(&old::Tail::<Test>::hashed_key(), &6u32);
+        unhashed::put(&old::LiveSize::<Test>::hashed_key(), &2u32);
+        // 1 left and joined again, so only slot 5 is theirs
+        for (idx, who) in [(3u32, 1u64), (4, 2), (5, 1)] {
+            unhashed::put(&old::Ring::<Test>::hashed_key_for(idx), &who);
+            unhashed::put(&InQueue::<Test>::hashed_key_for(who), &());
+        }
+
+        run_to_block(7);
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Matchmaker::on_chain_storage_version(),
+            StorageVersion::new(1)
+        );
+        assert!(!unhashed::exists(&old::Head::<Test>::hashed_key()));
+        assert!(!unhashed::exists(&old::Ring::<Test>::hashed_key_for(3)));
+        assert_eq!(Head::<Test>::get(DEFAULT_QUEUE), 3);
+        assert_eq!(Tail::<Test>::get(DEFAULT_QUEUE), 6);
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 2);
+        assert_eq!(Ring::<Test>::get(DEFAULT_QUEUE, 4), Some(2));
+        assert_eq!(Matchmaker::in_queue(1), Some((DEFAULT_QUEUE, 5)));
+        assert_eq!(Matchmaker::in_queue(2), Some((DEFAULT_QUEUE, 4)));
+        assert_eq!(JoinedAt::<Test>::get(1), 7);
+
+        // Their queue timeout (100 blocks) runs from the upgrade
+        idle(106);
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 2);
+        idle(107);
+        assert!(has_event(Event::Expired {
+            queue: DEFAULT_QUEUE,
+            who: 1
+        }));
+        assert_eq!(LiveSize::<Test>::get(DEFAULT_QUEUE), 0);
+    });
+}
@@ -0,0 +1,375 @@
+//! Weights for pallet_eterra_simple_matchmaker
+//!
+//! These values are estimates, not benchmark results. They were worked out by hand from
+//! the storage each call touches, sized for the worst cases set up in `benchmarking.rs`.
+//! Replace them with measured weights from reference hardware before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_eterra_simple_matchmaker.
+pub trait WeightInfo {
+	fn join_queue(n: u32) -> Weight;
+	fn leave_queue() -> Weight;
+	fn process_queue(n: u32) -> Weight;
+	fn set_strategy() -> Weight;
+	fn accept_match() -> Weight;
+	fn decline_match() -> Weight;
+	fn set_queue(n: u32) -> Weight;
+	fn remove_queue(n: u32) -> Weight;
+	fn idle_base() -> Weight;
+	fn lapse_proposal() -> Weight;
+	fn evict_queue() -> Weight;
+	fn evict_entry() -> Weight;
+}
+
+/// Estimated weights for pallet_eterra_simple_matchmaker.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: EterraSimpleMatchMaker InQueue (r:129 w:65)
+	/// Storage: Eterra CurrentHandOf (r:65 w:0)
+	/// Storage: EterraSimpleMatchMaker Head (r:2 w:2)
+	/// Storage: EterraSimpleMatchMaker Tail (r:2 w:1)
+	/// Storage: EterraSimpleMatchMaker Ring (r:128 w:65)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:2 w:2)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:64 w:65)
+	/// Storage: EterraRating Ratings (r:64 w:0)
+	/// Storage: Eterra ActiveGameOf (r:64 w:64)
+	/// Storage: Eterra GameStorage (r:32 w:32)
+	/// Storage: Eterra GameModes (r:0 w:32)
+	/// Storage: Eterra PlayerGames (r:64 w:64)
+	/// Storage: Eterra GameRules (r:0 w:32)
+	/// Storage: Eterra PendingDeals (r:32 w:32)
+	/// Storage: EterraSimpleMatchMaker PendingProposal (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker CooldownUntil (r:1 w:1)
+	fn join_queue(n: u32) -> Weight {
+		// The range of component `n` is `[2, 64]`.
+		Weight::from_parts(45_000_000, 9480)
+			.saturating_add(Weight::from_parts(46_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+	}
+	/// Storage: EterraSimpleMatchMaker InQueue (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	fn leave_queue() -> Weight {
+		Weight::from_parts(23_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:2 w:1)
+	/// Storage: EterraSimpleMatchMaker Head (r:2 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:2 w:0)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:128 w:64)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:128 w:64)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:64 w:64)
+	/// Storage: EterraRating Ratings (r:64 w:0)
+	/// Storage: Eterra CurrentHandOf (r:64 w:0)
+	/// Storage: Eterra ActiveGameOf (r:64 w:64)
+	/// Storage: Eterra GameStorage (r:32 w:32)
+	/// Storage: Eterra GameModes (r:0 w:32)
+	/// Storage: Eterra PlayerGames (r:64 w:64)
+	/// Storage: Eterra GameRules (r:0 w:32)
+	/// Storage: Eterra PendingDeals (r:32 w:32)
+	fn process_queue(n: u32) -> Weight {
+		// The range of component `n` is `[2, 64]`.
+		Weight::from_parts(32_000_000, 9480)
+			.saturating_add(Weight::from_parts(46_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+	}
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker CounterForQueues (r:1 w:1)
+	fn set_strategy() -> Weight {
+		Weight::from_parts(12_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker PendingProposal (r:1 w:2)
+	/// Storage: EterraSimpleMatchMaker Proposals (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:0 w:2)
+	/// Storage: Eterra CurrentHandOf (r:2 w:0)
+	/// Storage: Eterra ActiveGameOf (r:2 w:2)
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:0 w:1)
+	/// Storage: Eterra PlayerGames (r:2 w:2)
+	/// Storage: Eterra GameRules (r:0 w:1)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Head (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	fn accept_match() -> Weight {
+		Weight::from_parts(95_000_000, 9480)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker PendingProposal (r:1 w:2)
+	/// Storage: EterraSimpleMatchMaker Proposals (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker CooldownUntil (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Head (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	fn decline_match() -> Weight {
+		Weight::from_parts(32_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker CounterForQueues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:64)
+	/// Storage: EterraSimpleMatchMaker Head (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:0 w:1)
+	fn set_queue(n: u32) -> Weight {
+		// The range of component `n` is `[3, 64]`.
+		Weight::from_parts(14_000_000, 3529)
+			.saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker CounterForQueues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:64)
+	/// Storage: EterraSimpleMatchMaker Head (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:0 w:1)
+	fn remove_queue(n: u32) -> Weight {
+		// The range of component `n` is `[2, 64]`.
+		Weight::from_parts(13_000_000, 3529)
+			.saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: EterraSimpleMatchMaker ExpiryCursor (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker NextProposalId (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	fn idle_base() -> Weight {
+		Weight::from_parts(5_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker Proposals (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker PendingProposal (r:0 w:2)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker CooldownUntil (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Head (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker ExpiryCursor (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	fn lapse_proposal() -> Weight {
+		Weight::from_parts(30_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Tail (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Head (r:1 w:1)
+	fn evict_queue() -> Weight {
+		Weight::from_parts(7_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker Ring (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	fn evict_entry() -> Weight {
+		Weight::from_parts(18_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: EterraSimpleMatchMaker InQueue (r:129 w:65)
+	/// Storage: Eterra CurrentHandOf (r:65 w:0)
+	/// Storage: EterraSimpleMatchMaker Head (r:2 w:2)
+	/// Storage: EterraSimpleMatchMaker Tail (r:2 w:1)
+	/// Storage: EterraSimpleMatchMaker Ring (r:128 w:65)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:2 w:2)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:64 w:65)
+	/// Storage: EterraRating Ratings (r:64 w:0)
+	/// Storage: Eterra ActiveGameOf (r:64 w:64)
+	/// Storage: Eterra GameStorage (r:32 w:32)
+	/// Storage: Eterra GameModes (r:0 w:32)
+	/// Storage: Eterra PlayerGames (r:64 w:64)
+	/// Storage: Eterra GameRules (r:0 w:32)
+	/// Storage: Eterra PendingDeals (r:32 w:32)
+	/// Storage: EterraSimpleMatchMaker PendingProposal (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker CooldownUntil (r:1 w:1)
+	fn join_queue(n: u32) -> Weight {
+		// The range of component `n` is `[2, 64]`.
+		Weight::from_parts(45_000_000, 9480)
+			.saturating_add(Weight::from_parts(46_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
+	}
+	/// Storage: EterraSimpleMatchMaker InQueue (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	fn leave_queue() -> Weight {
+		Weight::from_parts(23_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:2 w:1)
+	/// Storage: EterraSimpleMatchMaker Head (r:2 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:2 w:0)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:128 w:64)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:128 w:64)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:64 w:64)
+	/// Storage: EterraRating Ratings (r:64 w:0)
+	/// Storage: Eterra CurrentHandOf (r:64 w:0)
+	/// Storage: Eterra ActiveGameOf (r:64 w:64)
+	/// Storage: Eterra GameStorage (r:32 w:32)
+	/// Storage: Eterra GameModes (r:0 w:32)
+	/// Storage: Eterra PlayerGames (r:64 w:64)
+	/// Storage: Eterra GameRules (r:0 w:32)
+	/// Storage: Eterra PendingDeals (r:32 w:32)
+	fn process_queue(n: u32) -> Weight {
+		// The range of component `n` is `[2, 64]`.
+		Weight::from_parts(32_000_000, 9480)
+			.saturating_add(Weight::from_parts(46_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
+	}
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker CounterForQueues (r:1 w:1)
+	fn set_strategy() -> Weight {
+		Weight::from_parts(12_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker PendingProposal (r:1 w:2)
+	/// Storage: EterraSimpleMatchMaker Proposals (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:0 w:2)
+	/// Storage: Eterra CurrentHandOf (r:2 w:0)
+	/// Storage: Eterra ActiveGameOf (r:2 w:2)
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:0 w:1)
+	/// Storage: Eterra PlayerGames (r:2 w:2)
+	/// Storage: Eterra GameRules (r:0 w:1)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Head (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	fn accept_match() -> Weight {
+		Weight::from_parts(95_000_000, 9480)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker PendingProposal (r:1 w:2)
+	/// Storage: EterraSimpleMatchMaker Proposals (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker CooldownUntil (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Head (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	fn decline_match() -> Weight {
+		Weight::from_parts(32_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker CounterForQueues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:64)
+	/// Storage: EterraSimpleMatchMaker Head (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:0 w:1)
+	fn set_queue(n: u32) -> Weight {
+		// The range of component `n` is `[3, 64]`.
+		Weight::from_parts(14_000_000, 3529)
+			.saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker CounterForQueues (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:64)
+	/// Storage: EterraSimpleMatchMaker Head (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:0 w:1)
+	fn remove_queue(n: u32) -> Weight {
+		// The range of component `n` is `[2, 64]`.
+		Weight::from_parts(13_000_000, 3529)
+			.saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: EterraSimpleMatchMaker ExpiryCursor (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker NextProposalId (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	fn idle_base() -> Weight {
+		Weight::from_parts(5_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker Proposals (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker PendingProposal (r:0 w:2)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker CooldownUntil (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Head (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker Tail (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Ring (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker ExpiryCursor (r:0 w:1)
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	fn lapse_proposal() -> Weight {
+		Weight::from_parts(30_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker Queues (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Tail (r:1 w:0)
+	/// Storage: EterraSimpleMatchMaker Head (r:1 w:1)
+	fn evict_queue() -> Weight {
+		Weight::from_parts(7_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraSimpleMatchMaker Ring (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker InQueue (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker JoinedAt (r:1 w:1)
+	/// Storage: EterraSimpleMatchMaker LiveSize (r:1 w:1)
+	fn evict_entry() -> Weight {
+		Weight::from_parts(18_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+}
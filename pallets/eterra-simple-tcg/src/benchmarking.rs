@@ -0,0 +1,145 @@
+//! Benchmarking setup for pallet-eterra-simple-tcg
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as EterraSimpleTCG;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+/// Give `who` enough to cover mint fees and purchases.
+fn fund<T: Config>(who: &T::AccountId) {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 4u32.into());
+}
+
+/// Fill `who`'s owned and listed indices up to one below the limit with ids that are never
+/// looked up, so every `position` scan walks the whole list.
+fn fill_indices<T: Config>(who: &T::AccountId, from: u32) {
+    let limit = <OwnedLimit as Get<u32>>::get();
+    let ids: BoundedVec<CardId, OwnedLimit> = (from..from + limit - 1)
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("one below the limit fits; qed");
+    OwnedCards::<T>::insert(who, ids.clone());
+    ListedByOwner::<T>::insert(who, ids);
+}
+
+/// Mint a card for `who` behind near-full indices and list it for sale.
+fn listed_card<T: Config>(who: &T::AccountId) -> CardId {
+    fund::<T>(who);
+    fill_indices::<T>(who, u32::MAX / 2);
+    // Make room for the real card at the end of the owned list.
+    OwnedCards::<T>::mutate(who, |v| {
+        v.pop();
+    });
+    ListedByOwner::<T>::mutate(who, |v| {
+        v.pop();
+    });
+    let card_id = NextCardId::<T>::get();
+    Pallet::<T>::mint_card(RawOrigin::Signed(who.clone()).into())
+        .expect("funded owner can mint; qed");
+    CardPrices::<T>::insert(card_id, BalanceOf::<T>::from(1_000u32));
+    ListedByOwner::<T>::mutate(who, |v| {
+        v.try_push(card_id).expect("room was made above; qed");
+    });
+    card_id
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn mint_card() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        fill_indices::<T>(&caller, u32::MAX / 2);
+        let card_id = NextCardId::<T>::get();
+
+        #[extrinsic_call]
+        mint_card(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(Cards::<T>::get(card_id).map(|c| c.owner), Some(caller));
+    }
+
+    #[benchmark]
+    fn transfer_card() {
+        let caller: T::AccountId = whitelisted_caller();
+        let card_id = listed_card::<T>(&caller);
+        let to: T::AccountId = account("to", 0, 0);
+        fill_indices::<T>(&to, u32::MAX / 4);
+
+        #[extrinsic_call]
+        transfer_card(RawOrigin::Signed(caller), card_id, to.clone());
+
+        assert_eq!(Cards::<T>::get(card_id).map(|c| c.owner), Some(to));
+    }
+
+    #[benchmark]
+    fn set_price() {
+        let caller: T::AccountId = whitelisted_caller();
+        let card_id = listed_card::<T>(&caller);
+        CardPrices::<T>::remove(card_id);
+        ListedByOwner::<T>::mutate(&caller, |v| {
+            v.pop();
+        });
+        let price: BalanceOf<T> = 5_000u32.into();
+
+        #[extrinsic_call]
+        set_price(RawOrigin::Signed(caller), card_id, price);
+
+        assert_eq!(CardPrices::<T>::get(card_id), Some(price));
+    }
+
+    #[benchmark]
+    fn remove_price() {
+        let caller: T::AccountId = whitelisted_caller();
+        let card_id = listed_card::<T>(&caller);
+
+        #[extrinsic_call]
+        remove_price(RawOrigin::Signed(caller), card_id);
+
+        assert!(!CardPrices::<T>::contains_key(card_id));
+    }
+
+    #[benchmark]
+    fn buy_card() {
+        let seller: T::AccountId = account("seller", 0, 0);
+        let card_id = listed_card::<T>(&seller);
+        let buyer: T::AccountId = whitelisted_caller();
+        fund::<T>(&buyer);
+        fill_indices::<T>(&buyer, u32::MAX / 4);
+
+        #[extrinsic_call]
+        buy_card(RawOrigin::Signed(buyer.clone()), card_id);
+
+        assert_eq!(Cards::<T>::get(card_id).map(|c| c.owner), Some(buyer));
+    }
+
+    /// `n` cards minted in an earlier block, all due for their stats.
+    #[benchmark]
+    fn roll_minted_cards(n: Linear<0, { <PendingMintLimit as Get<u32>>::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        for _ in 0..n {
+            Pallet::<T>::mint_card(RawOrigin::Signed(caller.clone()).into())
+                .expect("funded owner can mint; qed");
+        }
+        let drawn = frame_system::Pallet::<T>::block_number() + 1u32.into();
+
+        #[block]
+        {
+            Pallet::<T>::roll_minted_cards(drawn);
+        }
+
+        assert!(PendingMints::<T>::get().is_empty());
+        assert!(Cards::<T>::iter_values().all(|c| c.finalized));
+    }
+
+    impl_benchmark_test_suite!(
+        EterraSimpleTCG,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
@@ -11,7 +11,12 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-use frame_support::traits::{Currency, ExistenceRequirement};
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use frame_support::traits::{Currency, ExistenceRequirement, Randomness};
 use frame_support::{pallet_prelude::*, traits::Get, BoundedVec};
 // ===== New: utilities for in-pallet game logic =====
 
@@ -23,9 +28,28 @@ use frame_support::pallet_prelude::ConstU32;
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{Hash, SaturatedConversion};
+use sp_runtime::traits::SaturatedConversion;
 use sp_std::prelude::*;
 
+/// Why a card is locked.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum LockReason {
+    /// Part of a hand submitted to a running game.
+    InGame,
+    /// Staked in a game whose outcome may hand it to another player.
+    Ante,
+}
+
+/// Lets game pallets pin cards to a running game so they can't be sold or given away.
+pub trait CardLocker {
+    /// Lock `card_id` for `reason`. Fails if the card does not exist or is already locked.
+    fn lock(card_id: u32, reason: LockReason) -> sp_runtime::DispatchResult;
+    /// Release a lock. No-op if the card is not locked.
+    fn unlock(card_id: u32);
+    /// Whether the card is locked for any reason.
+    fn is_locked(card_id: u32) -> bool;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -42,6 +66,9 @@ pub mod pallet {
     // Max number of cards we track per owner (bounded index)
     pub type OwnedLimit = ConstU32<600>;
 
+    // Max number of minted cards waiting for their stats to be rolled
+    pub type PendingMintLimit = ConstU32<256>;
+
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     /// Which edition a card belongs to (extensible for future sets).
@@ -86,9 +113,9 @@ pub mod pallet {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        /// A numeric seed for our randomness.
-        #[pallet::constant]
-        type RandomnessSeed: Get<u64>;
+        /// Source of the randomness card stats are rolled from. Stats are rolled in the first
+        /// block whose randomness is later than the mint.
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
         /// Currency used to charge the mint fee.
         type Currency: Currency<Self::AccountId>;
@@ -100,6 +127,9 @@ pub mod pallet {
         /// Faucet account that receives the mint fee.
         #[pallet::constant]
         type FaucetAccount: Get<Self::AccountId>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     // ------------------
@@ -175,6 +205,18 @@ pub mod pallet {
     pub type ListedByOwner<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<CardId, OwnedLimit>, ValueQuery>;
 
+    /// Cards locked by a game pallet; they cannot be transferred, listed or bought.
+    #[pallet::storage]
+    #[pallet::getter(fn lock_reason_of)]
+    pub type LockedCards<T: Config> =
+        StorageMap<_, Blake2_128Concat, CardId, LockReason, OptionQuery>;
+
+    /// Minted cards whose stats are still to be rolled, with the block each was minted in.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_mints)]
+    pub type PendingMints<T: Config> =
+        StorageValue<_, BoundedVec<(CardId, BlockNumberFor<T>), PendingMintLimit>, ValueQuery>;
+
     // ------------------
     // Events
     // ------------------
@@ -182,8 +224,10 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// A card was minted for `player` with ID `card_id`.
+        /// A card was minted for `player` with ID `card_id`. Its stats follow in a later block.
         CardMinted { player: T::AccountId, card_id: u32 },
+        /// The stats of `card_id` were rolled, in north, east, south, west order.
+        CardStatsRolled { card_id: u32, stats: [u8; 4] },
         /// A card was transferred from `from` to `to`.
         CardTransferred {
             from: T::AccountId,
@@ -219,11 +263,30 @@ pub mod pallet {
         NotCardOwner,
         OwnedListFull,
         // --- Match errors ---
+        /// The card's stats haven't been rolled yet.
         CardNotFinalized,
         /// Card is not listed for sale.
         NotForSale,
         /// Only the current owner may list/unlist.
         NotOwner,
+        /// The card is locked in a game.
+        CardLocked,
+        /// The card is not locked as a game ante.
+        CardNotLocked,
+        /// Too many minted cards are waiting for their stats; try again next block.
+        TooManyPendingMints,
+    }
+
+    // ------------------
+    // Hooks
+    // ------------------
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let (_, drawn) = T::Randomness::random(b"simple-tcg/card");
+            T::WeightInfo::roll_minted_cards(Self::roll_minted_cards(drawn))
+        }
     }
 
     // ------------------
@@ -234,7 +297,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Mint a single card for the caller.
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::mint_card())]
         pub fn mint_card(origin: OriginFor<T>) -> DispatchResult {
             let player = ensure_signed(origin)?;
             let card_id = Self::create_new_card(&player)?;
@@ -246,13 +309,17 @@ pub mod pallet {
         /// If that card is also part of a pack, it still references it, but ownership
         /// changes to `to`.
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::transfer_card())]
         pub fn transfer_card(
             origin: OriginFor<T>,
             card_id: u32,
             to: T::AccountId,
         ) -> DispatchResult {
             let from = ensure_signed(origin)?;
+            ensure!(
+                !LockedCards::<T>::contains_key(card_id),
+                Error::<T>::CardLocked
+            );
 
             // Ensure card exists and belongs to `from`
             Cards::<T>::try_mutate(card_id, |maybe_card| -> DispatchResult {
@@ -275,7 +342,7 @@ pub mod pallet {
 
         /// List a card for sale at a fixed `price` (in chain base units).
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_price())]
         pub fn set_price(
             origin: OriginFor<T>,
             card_id: CardId,
@@ -287,6 +354,10 @@ pub mod pallet {
                 .map(|c| c.owner == who)
                 .ok_or(Error::<T>::NoSuchCard)?;
             ensure!(is_owner, Error::<T>::NotOwner);
+            ensure!(
+                !LockedCards::<T>::contains_key(card_id),
+                Error::<T>::CardLocked
+            );
 
             CardPrices::<T>::insert(card_id, price);
             ListedByOwner::<T>::try_mutate(&who, |v| -> DispatchResult {
@@ -309,7 +380,7 @@ pub mod pallet {
 
         /// Remove a card from sale.
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::remove_price())]
         pub fn remove_price(origin: OriginFor<T>, card_id: CardId) -> DispatchResult {
             let who = ensure_signed(origin)?;
             // Verify ownership
@@ -330,7 +401,7 @@ pub mod pallet {
 
         /// Buy a listed card at the asking price.
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::buy_card())]
         pub fn buy_card(origin: OriginFor<T>, card_id: CardId) -> DispatchResult {
             let buyer = ensure_signed(origin)?;
 
@@ -342,6 +413,10 @@ pub mod pallet {
 
             // Prevent self-buy (optional)
             ensure!(seller != buyer, Error::<T>::NotOwner);
+            ensure!(
+                !LockedCards::<T>::contains_key(card_id),
+                Error::<T>::CardLocked
+            );
 
             // Transfer funds buyer -> seller
             <T as Config>::Currency::transfer(
@@ -367,11 +442,70 @@ pub mod pallet {
         }
     }
 
+    // ------------------
+    // Card locks (used by game pallets)
+    // ------------------
+    impl<T: Config> CardLocker for Pallet<T> {
+        fn lock(card_id: CardId, reason: LockReason) -> DispatchResult {
+            let card = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+            // A card can't enter a game before its stats are known
+            ensure!(card.finalized, Error::<T>::CardNotFinalized);
+            let owner = card.owner;
+            ensure!(
+                !LockedCards::<T>::contains_key(card_id),
+                Error::<T>::CardLocked
+            );
+            // A locked card can't be bought, so take it off the market
+            if CardPrices::<T>::contains_key(card_id) {
+                Self::unlist(card_id, &owner);
+            }
+            LockedCards::<T>::insert(card_id, reason);
+            Ok(())
+        }
+
+        fn unlock(card_id: CardId) {
+            LockedCards::<T>::remove(card_id);
+        }
+
+        fn is_locked(card_id: CardId) -> bool {
+            LockedCards::<T>::contains_key(card_id)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Hand an ante card over to `to` as the outcome of a game, releasing its lock.
+        pub fn transfer_locked_card(
+            from: &T::AccountId,
+            to: &T::AccountId,
+            card_id: CardId,
+        ) -> DispatchResult {
+            ensure!(
+                LockedCards::<T>::get(card_id) == Some(LockReason::Ante),
+                Error::<T>::CardNotLocked
+            );
+            // Check room up front so a failed transfer leaves no partial writes behind
+            ensure!(
+                (OwnedCards::<T>::decode_len(to).unwrap_or(0) as u32)
+                    < <OwnedLimit as frame_support::traits::Get<u32>>::get(),
+                Error::<T>::OwnedListFull
+            );
+            Self::do_transfer(from, to, card_id)?;
+            LockedCards::<T>::remove(card_id);
+            Self::deposit_event(Event::CardTransferred {
+                from: from.clone(),
+                to: to.clone(),
+                card_id,
+            });
+            Ok(())
+        }
+    }
+
     // ------------------
     // Pallet Internals (helpers; not dispatchables)
     // ------------------
     impl<T: Config> Pallet<T> {
-        /// Create a brand-new card with `owner`.
+        /// Create a brand-new card with `owner`. Its stats are left for `roll_minted_cards`,
+        /// so nobody can tell what they will be when the mint is signed.
         fn create_new_card(owner: &T::AccountId) -> Result<u32, DispatchError> {
             // Charge the mint fee to the caller and send it to the faucet account.
             // This will fail with an error if the caller has insufficient funds.
@@ -385,20 +519,9 @@ pub mod pallet {
             )?;
 
             let card_id = NextCardId::<T>::get();
-
-            // Derive pseudo-random bytes from block, owner, seed, and card_id
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let seed = T::RandomnessSeed::get();
-            let hash = T::Hashing::hash_of(&(current_block, owner, seed, card_id));
-
-            // Use the first 4 bytes for the four directions (1..=9)
-            let bytes = hash.as_ref();
-            let mut to_stat = |b: u8| -> u8 { (b % 9) + 1 };
-
-            let n = to_stat(bytes.get(0).copied().unwrap_or(0));
-            let e = to_stat(bytes.get(1).copied().unwrap_or(0));
-            let s = to_stat(bytes.get(2).copied().unwrap_or(0));
-            let w = to_stat(bytes.get(3).copied().unwrap_or(0));
+            let now = <frame_system::Pallet<T>>::block_number();
+            PendingMints::<T>::try_append((card_id, now))
+                .map_err(|_| Error::<T>::TooManyPendingMints)?;
 
             // Name: "Card-<id>"
             let name_string = alloc::format!("Card-{}", card_id);
@@ -408,17 +531,17 @@ pub mod pallet {
 
             let new_card_info = CardInfo {
                 owner: owner.clone(),
-                finalized: true,
-                slot_values: Some([n, e, s, w]),
+                finalized: false,
+                slot_values: None,
                 // required by the front end
                 name: name_bv,
-                north: n,
-                east: e,
-                south: s,
-                west: w,
+                north: 0,
+                east: 0,
+                south: 0,
+                west: 0,
                 // existing fields
                 card_id,
-                minted_at: <frame_system::Pallet<T>>::block_number(),
+                minted_at: now,
                 price: 0u128,
                 edition: CardEdition::Base,
                 rarity: RarityType::Common,
@@ -441,6 +564,43 @@ pub mod pallet {
             Ok(card_id)
         }
 
+        /// Roll the stats of every pending card minted before `drawn`, the block the
+        /// randomness source was last refreshed in. Returns how many cards were rolled.
+        pub fn roll_minted_cards(drawn: BlockNumberFor<T>) -> u32 {
+            let mut rolled = 0;
+            PendingMints::<T>::mutate(|pending| {
+                pending.retain(|&(card_id, minted_at)| {
+                    if minted_at >= drawn {
+                        return true;
+                    }
+                    Self::roll_card(card_id);
+                    rolled += 1;
+                    false
+                });
+            });
+            rolled
+        }
+
+        /// Roll and finalize the stats of `card_id`, 1..=9 on each side.
+        fn roll_card(card_id: CardId) {
+            let (hash, _) = T::Randomness::random(&(b"simple-tcg/card", card_id).encode());
+            let bytes = hash.as_ref();
+            let to_stat = |i: usize| -> u8 { (bytes.get(i).copied().unwrap_or(0) % 9) + 1 };
+            let stats = [to_stat(0), to_stat(1), to_stat(2), to_stat(3)];
+
+            Cards::<T>::mutate(card_id, |maybe_card| {
+                if let Some(card) = maybe_card {
+                    card.finalized = true;
+                    card.slot_values = Some(stats);
+                    card.north = stats[0];
+                    card.east = stats[1];
+                    card.south = stats[2];
+                    card.west = stats[3];
+                }
+            });
+            Self::deposit_event(Event::CardStatsRolled { card_id, stats });
+        }
+
         /// Internal: remove a card from the marketplace listings, updating indices.
         fn unlist(card_id: CardId, owner: &T::AccountId) {
             // Remove price entry if any
@@ -18,6 +18,7 @@ construct_runtime!(
         System: frame_system,
         Balances: pallet_balances,
         EterraSimpleTCGConfig: pallet_eterra_simple_tcg,
+        EterraRandomness: pallet_eterra_randomness,
     }
 );
 
@@ -25,7 +26,6 @@ type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 parameter_types! {
-    pub const RandomnessSeed: u64 = 42;
     pub const ExistentialDeposit: u128 = 0; // keep accounts alive at 0 for tests
     pub const MintFeeConst: u128 = 100;     // 100 whole tokens in tests
     pub FaucetAccountParam: u64 = ALICE;    // faucet is Alice for tests
@@ -83,12 +83,19 @@ impl pallet_balances::Config for Test {
 
 impl pallet_eterra_simple_tcg::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type RandomnessSeed = RandomnessSeed;
+    type Randomness = EterraRandomness;
 
     // Currency integration for mint fee & marketplace
     type Currency = Balances;
     type MintFee = ConstU128<100>;
     type FaucetAccount = FaucetAccountParam;
+    type WeightInfo = ();
+}
+
+impl pallet_eterra_randomness::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RevealWindow = ConstU64<10>;
+    type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -6,6 +6,21 @@ fn last_event() -> RuntimeEvent {
     System::events().pop().expect("Event expected").event
 }
 
+/// Advance one block, running every pallet's `on_initialize` in runtime order.
+fn next_block() {
+    let n = System::block_number() + 1;
+    System::set_block_number(n);
+    AllPalletsWithSystem::on_initialize(n);
+}
+
+/// Run blocks until the cards minted so far have their stats. The randomness pallet runs
+/// after this one, so that takes two blocks.
+fn roll_pending_mints() {
+    next_block();
+    next_block();
+    assert!(EterraSimpleTCGConfig::pending_mints().is_empty());
+}
+
 #[test]
 fn mint_card_charges_fee_and_mints() {
     new_test_ext().execute_with(|| {
@@ -172,3 +187,172 @@ fn buy_card_fails_if_not_listed() {
         );
     });
 }
+
+#[test]
+fn locked_cards_cannot_be_transferred_listed_or_bought() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        roll_pending_mints();
+        let id = EterraSimpleTCGConfig::owned_cards(BOB)[0];
+        assert_ok!(EterraSimpleTCGConfig::set_price(
+            RuntimeOrigin::signed(BOB),
+            id,
+            300
+        ));
+
+        // Locking takes the card off the market
+        assert_ok!(EterraSimpleTCGConfig::lock(id, LockReason::Ante));
+        assert!(EterraSimpleTCGConfig::is_locked(id));
+        assert_eq!(
+            EterraSimpleTCGConfig::lock_reason_of(id),
+            Some(LockReason::Ante)
+        );
+        assert_eq!(EterraSimpleTCGConfig::card_prices(id), None);
+        assert_noop!(
+            EterraSimpleTCGConfig::lock(id, LockReason::InGame),
+            Error::<Test>::CardLocked
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::transfer_card(RuntimeOrigin::signed(BOB), id, ALICE),
+            Error::<Test>::CardLocked
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::set_price(RuntimeOrigin::signed(BOB), id, 300),
+            Error::<Test>::CardLocked
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::buy_card(RuntimeOrigin::signed(ALICE), id),
+            Error::<Test>::NotForSale
+        );
+
+        // A game outcome moves it and releases the lock
+        assert_ok!(EterraSimpleTCGConfig::transfer_locked_card(
+            &BOB, &ALICE, id
+        ));
+        assert!(!EterraSimpleTCGConfig::is_locked(id));
+        assert_eq!(EterraSimpleTCGConfig::cards(id).unwrap().owner, ALICE);
+        assert!(EterraSimpleTCGConfig::owned_cards(ALICE).contains(&id));
+        assert_noop!(
+            EterraSimpleTCGConfig::transfer_locked_card(&ALICE, &BOB, id),
+            Error::<Test>::CardNotLocked
+        );
+    });
+}
+
+#[test]
+fn in_game_locks_only_move_with_an_unlock() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        roll_pending_mints();
+        let id = EterraSimpleTCGConfig::owned_cards(BOB)[0];
+        assert_noop!(
+            EterraSimpleTCGConfig::lock(id + 1, LockReason::InGame),
+            Error::<Test>::NoSuchCard
+        );
+
+        assert_ok!(EterraSimpleTCGConfig::lock(id, LockReason::InGame));
+        assert_noop!(
+            EterraSimpleTCGConfig::transfer_locked_card(&BOB, &ALICE, id),
+            Error::<Test>::CardNotLocked
+        );
+
+        EterraSimpleTCGConfig::unlock(id);
+        assert!(!EterraSimpleTCGConfig::is_locked(id));
+        assert_ok!(EterraSimpleTCGConfig::transfer_card(
+            RuntimeOrigin::signed(BOB),
+            id,
+            ALICE
+        ));
+    });
+}
+
+#[test]
+fn minted_cards_get_their_stats_in_a_later_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        let id = EterraSimpleTCGConfig::owned_cards(BOB)[0];
+
+        // Nothing is rolled in the mint's own block, and the card can't enter a game yet.
+        let card = EterraSimpleTCGConfig::cards(id).unwrap();
+        assert!(!card.finalized);
+        assert_eq!(card.slot_values, None);
+        assert_eq!(
+            EterraSimpleTCGConfig::pending_mints().to_vec(),
+            vec![(id, 1)]
+        );
+        assert_noop!(
+            EterraSimpleTCGConfig::lock(id, LockReason::InGame),
+            Error::<Test>::CardNotFinalized
+        );
+
+        roll_pending_mints();
+        let card = EterraSimpleTCGConfig::cards(id).unwrap();
+        let stats = [card.north, card.east, card.south, card.west];
+        assert!(card.finalized);
+        assert_eq!(card.slot_values, Some(stats));
+        assert!(stats.iter().all(|s| (1..=9).contains(s)));
+        System::assert_has_event(RuntimeEvent::EterraSimpleTCGConfig(
+            TcgEvent::CardStatsRolled { card_id: id, stats },
+        ));
+        assert_ok!(EterraSimpleTCGConfig::lock(id, LockReason::InGame));
+    });
+}
+
+/// Bob's first card, minted at block 2. Account 3 reveals `secret` in the block after the
+/// mint, before its stats are rolled.
+fn stats_of_first_mint(secret: Option<[u8; 32]>) -> [u8; 4] {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        if let Some(secret) = secret {
+            let commitment = EterraRandomness::commitment(&3, &secret);
+            assert_ok!(EterraRandomness::commit(
+                RuntimeOrigin::signed(3),
+                commitment
+            ));
+        }
+        next_block();
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        next_block();
+        if let Some(secret) = secret {
+            assert_ok!(EterraRandomness::reveal(RuntimeOrigin::signed(3), secret));
+        }
+        next_block();
+        EterraSimpleTCGConfig::cards(0)
+            .and_then(|c| c.slot_values)
+            .expect("card rolled")
+    })
+}
+
+#[test]
+fn minted_stats_cannot_be_computed_from_public_inputs() {
+    use sp_runtime::traits::{BlakeTwo256, Hash};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        next_block();
+
+        // Everything an attacker can read before signing: the seed and the mint's subject.
+        let card_id = EterraSimpleTCGConfig::next_card_id();
+        let subject = (b"simple-tcg/card", card_id).encode();
+        let seen = BlakeTwo256::hash_of(&(EterraRandomness::seed(), subject));
+        let predicted: [u8; 4] = core::array::from_fn(|i| seen.as_ref()[i] % 9 + 1);
+
+        assert_ok!(EterraSimpleTCGConfig::mint_card(RuntimeOrigin::signed(BOB)));
+        roll_pending_mints();
+
+        let rolled = EterraSimpleTCGConfig::cards(card_id)
+            .and_then(|c| c.slot_values)
+            .expect("card rolled");
+        assert_ne!(rolled, predicted);
+    });
+
+    // Same blocks, minter and card id every time; only a secret revealed after the mint
+    // differs.
+    let outcomes = [None, Some([1; 32]), Some([2; 32]), Some([3; 32])].map(stats_of_first_mint);
+    for (i, stats) in outcomes.iter().enumerate() {
+        assert!(outcomes[i + 1..].iter().all(|other| other != stats));
+    }
+}
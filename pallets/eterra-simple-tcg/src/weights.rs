@@ -0,0 +1,153 @@
+//! Weights for pallet_eterra_simple_tcg
+//!
+//! These values are estimates, not benchmark results. They were worked out by hand from
+//! the storage each call touches, sized for the worst cases set up in `benchmarking.rs`.
+//! Replace them with measured weights from reference hardware before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_eterra_simple_tcg.
+pub trait WeightInfo {
+	fn mint_card() -> Weight;
+	fn transfer_card() -> Weight;
+	fn set_price() -> Weight;
+	fn remove_price() -> Weight;
+	fn buy_card() -> Weight;
+	fn roll_minted_cards(n: u32) -> Weight;
+}
+
+/// Estimated weights for pallet_eterra_simple_tcg.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: EterraSimpleTCG NextCardId (r:1 w:1)
+	/// Storage: EterraSimpleTCG PendingMints (r:1 w:1)
+	/// Storage: EterraSimpleTCG Cards (r:0 w:1)
+	/// Storage: EterraSimpleTCG OwnedCards (r:1 w:1)
+	fn mint_card() -> Weight {
+		Weight::from_parts(52_000_000, 10851)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: EterraSimpleTCG LockedCards (r:1 w:0)
+	/// Storage: EterraSimpleTCG Cards (r:2 w:1)
+	/// Storage: EterraSimpleTCG CardPrices (r:1 w:1)
+	/// Storage: EterraSimpleTCG ListedByOwner (r:1 w:1)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	fn transfer_card() -> Weight {
+		Weight::from_parts(61_000_000, 12450)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: EterraSimpleTCG Cards (r:1 w:0)
+	/// Storage: EterraSimpleTCG LockedCards (r:1 w:0)
+	/// Storage: EterraSimpleTCG CardPrices (r:0 w:1)
+	/// Storage: EterraSimpleTCG ListedByOwner (r:1 w:1)
+	fn set_price() -> Weight {
+		Weight::from_parts(34_000_000, 6118)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: EterraSimpleTCG Cards (r:1 w:0)
+	/// Storage: EterraSimpleTCG CardPrices (r:1 w:1)
+	/// Storage: EterraSimpleTCG ListedByOwner (r:1 w:1)
+	fn remove_price() -> Weight {
+		Weight::from_parts(31_000_000, 6118)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: EterraSimpleTCG CardPrices (r:1 w:1)
+	/// Storage: EterraSimpleTCG Cards (r:1 w:1)
+	/// Storage: EterraSimpleTCG LockedCards (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: EterraSimpleTCG ListedByOwner (r:1 w:1)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	fn buy_card() -> Weight {
+		Weight::from_parts(83_000_000, 12450)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: EterraRandomness Seed (r:1 w:0)
+	/// Storage: EterraRandomness SeededAt (r:1 w:0)
+	/// Storage: EterraSimpleTCG PendingMints (r:1 w:1)
+	/// Storage: EterraSimpleTCG Cards (r:256 w:256)
+	fn roll_minted_cards(n: u32) -> Weight {
+		// The range of component `n` is `[0, 256]`.
+		Weight::from_parts(7_000_000, 4041)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: EterraSimpleTCG NextCardId (r:1 w:1)
+	/// Storage: EterraSimpleTCG PendingMints (r:1 w:1)
+	/// Storage: EterraSimpleTCG Cards (r:0 w:1)
+	/// Storage: EterraSimpleTCG OwnedCards (r:1 w:1)
+	fn mint_card() -> Weight {
+		Weight::from_parts(52_000_000, 10851)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: EterraSimpleTCG LockedCards (r:1 w:0)
+	/// Storage: EterraSimpleTCG Cards (r:2 w:1)
+	/// Storage: EterraSimpleTCG CardPrices (r:1 w:1)
+	/// Storage: EterraSimpleTCG ListedByOwner (r:1 w:1)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	fn transfer_card() -> Weight {
+		Weight::from_parts(61_000_000, 12450)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: EterraSimpleTCG Cards (r:1 w:0)
+	/// Storage: EterraSimpleTCG LockedCards (r:1 w:0)
+	/// Storage: EterraSimpleTCG CardPrices (r:0 w:1)
+	/// Storage: EterraSimpleTCG ListedByOwner (r:1 w:1)
+	fn set_price() -> Weight {
+		Weight::from_parts(34_000_000, 6118)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: EterraSimpleTCG Cards (r:1 w:0)
+	/// Storage: EterraSimpleTCG CardPrices (r:1 w:1)
+	/// Storage: EterraSimpleTCG ListedByOwner (r:1 w:1)
+	fn remove_price() -> Weight {
+		Weight::from_parts(31_000_000, 6118)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: EterraSimpleTCG CardPrices (r:1 w:1)
+	/// Storage: EterraSimpleTCG Cards (r:1 w:1)
+	/// Storage: EterraSimpleTCG LockedCards (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: EterraSimpleTCG ListedByOwner (r:1 w:1)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	fn buy_card() -> Weight {
+		Weight::from_parts(83_000_000, 12450)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: EterraRandomness Seed (r:1 w:0)
+	/// Storage: EterraRandomness SeededAt (r:1 w:0)
+	/// Storage: EterraSimpleTCG PendingMints (r:1 w:1)
+	/// Storage: EterraSimpleTCG Cards (r:256 w:256)
+	fn roll_minted_cards(n: u32) -> Weight {
+		// The range of component `n` is `[0, 256]`.
+		Weight::from_parts(7_000_000, 4041)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
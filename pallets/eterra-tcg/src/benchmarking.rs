@@ -0,0 +1,145 @@
+//! Benchmarking setup for pallet-eterra-tcg
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as EterraTCG;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+/// Mint `packs` packs for `who`.
+fn mint_packs<T: Config>(who: &T::AccountId, packs: u32) {
+    for _ in 0..packs {
+        Pallet::<T>::mint_pack(RawOrigin::Signed(who.clone()).into()).expect("below MaxPacks; qed");
+    }
+}
+
+/// Make every pending slot roll, as the next block would.
+fn roll_slots<T: Config>() {
+    Pallet::<T>::roll_pending_slots(frame_system::Pallet::<T>::block_number() + 1u32.into());
+}
+
+/// Fill the pending rolls up to `len` with rolls for cards that are never looked up, so
+/// every scan walks the whole list.
+fn fill_pending_slots<T: Config>(who: &T::AccountId, len: u32) {
+    let now = frame_system::Pallet::<T>::block_number();
+    let pending: BoundedVec<_, PendingSlotLimit> = (0..len)
+        .map(|i| (u32::MAX - i, who.clone(), now))
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("len is within the limit; qed");
+    PendingSlots::<T>::put(pending);
+}
+
+/// Fill `who`'s pack list, finalize every card of the last pack but the final one and make
+/// that one active. Finalizing it then has to check every card in the pack.
+fn last_card_active<T: Config>(who: &T::AccountId) -> u32 {
+    mint_packs::<T>(who, T::MaxPacks::get());
+    let card_ids = PlayerPacks::<T>::get(who)
+        .last()
+        .map(|p| p.get_card_ids().clone())
+        .expect("packs were just minted; qed");
+    let last = card_ids.len().saturating_sub(1);
+    for idx in 0..last {
+        ActiveCard::<T>::insert(who, Some(idx as u8));
+        Pallet::<T>::generate_slot(RawOrigin::Signed(who.clone()).into())
+            .expect("fresh card has attempts left; qed");
+        roll_slots::<T>();
+        Pallet::<T>::accept_slot(RawOrigin::Signed(who.clone()).into())
+            .expect("slot was just rolled; qed");
+    }
+    ActiveCard::<T>::insert(who, Some(last as u8));
+    card_ids[last]
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn mint_pack() {
+        let caller: T::AccountId = whitelisted_caller();
+        mint_packs::<T>(&caller, T::MaxPacks::get().saturating_sub(1));
+
+        #[extrinsic_call]
+        mint_pack(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(
+            PlayerPacks::<T>::get(&caller).len() as u32,
+            T::MaxPacks::get()
+        );
+    }
+
+    #[benchmark]
+    fn generate_slot() {
+        let caller: T::AccountId = whitelisted_caller();
+        let card_id = last_card_active::<T>(&caller);
+        let limit = <PendingSlotLimit as Get<u32>>::get();
+        fill_pending_slots::<T>(&caller, limit - 1);
+
+        #[extrinsic_call]
+        generate_slot(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(PendingSlots::<T>::get().len() as u32, limit);
+        assert_eq!(CardAttempts::<T>::get(card_id), 1);
+    }
+
+    #[benchmark]
+    fn accept_slot() {
+        let caller: T::AccountId = whitelisted_caller();
+        last_card_active::<T>(&caller);
+        Pallet::<T>::generate_slot(RawOrigin::Signed(caller.clone()).into())
+            .expect("fresh card has attempts left; qed");
+        roll_slots::<T>();
+        fill_pending_slots::<T>(&caller, <PendingSlotLimit as Get<u32>>::get());
+
+        #[extrinsic_call]
+        accept_slot(RawOrigin::Signed(caller.clone()));
+
+        let pack = PlayerPacks::<T>::get(&caller);
+        assert!(pack.last().map(|p| p.get_completed()).unwrap_or(false));
+    }
+
+    #[benchmark]
+    fn transfer_card() {
+        let caller: T::AccountId = whitelisted_caller();
+        let card_id = last_card_active::<T>(&caller);
+        Pallet::<T>::generate_slot(RawOrigin::Signed(caller.clone()).into())
+            .expect("fresh card has attempts left; qed");
+        roll_slots::<T>();
+        Pallet::<T>::accept_slot(RawOrigin::Signed(caller.clone()).into())
+            .expect("slot was just rolled; qed");
+        let to: T::AccountId = account("to", 0, 0);
+
+        #[extrinsic_call]
+        transfer_card(RawOrigin::Signed(caller), card_id, to.clone());
+
+        assert_eq!(
+            Cards::<T>::get(card_id).map(|c| c.get_owner().clone()),
+            Some(to)
+        );
+    }
+
+    /// `n` rolls due, each on the last attempt of the last card in a full pack list, so every
+    /// roll finalizes its card and checks the whole pack.
+    #[benchmark]
+    fn roll_pending_slots(n: Linear<0, { <PendingSlotLimit as Get<u32>>::get() }>) {
+        for i in 0..n {
+            let who: T::AccountId = account("player", i, 0);
+            let card_id = last_card_active::<T>(&who);
+            CardAttempts::<T>::insert(card_id, T::MaxAttempts::get().saturating_sub(1));
+            Pallet::<T>::generate_slot(RawOrigin::Signed(who).into())
+                .expect("one attempt is left; qed");
+        }
+        let drawn = frame_system::Pallet::<T>::block_number() + 1u32.into();
+
+        #[block]
+        {
+            Pallet::<T>::roll_pending_slots(drawn);
+        }
+
+        assert!(PendingSlots::<T>::get().is_empty());
+    }
+
+    impl_benchmark_test_suite!(EterraTCG, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -9,11 +9,23 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-use frame_support::{pallet_prelude::*, traits::Get, BoundedVec};
-use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Get, Randomness},
+    BoundedVec,
+};
+use frame_system::{
+    ensure_signed,
+    pallet_prelude::{BlockNumberFor, OriginFor},
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{Hash, SaturatedConversion};
+use sp_runtime::traits::SaturatedConversion;
 use sp_std::prelude::*;
 
 #[frame_support::pallet]
@@ -23,6 +35,9 @@ pub mod pallet {
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
+    /// Max number of slot rolls waiting for randomness at once.
+    pub type PendingSlotLimit = ConstU32<256>;
+
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
@@ -36,9 +51,9 @@ pub mod pallet {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        /// A numeric seed for our randomness.
-        #[pallet::constant]
-        type RandomnessSeed: Get<u64>;
+        /// Source of the randomness slot values are rolled from. Each roll uses the first block
+        /// whose randomness is later than the `generate_slot` call.
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
         /// The maximum times a card can generate slots before it is forced to finalize.
         #[pallet::constant]
@@ -51,6 +66,9 @@ pub mod pallet {
         /// The maximum number of packs a single account can hold.
         #[pallet::constant]
         type MaxPacks: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     // ------------------
@@ -132,6 +150,15 @@ pub mod pallet {
     #[pallet::getter(fn card_attempts)]
     pub type CardAttempts<T: Config> = StorageMap<_, Blake2_128Concat, u32, u8, ValueQuery>;
 
+    /// Slot rolls still to be made: the card, the player who asked and the block they asked in.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_slots)]
+    pub type PendingSlots<T: Config> = StorageValue<
+        _,
+        BoundedVec<(u32, T::AccountId, BlockNumberFor<T>), PendingSlotLimit>,
+        ValueQuery,
+    >;
+
     // ------------------
     // Events
     // ------------------
@@ -141,6 +168,8 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// A new pack was minted for `player` with ID `pack_id`, containing multiple new cards.
         PackMinted { player: T::AccountId, pack_id: u32 },
+        /// A slot roll was requested for a card; its values follow in a later block.
+        SlotRequested { card_id: u32 },
         /// A card’s slot was generated.
         SlotGenerated { card_id: u32, values: [u8; 4] },
         /// A card’s slot was accepted (finalized).
@@ -177,6 +206,22 @@ pub mod pallet {
         NoSuchCard,
         /// You do not own the card you’re trying to act upon.
         NotCardOwner,
+        /// The card's last slot roll hasn't landed yet.
+        SlotPending,
+        /// Too many slot rolls are waiting for randomness; try again next block.
+        TooManyPendingSlots,
+    }
+
+    // ------------------
+    // Hooks
+    // ------------------
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let (_, drawn) = T::Randomness::random(b"tcg/slot");
+            T::WeightInfo::roll_pending_slots(Self::roll_pending_slots(drawn))
+        }
     }
 
     // ------------------
@@ -188,7 +233,7 @@ pub mod pallet {
         /// Mint a new pack of cards for the caller, up to `MaxPacks`.
         /// Each card is stored globally in `Cards<T>`.
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::mint_pack())]
         pub fn mint_pack(origin: OriginFor<T>) -> DispatchResult {
             let player = ensure_signed(origin)?;
 
@@ -229,9 +274,11 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Generate new slot values for the user’s current (active) card, up to `MaxAttempts`.
+        /// Ask for new slot values for the user’s current (active) card, up to `MaxAttempts`.
+        /// The values are rolled in a later block, so they can't be known when choosing
+        /// between this and `accept_slot`.
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::generate_slot())]
         pub fn generate_slot(origin: OriginFor<T>) -> DispatchResult {
             let player = ensure_signed(origin)?;
 
@@ -248,38 +295,27 @@ pub mod pallet {
                     .ok_or(Error::<T>::NoActiveCard)?;
 
                 // 3) Check ownership
-                let mut card_info = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+                let card_info = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
                 ensure!(card_info.owner == player, Error::<T>::NotCardOwner);
 
-                // 4) Check attempts
-                let mut attempts = CardAttempts::<T>::get(card_id);
+                // 4) Check attempts and that the previous roll has landed
+                let attempts = CardAttempts::<T>::get(card_id);
                 ensure!(
                     attempts < T::MaxAttempts::get(),
                     Error::<T>::MaxAttemptsExceeded
                 );
+                ensure!(!Self::slot_pending(card_id), Error::<T>::SlotPending);
 
-                // 5) Generate slot values
-                let current_block = <frame_system::Pallet<T>>::block_number();
-                let seed = T::RandomnessSeed::get();
-                let hash = T::Hashing::hash_of(&(current_block, &player, seed));
-                let values = hash.as_ref()[..4].try_into().unwrap_or([0u8; 4]);
-
-                // 6) Update card’s slot values
-                card_info.slot_values = Some(values);
+                // 5) Queue the roll; `roll_pending_slots` makes it once the block's randomness
+                //    is later than this one
+                let now = <frame_system::Pallet<T>>::block_number();
+                PendingSlots::<T>::try_append((card_id, player.clone(), now))
+                    .map_err(|_| Error::<T>::TooManyPendingSlots)?;
 
-                // 7) Store back
-                Cards::<T>::insert(card_id, card_info);
+                // 6) Increment attempts
+                CardAttempts::<T>::insert(card_id, attempts + 1);
 
-                // 8) Increment attempts
-                attempts += 1;
-                CardAttempts::<T>::insert(card_id, attempts);
-
-                // 9) If attempts == max, finalize now
-                if attempts == T::MaxAttempts::get() {
-                    Self::internal_finalize_card(card_id, pack)?;
-                }
-
-                Self::deposit_event(Event::SlotGenerated { card_id, values });
+                Self::deposit_event(Event::SlotRequested { card_id });
                 Ok(())
             })?;
 
@@ -288,7 +324,7 @@ pub mod pallet {
 
         /// Accept (finalize) the user’s current card’s slot values immediately.
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::accept_slot())]
         pub fn accept_slot(origin: OriginFor<T>) -> DispatchResult {
             let player = ensure_signed(origin)?;
 
@@ -302,10 +338,11 @@ pub mod pallet {
                     .ok_or(Error::<T>::NoActiveCard)?;
 
                 // Must have a card
-                let mut card_info = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
+                let card_info = Cards::<T>::get(card_id).ok_or(Error::<T>::NoSuchCard)?;
                 ensure!(card_info.owner == player, Error::<T>::NotCardOwner);
 
-                // Must have generated at least once
+                // Must have generated at least once, with no roll still on its way
+                ensure!(!Self::slot_pending(card_id), Error::<T>::SlotPending);
                 ensure!(card_info.slot_values.is_some(), Error::<T>::NoActiveCard);
 
                 // Finalize
@@ -322,7 +359,7 @@ pub mod pallet {
         /// If that card is also part of a pack, it still references it, but ownership
         /// changes to `to`.
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::transfer_card())]
         pub fn transfer_card(
             origin: OriginFor<T>,
             card_id: u32,
@@ -368,6 +405,54 @@ pub mod pallet {
             Ok(card_id)
         }
 
+        /// Whether `card_id` has a slot roll waiting in `PendingSlots`.
+        fn slot_pending(card_id: u32) -> bool {
+            PendingSlots::<T>::get()
+                .iter()
+                .any(|(id, _, _)| *id == card_id)
+        }
+
+        /// Make every pending slot roll requested before `drawn`, the block the randomness
+        /// source was last refreshed in. Returns how many rolls were made.
+        pub fn roll_pending_slots(drawn: BlockNumberFor<T>) -> u32 {
+            let mut rolled = 0;
+            PendingSlots::<T>::mutate(|pending| {
+                pending.retain(|(card_id, player, requested_at)| {
+                    if *requested_at >= drawn {
+                        return true;
+                    }
+                    Self::roll_slot(*card_id, player);
+                    rolled += 1;
+                    false
+                });
+            });
+            rolled
+        }
+
+        /// Roll new slot values for `card_id`, fresh for every attempt on it. The last attempt
+        /// finalizes the card.
+        fn roll_slot(card_id: u32, player: &T::AccountId) {
+            let attempts = CardAttempts::<T>::get(card_id);
+            let (hash, _) =
+                T::Randomness::random(&(b"tcg/slot", player, card_id, attempts).encode());
+            let values: [u8; 4] = hash.as_ref()[..4].try_into().unwrap_or([0u8; 4]);
+
+            Cards::<T>::mutate(card_id, |maybe_card| {
+                if let Some(card_info) = maybe_card {
+                    card_info.slot_values = Some(values);
+                }
+            });
+            Self::deposit_event(Event::SlotGenerated { card_id, values });
+
+            if attempts >= T::MaxAttempts::get() {
+                PlayerPacks::<T>::mutate(player, |packs| {
+                    if let Some(pack) = packs.iter_mut().find(|p| p.card_ids.contains(&card_id)) {
+                        let _ = Self::internal_finalize_card(card_id, pack);
+                    }
+                });
+            }
+        }
+
         /// Finalize a card, remove attempts, possibly mark the pack completed, etc.
         fn internal_finalize_card(card_id: u32, pack: &mut Pack) -> DispatchResult {
             // 1) Mark card as finalized, remove attempts
@@ -18,6 +18,7 @@ frame_support::construct_runtime!(
     pub struct Test {
         System: frame_system,
         EterraSlots: pallet_eterra_slots,
+        EterraRandomness: pallet_eterra_randomness,
     }
 );
 
@@ -26,7 +27,6 @@ parameter_types! {
     pub const MaxAttempts: u8 = 3;
     pub const CardsPerPack: u8 = 5;
     pub const MaxPacks: u32 = 10;
-    pub const RandomnessSeed: u64 = 42;
 }
 
 impl system::Config for Test {
@@ -64,10 +64,17 @@ impl system::Config for Test {
 
 impl pallet_eterra_slots::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type RandomnessSeed = RandomnessSeed;
+    type Randomness = EterraRandomness;
     type MaxAttempts = ConstU8<3>;
     type CardsPerPack = ConstU8<5>;
     type MaxPacks = ConstU32<10>;
+    type WeightInfo = ();
+}
+
+impl pallet_eterra_randomness::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RevealWindow = ConstU64<10>;
+    type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -1,6 +1,6 @@
 use crate::pallet::Config as EterraSlotsConfig;
-use crate::{mock::*, ActiveCard, Error, Event, PlayerPacks};
-use frame_support::traits::Get;
+use crate::{mock::*, ActiveCard, Error, Event, PendingSlots, PlayerPacks};
+use frame_support::traits::{Get, OnInitialize};
 use frame_support::{assert_noop, assert_ok};
 use log::{debug, Level, Metadata, Record};
 use sp_runtime::traits::SaturatedConversion;
@@ -67,6 +67,11 @@ fn run_to_block(n: u64) {
     }
 }
 
+/// Make every pending slot roll as the next block would, without moving the block number.
+fn roll_slots() {
+    EterraSlots::roll_pending_slots(System::block_number() + 1);
+}
+
 #[test]
 fn test_mint_pack_simple_storage_check() {
     new_test_ext().execute_with(|| {
@@ -198,6 +203,7 @@ fn test_generate_slot_success() {
         debug!("Generate slot for the active card");
         System::reset_events();
         assert_ok!(EterraSlots::generate_slot(RuntimeOrigin::signed(player)));
+        roll_slots();
 
         run_to_block(frame_system::Pallet::<Test>::block_number() + 1);
 
@@ -225,6 +231,7 @@ fn test_accept_slot_success() {
 
         // Generate a slot
         assert_ok!(EterraSlots::generate_slot(RuntimeOrigin::signed(player)));
+        roll_slots();
         run_to_block(System::block_number() + 1);
 
         debug!("Accepting slot...");
@@ -324,6 +331,7 @@ fn test_attempts_removed_after_generating_max_times() {
         // Generate slots until we hit max
         for _ in 0..max_attempts {
             assert_ok!(EterraSlots::generate_slot(RuntimeOrigin::signed(player)));
+            roll_slots();
         }
 
         // After final generation, that card should be finalized => attempts removed
@@ -354,6 +362,7 @@ fn test_attempts_removed_after_accept_slot() {
         // Should now have attempts = 1
         let attempts_before = EterraSlots::card_attempts(card_id);
         assert_eq!(attempts_before, 1);
+        roll_slots();
 
         // Accept slot => finalize the card => attempts removed
         assert_ok!(EterraSlots::accept_slot(RuntimeOrigin::signed(player)));
@@ -441,6 +450,7 @@ fn test_transfer_card_success() {
         assert_ok!(EterraSlots::generate_slot(RuntimeOrigin::signed(
             original_owner
         )));
+        roll_slots();
         assert_ok!(EterraSlots::accept_slot(RuntimeOrigin::signed(
             original_owner
         )));
@@ -484,3 +494,41 @@ fn test_transfer_card_success() {
         }
     });
 }
+
+#[test]
+fn slot_rolls_land_in_a_later_block() {
+    new_test_ext().execute_with(|| {
+        let player = 1;
+        System::set_block_number(1);
+        assert_ok!(EterraSlots::mint_pack(RuntimeOrigin::signed(player)));
+        let card_id = EterraSlots::player_packs(player)[0].get_card_ids()[0];
+
+        // The roll is only queued: nothing to accept and no second roll until it lands.
+        assert_ok!(EterraSlots::generate_slot(RuntimeOrigin::signed(player)));
+        System::assert_has_event(RuntimeEvent::EterraSlots(Event::SlotRequested { card_id }));
+        assert_eq!(
+            PendingSlots::<Test>::get().to_vec(),
+            vec![(card_id, player, 1)]
+        );
+        assert_noop!(
+            EterraSlots::accept_slot(RuntimeOrigin::signed(player)),
+            Error::<Test>::SlotPending
+        );
+        assert_noop!(
+            EterraSlots::generate_slot(RuntimeOrigin::signed(player)),
+            Error::<Test>::SlotPending
+        );
+
+        // The randomness pallet runs after this one, so the roll lands two blocks later.
+        for n in 2..=3 {
+            System::set_block_number(n);
+            AllPalletsWithSystem::on_initialize(n);
+        }
+        assert!(PendingSlots::<Test>::get().is_empty());
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::EterraSlots(Event::SlotGenerated { card_id: id, .. }) if id == card_id
+        )));
+        assert_ok!(EterraSlots::accept_slot(RuntimeOrigin::signed(player)));
+    });
+}
@@ -0,0 +1,130 @@
+//! Weights for pallet_eterra_tcg
+//!
+//! These values are estimates, not benchmark results. They were worked out by hand from
+//! the storage each call touches, sized for the worst cases set up in `benchmarking.rs`.
+//! Replace them with measured weights from reference hardware before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_eterra_tcg.
+pub trait WeightInfo {
+	fn mint_pack() -> Weight;
+	fn generate_slot() -> Weight;
+	fn accept_slot() -> Weight;
+	fn transfer_card() -> Weight;
+	fn roll_pending_slots(n: u32) -> Weight;
+}
+
+/// Estimated weights for pallet_eterra_tcg.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: EterraTCG PlayerPacks (r:1 w:1)
+	/// Storage: EterraTCG NextCardId (r:1 w:1)
+	/// Storage: EterraTCG Cards (r:0 w:16)
+	/// Storage: EterraTCG ActiveCard (r:0 w:1)
+	fn mint_pack() -> Weight {
+		Weight::from_parts(48_000_000, 21340)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	/// Storage: EterraTCG PlayerPacks (r:1 w:1)
+	/// Storage: EterraTCG ActiveCard (r:1 w:0)
+	/// Storage: EterraTCG Cards (r:1 w:0)
+	/// Storage: EterraTCG CardAttempts (r:1 w:1)
+	/// Storage: EterraTCG PendingSlots (r:1 w:1)
+	fn generate_slot() -> Weight {
+		Weight::from_parts(38_000_000, 31601)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: EterraTCG PlayerPacks (r:1 w:1)
+	/// Storage: EterraTCG ActiveCard (r:1 w:0)
+	/// Storage: EterraTCG Cards (r:18 w:1)
+	/// Storage: EterraTCG PendingSlots (r:1 w:0)
+	/// Storage: EterraTCG CardAttempts (r:0 w:1)
+	fn accept_slot() -> Weight {
+		Weight::from_parts(49_000_000, 31601)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: EterraTCG Cards (r:1 w:1)
+	fn transfer_card() -> Weight {
+		Weight::from_parts(18_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraRandomness Seed (r:1 w:0)
+	/// Storage: EterraRandomness SeededAt (r:1 w:0)
+	/// Storage: EterraTCG PendingSlots (r:1 w:1)
+	/// Storage: EterraTCG CardAttempts (r:256 w:256)
+	/// Storage: EterraTCG Cards (r:4608 w:512)
+	/// Storage: EterraTCG PlayerPacks (r:256 w:256)
+	fn roll_pending_slots(n: u32) -> Weight {
+		// The range of component `n` is `[0, 256]`.
+		Weight::from_parts(8_000_000, 11785)
+			.saturating_add(Weight::from_parts(58_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((19_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: EterraTCG PlayerPacks (r:1 w:1)
+	/// Storage: EterraTCG NextCardId (r:1 w:1)
+	/// Storage: EterraTCG Cards (r:0 w:16)
+	/// Storage: EterraTCG ActiveCard (r:0 w:1)
+	fn mint_pack() -> Weight {
+		Weight::from_parts(48_000_000, 21340)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	/// Storage: EterraTCG PlayerPacks (r:1 w:1)
+	/// Storage: EterraTCG ActiveCard (r:1 w:0)
+	/// Storage: EterraTCG Cards (r:1 w:0)
+	/// Storage: EterraTCG CardAttempts (r:1 w:1)
+	/// Storage: EterraTCG PendingSlots (r:1 w:1)
+	fn generate_slot() -> Weight {
+		Weight::from_parts(38_000_000, 31601)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: EterraTCG PlayerPacks (r:1 w:1)
+	/// Storage: EterraTCG ActiveCard (r:1 w:0)
+	/// Storage: EterraTCG Cards (r:18 w:1)
+	/// Storage: EterraTCG PendingSlots (r:1 w:0)
+	/// Storage: EterraTCG CardAttempts (r:0 w:1)
+	fn accept_slot() -> Weight {
+		Weight::from_parts(49_000_000, 31601)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: EterraTCG Cards (r:1 w:1)
+	fn transfer_card() -> Weight {
+		Weight::from_parts(18_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraRandomness Seed (r:1 w:0)
+	/// Storage: EterraRandomness SeededAt (r:1 w:0)
+	/// Storage: EterraTCG PendingSlots (r:1 w:1)
+	/// Storage: EterraTCG CardAttempts (r:256 w:256)
+	/// Storage: EterraTCG Cards (r:4608 w:512)
+	/// Storage: EterraTCG PlayerPacks (r:256 w:256)
+	fn roll_pending_slots(n: u32) -> Weight {
+		// The range of component `n` is `[0, 256]`.
+		Weight::from_parts(8_000_000, 11785)
+			.saturating_add(Weight::from_parts(58_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((19_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+}
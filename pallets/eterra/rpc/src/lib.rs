@@ -0,0 +1,193 @@
+//! RPC interface for reading Eterra game state.
+//!
+//! Thin wrapper over the `EterraGameApi` runtime API. Every method takes an
+//! optional block hash and defaults to the best block.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use parity_scale_codec::Codec;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use pallet_eterra_runtime_api::replay::{self, ReplayReport};
+pub use pallet_eterra_runtime_api::EterraGameApi as EterraGameRuntimeApi;
+use pallet_eterra_runtime_api::{GameView, HandView, LegalMove, MovePreview};
+
+#[rpc(client, server)]
+pub trait EterraApi<BlockHash, AccountId, GameId, BlockNumber> {
+    #[method(name = "eterra_game")]
+    fn game(
+        &self,
+        game_id: GameId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<GameView<AccountId, BlockNumber>>>;
+
+    #[method(name = "eterra_activeGame")]
+    fn active_game(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<GameId>>;
+
+    #[method(name = "eterra_recentGames")]
+    fn recent_games(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<GameId>>;
+
+    #[method(name = "eterra_hands")]
+    fn hands(&self, game_id: GameId, at: Option<BlockHash>) -> RpcResult<Vec<HandView<AccountId>>>;
+
+    #[method(name = "eterra_legalMoves")]
+    fn legal_moves(&self, game_id: GameId, at: Option<BlockHash>) -> RpcResult<Vec<LegalMove>>;
+
+    #[method(name = "eterra_previewMove")]
+    fn preview_move(
+        &self,
+        game_id: GameId,
+        hand_index: u8,
+        x: u8,
+        y: u8,
+        at: Option<BlockHash>,
+    ) -> RpcResult<MovePreview>;
+
+    /// Replay the game's move log and check it reproduces the stored board and scores.
+    #[method(name = "eterra_replayGame")]
+    fn replay_game(&self, game_id: GameId, at: Option<BlockHash>) -> RpcResult<ReplayReport>;
+}
+
+/// Provides RPC methods to query Eterra games.
+pub struct Eterra<C, P> {
+    client: Arc<C>,
+    _marker: PhantomData<P>,
+}
+
+impl<C, P> Eterra<C, P> {
+    /// Creates a new instance of the Eterra RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+    /// The runtime rejected the previewed move.
+    InvalidMove,
+    /// The game is unknown or its move log does not reproduce the stored state.
+    ReplayFailed,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::InvalidMove => 2,
+            Error::ReplayFailed => 3,
+        }
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Display) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query Eterra game state.",
+        Some(e.to_string()),
+    )
+}
+
+impl<C, Block, AccountId, GameId, BlockNumber>
+    EterraApiServer<<Block as BlockT>::Hash, AccountId, GameId, BlockNumber> for Eterra<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EterraGameRuntimeApi<Block, AccountId, GameId, BlockNumber>,
+    AccountId: Codec + PartialEq + Serialize + DeserializeOwned + Send + Sync + 'static,
+    GameId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn game(
+        &self,
+        game_id: GameId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<GameView<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.game(at_hash, game_id).map_err(runtime_error)
+    }
+
+    fn active_game(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<Option<GameId>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.active_game(at_hash, who).map_err(runtime_error)
+    }
+
+    fn recent_games(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<GameId>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.recent_games(at_hash, who).map_err(runtime_error)
+    }
+
+    fn hands(
+        &self,
+        game_id: GameId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<HandView<AccountId>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.hands(at_hash, game_id).map_err(runtime_error)
+    }
+
+    fn legal_moves(&self, game_id: GameId, at: Option<Block::Hash>) -> RpcResult<Vec<LegalMove>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.legal_moves(at_hash, game_id).map_err(runtime_error)
+    }
+
+    fn preview_move(
+        &self,
+        game_id: GameId,
+        hand_index: u8,
+        x: u8,
+        y: u8,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<MovePreview> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.preview_move(at_hash, game_id, hand_index, x, y)
+            .map_err(runtime_error)?
+            .map_err(|e| {
+                ErrorObject::owned(
+                    Error::InvalidMove.into(),
+                    "Move would be rejected.",
+                    Some(format!("{:?}", e)),
+                )
+            })
+    }
+
+    fn replay_game(&self, game_id: GameId, at: Option<Block::Hash>) -> RpcResult<ReplayReport> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let record = api
+            .game_record(at_hash, game_id)
+            .map_err(runtime_error)?
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    Error::ReplayFailed.into(),
+                    "Game not found or already archived.",
+                    None::<()>,
+                )
+            })?;
+        replay::replay(&record).map_err(|e| {
+            ErrorObject::owned(
+                Error::ReplayFailed.into(),
+                "Move log does not reproduce the stored game.",
+                Some(e.to_string()),
+            )
+        })
+    }
+}
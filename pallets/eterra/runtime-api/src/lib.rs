@@ -0,0 +1,39 @@
+//! Runtime API for reading Eterra game state.
+//!
+//! Lets clients fetch decoded games, hands, legal moves, move previews and move logs without
+//! knowing the pallet's storage layout. Served over RPC by `pallet-eterra-rpc`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+pub use pallet_eterra::replay;
+pub use pallet_eterra::{GameRecord, GameView, HandView, LegalMove, MovePreview, MoveRecord};
+
+sp_api::decl_runtime_apis! {
+    pub trait EterraGameApi<AccountId, GameId, BlockNumber>
+    where
+        AccountId: Codec,
+        GameId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Decoded game by id.
+        fn game(game_id: GameId) -> Option<GameView<AccountId, BlockNumber>>;
+        /// The game `who` is currently playing, if any.
+        fn active_game(who: AccountId) -> Option<GameId>;
+        /// Recent games of `who`, most recent first.
+        fn recent_games(who: AccountId) -> Vec<GameId>;
+        /// Submitted hands of both players.
+        fn hands(game_id: GameId) -> Vec<HandView<AccountId>>;
+        /// Placements available to the player to move.
+        fn legal_moves(game_id: GameId) -> Vec<LegalMove>;
+        /// Board, score delta and flipped cells if the player to move played
+        /// `hand_index` at `(x, y)`. Read-only.
+        fn preview_move(game_id: GameId, hand_index: u8, x: u8, y: u8) -> Result<MovePreview, DispatchError>;
+        /// Game, rules, hands and move log, for replaying off-chain.
+        fn game_record(game_id: GameId) -> Option<GameRecord<AccountId, BlockNumber>>;
+    }
+}
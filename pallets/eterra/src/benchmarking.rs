@@ -0,0 +1,533 @@
+//! Benchmarking setup for pallet-eterra
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Eterra;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{
+    fungible::{Inspect, Mutate},
+    Currency, Hooks,
+};
+use frame_system::RawOrigin;
+use pallet_eterra_simple_tcg as cards;
+
+/// Every capture rule on, so each placement does the most work.
+fn all_rules() -> RuleSet {
+    RuleSet {
+        same: true,
+        plus: true,
+        combo: true,
+        same_wall: true,
+        elemental: true,
+    }
+}
+
+fn largest_board() -> BoardSize {
+    let dim = eterra_rules::MAX_BOARD_DIM as u8;
+    BoardSize::new(dim, dim)
+}
+
+/// A stake comfortably above the existential deposit.
+fn stake<T: Config>() -> BalanceOf<T> {
+    T::StakeCurrency::minimum_balance()
+        .max(1u32.into())
+        .saturating_mul(100u32.into())
+}
+
+/// A funded account holding `HandSize` freshly minted cards as its current hand.
+fn player<T: Config>(name: &'static str, index: u32) -> AccountIdOf<T> {
+    let who: AccountIdOf<T> = account(name, index, 0);
+    let fee = <T as cards::Config>::MintFee::get();
+    <T as cards::Config>::Currency::make_free_balance_be(
+        &who,
+        fee.saturating_mul(1_000u32.into())
+            .saturating_add(<T as cards::Config>::Currency::minimum_balance()),
+    );
+    T::StakeCurrency::mint_into(&who, stake::<T>().saturating_mul(2u32.into()))
+        .expect("minting a small balance succeeds; qed");
+
+    let first = cards::NextCardId::<T>::get();
+    for _ in 0..T::HandSize::get() {
+        cards::Pallet::<T>::mint_card(RawOrigin::Signed(who.clone()).into())
+            .expect("account can pay the mint fee; qed");
+    }
+    cards::Pallet::<T>::roll_minted_cards(frame_system::Pallet::<T>::block_number() + 1u32.into());
+    let ids: Vec<u32> = (first..first + T::HandSize::get()).collect();
+    Pallet::<T>::set_current_hand(RawOrigin::Signed(who.clone()).into(), ids)
+        .expect("cards were just minted to this account; qed");
+    who
+}
+
+/// Deal every pending game as the next block would, without moving the block number.
+fn deal_pending<T: Config>() {
+    Pallet::<T>::deal_games(frame_system::Pallet::<T>::block_number() + 1u32.into());
+}
+
+/// PvE game on the largest board with every rule against the costliest AI profile, the
+/// human's hand already submitted and the AI's dealt.
+fn pve_game<T: Config>(human: &AccountIdOf<T>) -> GameId<T> {
+    pve_game_against::<T>(human, AiProfile::Solver)
+}
+
+/// Like [`pve_game`], against `profile`.
+fn pve_game_against<T: Config>(human: &AccountIdOf<T>, profile: AiProfile) -> GameId<T> {
+    let game_id = pve_game_without_hand::<T>(human, profile);
+    Pallet::<T>::submit_hand(RawOrigin::Signed(human.clone()).into(), game_id, Vec::new())
+        .expect("hand not yet submitted; qed");
+    deal_pending::<T>();
+    game_id
+}
+
+/// Dealt PvE game on the largest board with every rule against `profile`, before the human
+/// submits a hand.
+fn pve_game_without_hand<T: Config>(human: &AccountIdOf<T>, profile: AiProfile) -> GameId<T> {
+    Pallet::<T>::create_game(
+        RawOrigin::Signed(human.clone()).into(),
+        Vec::new(),
+        GameMode::PvE,
+        all_rules(),
+        largest_board(),
+        Zero::zero(),
+        TradeRule::None,
+        Some(profile),
+        HandVisibility::Open,
+    )
+    .expect("human has a current hand and no active game; qed");
+    deal_pending::<T>();
+    ActiveGameOf::<T>::get(human).expect("game was just created; qed")
+}
+
+/// Wagered PvP game staking every card (`TradeRule::All`), still waiting for the opponent.
+fn pending_wager<T: Config>(a: &AccountIdOf<T>, b: &AccountIdOf<T>) -> GameId<T> {
+    Pallet::<T>::create_game(
+        RawOrigin::Signed(a.clone()).into(),
+        sp_std::vec![a.clone(), b.clone()],
+        GameMode::PvP,
+        all_rules(),
+        largest_board(),
+        stake::<T>(),
+        TradeRule::All,
+        None,
+        HandVisibility::Open,
+    )
+    .expect("both players are free and funded; qed");
+    ActiveGameOf::<T>::get(a).expect("game was just created; qed")
+}
+
+/// Block at which turns started in the current block time out.
+fn turn_deadline<T: Config>() -> BlockNumberFor<T> {
+    let limit: BlockNumberFor<T> = T::BlocksToPlayLimit::get().into();
+    frame_system::Pallet::<T>::block_number().saturating_add(limit)
+}
+
+fn submit_both<T: Config>(game_id: GameId<T>, a: &AccountIdOf<T>, b: &AccountIdOf<T>) {
+    for who in [a, b] {
+        Pallet::<T>::submit_hand(RawOrigin::Signed(who.clone()).into(), game_id, Vec::new())
+            .expect("hand not yet submitted; qed");
+    }
+}
+
+/// Accepted wager with both hands locked in, so ending it settles stakes and moves cards.
+fn wagered_game<T: Config>(a: &AccountIdOf<T>, b: &AccountIdOf<T>) -> GameId<T> {
+    let game_id = pending_wager::<T>(a, b);
+    Pallet::<T>::accept_wager(RawOrigin::Signed(b.clone()).into(), game_id)
+        .expect("opponent can match the stake; qed");
+    deal_pending::<T>();
+    submit_both::<T>(game_id, a, b);
+    game_id
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_game() {
+        let a = player::<T>("creator", 0);
+        let b = player::<T>("opponent", 0);
+
+        #[extrinsic_call]
+        create_game(
+            RawOrigin::Signed(a.clone()),
+            sp_std::vec![a.clone(), b.clone()],
+            GameMode::PvP,
+            all_rules(),
+            largest_board(),
+            stake::<T>(),
+            TradeRule::All,
+            None,
+            HandVisibility::Open,
+        );
+
+        assert!(ActiveGameOf::<T>::get(&a).is_some());
+    }
+
+    /// The human's move hands the turn to the AI, which searches the rest of the opening
+    /// unless its moves are left to the offchain worker. `play` needs a game without hands.
+    #[benchmark]
+    fn play() {
+        let human = player::<T>("human", 0);
+        let game_id = pve_game_without_hand::<T>(&human, AiProfile::Solver);
+        let mv = Move {
+            place_card: Card::new(9, 9, 9, 9),
+            place_index_x: 0,
+            place_index_y: 0,
+        };
+
+        #[extrinsic_call]
+        play(RawOrigin::Signed(human), game_id, mv);
+
+        assert!(!MoveLog::<T>::get(&game_id).is_empty());
+    }
+
+    /// The game is already under way, so the AI's hand has to be dealt again.
+    #[benchmark]
+    fn submit_hand() {
+        let human = player::<T>("human", 0);
+        let game_id =
+            pve_game_without_hand::<T>(&human, AiProfile::MonteCarlo(T::AiDifficulty::get()));
+
+        #[extrinsic_call]
+        submit_hand(RawOrigin::Signed(human.clone()), game_id, Vec::new());
+
+        assert!(HandsOfGame::<T>::contains_key(&game_id, &human));
+        assert_eq!(PendingDeals::<T>::get().len(), 1);
+    }
+
+    #[benchmark]
+    fn play_from_hand() {
+        let human = player::<T>("human", 0);
+        let game_id = pve_game::<T>(&human);
+
+        #[extrinsic_call]
+        play_from_hand(RawOrigin::Signed(human), game_id, 0, 0, 0, None);
+
+        assert!(!MoveLog::<T>::get(&game_id).is_empty());
+    }
+
+    #[benchmark]
+    fn commit_hand() {
+        let a = player::<T>("creator", 0);
+        let b = player::<T>("opponent", 0);
+        Pallet::<T>::create_game(
+            RawOrigin::Signed(a.clone()).into(),
+            sp_std::vec![a.clone(), b.clone()],
+            GameMode::PvP,
+            all_rules(),
+            largest_board(),
+            Zero::zero(),
+            TradeRule::None,
+            None,
+            HandVisibility::Hidden,
+        )
+        .expect("both players are free; qed");
+        let game_id = ActiveGameOf::<T>::get(&a).expect("game was just created; qed");
+        let commitments: Vec<T::Hash> = (0..T::HandSize::get())
+            .map(|card_id| Pallet::<T>::hand_commitment(&game_id, &a, card_id, &[0; 32]))
+            .collect();
+
+        #[extrinsic_call]
+        commit_hand(RawOrigin::Signed(a.clone()), game_id, commitments);
+
+        assert!(HandCommitments::<T>::contains_key(&game_id, &a));
+    }
+
+    /// Forcing the last turn of a wagered ante game ends it, paying out stakes and cards.
+    #[benchmark]
+    fn force_finish_turn() {
+        let a = player::<T>("creator", 0);
+        let b = player::<T>("opponent", 0);
+        let game_id = wagered_game::<T>(&a, &b);
+        GameStorage::<T>::mutate(&game_id, |game| {
+            let game = game.as_mut().expect("game exists; qed");
+            game.player_turn = 1;
+            game.round = game.max_rounds.saturating_sub(1);
+            game.scores = (6, 4);
+            game.last_played_block = Zero::zero();
+        });
+        frame_system::Pallet::<T>::set_block_number(T::BlocksToPlayLimit::get().into());
+
+        #[extrinsic_call]
+        force_finish_turn(RawOrigin::Signed(a.clone()), game_id);
+
+        assert!(ActiveGameOf::<T>::get(&a).is_none());
+    }
+
+    #[benchmark]
+    fn set_current_hand() {
+        let who = player::<T>("player", 0);
+        let ids = CurrentHandOf::<T>::get(&who)
+            .expect("player has a current hand; qed")
+            .into_inner();
+
+        #[extrinsic_call]
+        set_current_hand(RawOrigin::Signed(who.clone()), ids);
+
+        assert!(CurrentHandOf::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn set_preset_hand() {
+        let who = player::<T>("player", 0);
+        let ids = CurrentHandOf::<T>::get(&who)
+            .expect("player has a current hand; qed")
+            .into_inner();
+
+        #[extrinsic_call]
+        set_preset_hand(RawOrigin::Signed(who.clone()), ids);
+
+        assert!(CurrentHandOf::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn resign() {
+        let a = player::<T>("creator", 0);
+        let b = player::<T>("opponent", 0);
+        let game_id = wagered_game::<T>(&a, &b);
+
+        #[extrinsic_call]
+        resign(RawOrigin::Signed(b.clone()), game_id);
+
+        assert!(ActiveGameOf::<T>::get(&b).is_none());
+    }
+
+    #[benchmark]
+    fn offer_draw() {
+        let a = player::<T>("creator", 0);
+        let b = player::<T>("opponent", 0);
+        let game_id = wagered_game::<T>(&a, &b);
+
+        #[extrinsic_call]
+        offer_draw(RawOrigin::Signed(a.clone()), game_id);
+
+        assert_eq!(DrawOffers::<T>::get(&game_id), Some(a));
+    }
+
+    #[benchmark]
+    fn accept_draw() {
+        let a = player::<T>("creator", 0);
+        let b = player::<T>("opponent", 0);
+        let game_id = wagered_game::<T>(&a, &b);
+        Pallet::<T>::offer_draw(RawOrigin::Signed(a.clone()).into(), game_id)
+            .expect("game is a running PvP game; qed");
+
+        #[extrinsic_call]
+        accept_draw(RawOrigin::Signed(b.clone()), game_id);
+
+        assert!(ActiveGameOf::<T>::get(&b).is_none());
+    }
+
+    #[benchmark]
+    fn abandon() {
+        let human = player::<T>("human", 0);
+        let game_id = pve_game::<T>(&human);
+
+        #[extrinsic_call]
+        abandon(RawOrigin::Signed(human.clone()), game_id);
+
+        assert!(ActiveGameOf::<T>::get(&human).is_none());
+    }
+
+    #[benchmark]
+    fn accept_wager() {
+        let a = player::<T>("creator", 0);
+        let b = player::<T>("opponent", 0);
+        let game_id = pending_wager::<T>(&a, &b);
+
+        #[extrinsic_call]
+        accept_wager(RawOrigin::Signed(b), game_id);
+
+        assert!(matches!(
+            GameStorage::<T>::get(&game_id).map(|g| g.state),
+            Some(GameState::Dealing)
+        ));
+    }
+
+    /// Both hands were submitted while the wager was pending, so every card gets unlocked.
+    #[benchmark]
+    fn decline_wager() {
+        let a = player::<T>("creator", 0);
+        let b = player::<T>("opponent", 0);
+        let game_id = pending_wager::<T>(&a, &b);
+        submit_both::<T>(game_id, &a, &b);
+
+        #[extrinsic_call]
+        decline_wager(RawOrigin::Signed(b), game_id);
+
+        assert!(!GameStorage::<T>::contains_key(&game_id));
+    }
+
+    /// `n` PvP games whose turn runs out and passes to the opponent. What a skip sets off
+    /// beyond that is charged with `timeout_end_game` and `timeout_ai_turn`.
+    #[benchmark]
+    fn process_timeouts(n: Linear<0, { T::MaxTimeoutsPerBlock::get() }>) {
+        let games: Vec<GameId<T>> = (0..n)
+            .map(|i| {
+                let a = player::<T>("creator", i);
+                let b = player::<T>("opponent", i);
+                Pallet::<T>::create_game(
+                    RawOrigin::Signed(a.clone()).into(),
+                    sp_std::vec![a.clone(), b],
+                    GameMode::PvP,
+                    all_rules(),
+                    largest_board(),
+                    Zero::zero(),
+                    TradeRule::None,
+                    None,
+                    HandVisibility::Open,
+                )
+                .expect("both players are free; qed");
+                ActiveGameOf::<T>::get(&a).expect("game was just created; qed")
+            })
+            .collect();
+        deal_pending::<T>();
+        let deadline = turn_deadline::<T>();
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(deadline);
+        }
+
+        for game_id in games {
+            let game =
+                GameStorage::<T>::get(&game_id).expect("a first timeout never ends the game; qed");
+            assert_eq!(game.last_played_block, deadline);
+        }
+    }
+
+    /// A timeout on the last turn of a wagered game staking every card, with a winner.
+    #[benchmark]
+    fn timeout_end_game() {
+        let a = player::<T>("creator", 0);
+        let b = player::<T>("opponent", 0);
+        let game_id = wagered_game::<T>(&a, &b);
+        GameStorage::<T>::mutate(&game_id, |game| {
+            let game = game.as_mut().expect("game was just created; qed");
+            game.player_turn = 1;
+            game.round = game.max_rounds.saturating_sub(1);
+            game.scores = (6, 4);
+        });
+        let deadline = turn_deadline::<T>();
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(deadline);
+        }
+
+        assert!(ActiveGameOf::<T>::get(&a).is_none());
+    }
+
+    /// A human turn running out at the opening, handing the move to the costliest AI profile
+    /// that still plays in-block on this chain.
+    #[benchmark]
+    fn timeout_ai_turn() {
+        let human = player::<T>("human", 0);
+        let profile = if T::AiMovesOffchain::get() {
+            AiProfile::Greedy
+        } else {
+            AiProfile::Solver
+        };
+        let game_id = pve_game_against::<T>(&human, profile);
+        let deadline = turn_deadline::<T>();
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(deadline);
+        }
+
+        assert_eq!(MoveLog::<T>::get(&game_id).len(), 1);
+    }
+
+    /// `n` PvE games whose human submitted a hand in an earlier block, each getting elements
+    /// and an AI hand fitted to the human's.
+    #[benchmark]
+    fn deal_games(n: Linear<0, { <PendingDealLimit as Get<u32>>::get() }>) {
+        for i in 0..n {
+            let human = player::<T>("human", i);
+            let game_id = pve_game_without_hand::<T>(&human, AiProfile::Solver);
+            GameElements::<T>::remove(&game_id);
+            Pallet::<T>::submit_hand(RawOrigin::Signed(human).into(), game_id, Vec::new())
+                .expect("hand not yet submitted; qed");
+        }
+        let drawn = frame_system::Pallet::<T>::block_number() + 1u32.into();
+
+        #[block]
+        {
+            Pallet::<T>::deal_games(drawn);
+        }
+
+        assert!(PendingDeals::<T>::get().is_empty());
+    }
+
+    /// An `on_idle` call that only looks at the cursor.
+    #[benchmark]
+    fn prune_idle() {
+        let now = frame_system::Pallet::<T>::block_number();
+        PruneCursor::<T>::put(now);
+
+        #[block]
+        {
+            Pallet::<T>::on_idle(now, Weight::MAX);
+        }
+    }
+
+    /// One `PendingPrunes` bucket of `g` finished games, each with both hands stored.
+    #[benchmark]
+    fn prune_bucket(g: Linear<0, { T::MaxPrunesPerBlock::get() }>) {
+        let finished_at = frame_system::Pallet::<T>::block_number();
+        let hand: BoundedVec<HandEntry, HandLimit> = (0..T::HandSize::get())
+            .map(|card_id| HandEntry {
+                card_id,
+                north: 1,
+                east: 2,
+                south: 3,
+                west: 4,
+                used: true,
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("HandSize is within HandLimit; qed");
+        let mut bucket: Vec<GameId<T>> = Vec::new();
+        for i in 0..g {
+            let a: AccountIdOf<T> = account("a", i, 0);
+            let b: AccountIdOf<T> = account("b", i, 0);
+            let game_id = T::Hashing::hash_of(&(b"prune", i));
+            let game = Game {
+                state: GameState::Finished { winner: Some(0) },
+                last_played_block: finished_at,
+                players: sp_std::vec![a.clone(), b.clone()]
+                    .try_into()
+                    .expect("two players; qed"),
+                player_turn: 0,
+                round: 0,
+                max_rounds: 0,
+                board: Board::new(largest_board()),
+                scores: (6, 4),
+            };
+            GameStorage::<T>::insert(&game_id, game);
+            GameModes::<T>::insert(&game_id, GameMode::PvP);
+            GameRules::<T>::insert(&game_id, all_rules());
+            GameElements::<T>::insert(&game_id, Elements::default());
+            HandsOfGame::<T>::insert(&game_id, &a, hand.clone());
+            HandsOfGame::<T>::insert(&game_id, &b, hand.clone());
+            bucket.push(game_id);
+        }
+        PendingPrunes::<T>::insert(
+            finished_at,
+            BoundedVec::<_, T::MaxPrunesPerBlock>::try_from(bucket)
+                .expect("at most MaxPrunesPerBlock games; qed"),
+        );
+        PruneCursor::<T>::put(finished_at);
+        let now = finished_at.saturating_add(T::FinishedGameRetention::get());
+
+        #[block]
+        {
+            Pallet::<T>::on_idle(now, Weight::MAX);
+        }
+
+        assert!(PendingPrunes::<T>::get(finished_at).is_empty());
+    }
+
+    impl_benchmark_test_suite!(Eterra, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -8,55 +8,128 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+#[cfg(feature = "std")]
+pub mod replay;
 mod types;
+pub mod weights;
+pub use weights::*;
 
 pub use crate::types::GameId;
 use frame_support::ensure;
 use frame_support::pallet_prelude::ConstU32;
-use frame_support::traits::Get;
+use frame_support::traits::fungible::{InspectHold, MutateHold};
+use frame_support::traits::tokens::{Fortitude, Precision, Restriction};
+use frame_support::traits::{Get, Randomness};
+use frame_support::weights::Weight;
 use frame_support::BoundedVec;
 use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::Encode;
 use sp_runtime::traits::Hash;
 use sp_runtime::traits::SaturatedConversion;
+use sp_runtime::traits::Zero;
+use sp_runtime::{PerThing, Saturating};
 use sp_std::vec::Vec;
-pub use types::board::Board;
+pub use types::board::{Board, BoardSize};
 pub use types::card::Card;
 pub use types::card::Possession as Player; // PlayerOne / PlayerTwo
 pub use types::game::*;
+pub use types::rules::{Elements, RuleSet};
+pub use types::view::{GameRecord, GameView, HandView, LegalMove, MovePreview};
 
 use eterra_card_ai_adapter::eterra_adapter as ai;
 use pallet_eterra_monte_carlo_ai as mc_ai; // reserved for future use
+use pallet_eterra_rating::{OnGameFinished, Outcome};
+use pallet_eterra_simple_matchmaker::MatchError;
+use pallet_eterra_simple_tcg::CardLocker;
+
+/// How many blocks past a full block-indexed queue entry a game may be pushed back.
+const MAX_SPILL_BLOCKS: u32 = 8;
+
+/// Opening position on the largest board with full hands and every capture rule on, which is
+/// where the AI has the most moves to search. Runtimes using the card adapter can plug this
+/// into the Monte-Carlo pallet's `BenchmarkHelper`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AiBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl mc_ai::BenchmarkHelper<ai::State> for AiBenchmarkHelper {
+    fn worst_case_state() -> ai::State {
+        let entries: Vec<ai::HandEntry> = (0..eterra_rules::MAX_HAND_SIZE as u8)
+            .map(|i| ai::HandEntry {
+                north: i % 9 + 1,
+                east: (i + 3) % 9 + 1,
+                south: (i + 5) % 9 + 1,
+                west: (i + 7) % 9 + 1,
+                used: false,
+            })
+            .collect();
+        let hand = ai::Hand::new(&entries);
+        let dim = eterra_rules::MAX_BOARD_DIM as u8;
+        ai::State {
+            board: Board::new(BoardSize::new(dim, dim)),
+            scores: (hand.len, hand.len),
+            player_turn: 0,
+            round: 0,
+            max_rounds: hand.len,
+            hands: [hand.clone(), hand],
+            rules: RuleSet {
+                same: true,
+                plus: true,
+                combo: true,
+                same_wall: true,
+                elemental: true,
+            },
+            elements: Default::default(),
+        }
+    }
+}
 
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::ConstU32;
+    use frame_support::traits::fungible::{self, MutateHold};
+    use frame_support::traits::tokens::Precision;
     use frame_support::BoundedVec;
     use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::Hash;
-    use sp_runtime::Saturating;
+    use sp_runtime::traits::{Hash, Zero};
+    use sp_runtime::{Permill, Saturating};
     use sp_std::vec::Vec;
 
+    use crate::weights::WeightInfo;
+
     pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub type BalanceOf<T> =
+        <<T as Config>::StakeCurrency as fungible::Inspect<AccountIdOf<T>>>::Balance;
 
-    use crate::types::board::Board;
+    use crate::types::board::{Board, BoardSize};
     use crate::types::card::Card;
     use crate::types::card::Possession as Player;
     use crate::types::game::Move;
     use crate::types::game::*;
+    use crate::types::rules::{Elements, RuleSet};
     use crate::types::GameId;
     // Alias the simple TCG pallet so we can read card ownership & stats
     use eterra_card_ai_adapter::eterra_adapter as ai;
     use pallet_eterra_monte_carlo_ai as mc_ai;
     use pallet_eterra_simple_tcg as cards; // reserved for future use
+    use pallet_eterra_simple_tcg::{CardLocker, LockReason};
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
     pub trait Config: frame_system::Config + cards::pallet::Config + mc_ai::pallet::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Source of the randomness starting players, elements and AI hands are drawn from. A
+        /// game is dealt in the first block whose randomness is later than the deal request.
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
         // Exact number of players that can join a single game
         #[pallet::constant]
         type NumPlayers: Get<u32> + Clone + TypeInfo;
@@ -64,13 +137,51 @@ pub mod pallet {
         type MaxRounds: Get<u8>;
         #[pallet::constant]
         type BlocksToPlayLimit: Get<u8>;
-        /// Exactly how many cards a submitted hand must contain
+        /// Maximum number of turn timeouts processed in a single block (bounds on_initialize work)
+        #[pallet::constant]
+        type MaxTimeoutsPerBlock: Get<u32>;
+        /// Blocks a finished game keeps its full state before `on_idle` archives it
+        #[pallet::constant]
+        type FinishedGameRetention: Get<BlockNumberFor<Self>>;
+        /// Maximum number of finished games queued for pruning under a single block
+        #[pallet::constant]
+        type MaxPrunesPerBlock: Get<u32>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+        /// Currency for wagers; stakes are held under `HoldReason::GameStake`
+        type StakeCurrency: fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + fungible::Mutate<Self::AccountId>;
+        /// Receives the house cut of wagered games
+        type TreasuryAccount: Get<Self::AccountId>;
+        /// Share of the loser's stake paid to `TreasuryAccount` instead of the winner
+        #[pallet::constant]
+        type HouseCut: Get<Permill>;
+        /// Least stake each player puts up for matchmade games; queues may ask for more (zero for
+        /// unwagered matchmaking)
+        #[pallet::constant]
+        type MatchmakingStake: Get<BalanceOf<Self>>;
+        /// Exactly how many cards a submitted hand must contain (at most `HandLimit`)
         #[pallet::constant]
         type HandSize: Get<u32>;
         /// Special account representing the AI opponent in PvE games
         type AiAccount: Get<Self::AccountId>;
-        /// Default AI difficulty (0..=100)
+        /// Difficulty (0..=100) of PvE games created without an `AiProfile`
         type AiDifficulty: Get<u8>;
+        /// Leave the AI's PvE moves to the Monte-Carlo pallet's offchain worker instead of
+        /// searching them inside the human player's extrinsic.
+        type AiMovesOffchain: Get<bool>;
+        /// Told how every finished game went, e.g. to keep ratings
+        type OnGameFinished: OnGameFinished<Self::AccountId>;
+        /// Weight information for extrinsics and hooks in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Reasons the pallet places holds on player funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Stake escrowed for a wagered game until it ends.
+        #[codec(index = 0)]
+        GameStake,
     }
 
     #[pallet::storage]
@@ -83,16 +194,125 @@ pub mod pallet {
     >;
 
     #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
     pub enum GameMode {
         PvP,
         PvE,
     }
 
+    /// Card ante for PvP games: which submitted cards change owner when the game ends.
+    #[derive(
+        Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug, Default,
+    )]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub enum TradeRule {
+        /// Nobody loses cards.
+        #[default]
+        None,
+        /// The winner takes one card from the loser.
+        One,
+        /// The winner takes as many cards as the score difference (at least one).
+        Diff,
+        /// Every card ends up with whoever holds it on the final board; draws included.
+        Direct,
+        /// The winner takes the loser's whole hand.
+        All,
+    }
+
+    /// Whether the players' cards are known to both sides from the start of a PvP game.
+    #[derive(
+        Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug, Default,
+    )]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub enum HandVisibility {
+        /// Hands are stored in full by `submit_hand`, so either player can read both.
+        #[default]
+        Open,
+        /// Players submit salted commitments with `commit_hand`, and each card stays
+        /// hidden until `play_from_hand` reveals it.
+        Hidden,
+    }
+
+    /// Opens one committed slot of a hidden hand: the card and the salt it was committed with.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct CardReveal {
+        pub card_id: u32,
+        pub salt: [u8; 32],
+    }
+
+    /// Settings a matchmaker queue creates its PvP games with; see `create_game` for each.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug, Default)]
+    pub struct MatchSettings<Balance> {
+        pub rules: RuleSet,
+        pub board_size: BoardSize,
+        /// Held from both players and paid out like a wager. `MatchmakingStake` is the floor.
+        pub stake: Balance,
+        pub trade_rule: TradeRule,
+        pub hand_visibility: HandVisibility,
+        /// Casual games don't count towards ratings.
+        pub casual: bool,
+    }
+
+    /// How the AI opponent of a PvE game picks its moves and is dealt its hand.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub enum AiProfile {
+        /// Plays any legal move, holding uniformly low cards.
+        Novice,
+        /// Plays whichever move scores best right away, never looking at the reply. Holds
+        /// lopsided cards with one strong edge each.
+        Greedy,
+        /// Monte-Carlo tree search with a budget scaled by the difficulty (0..=100). Holds
+        /// cards that get closer to the player's the higher the difficulty.
+        MonteCarlo(u8),
+        /// Plays perfectly whenever the rest of the game fits its budget and searches at
+        /// full difficulty otherwise. Holds a copy of the player's hand.
+        Solver,
+    }
+
+    impl AiProfile {
+        /// Whether picking a move takes a search, which `AiMovesOffchain` leaves to the
+        /// offchain worker.
+        pub fn searches(&self) -> bool {
+            matches!(self, Self::MonteCarlo(_) | Self::Solver)
+        }
+    }
+
     #[pallet::storage]
     #[pallet::getter(fn game_mode_of)]
     pub type GameModes<T: Config> =
         StorageMap<_, Blake2_128Concat, GameId<T>, GameMode, OptionQuery>;
 
+    /// Capture rules each game was created with. Missing entries mean basic rules only.
+    #[pallet::storage]
+    #[pallet::getter(fn game_rules_of)]
+    pub type GameRules<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, RuleSet, ValueQuery>;
+
+    /// Trade rule of ante games; absent for `TradeRule::None`.
+    #[pallet::storage]
+    #[pallet::getter(fn trade_rule_of)]
+    pub type GameTradeRules<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, TradeRule, OptionQuery>;
+
+    /// Hand visibility of games with hidden hands; absent for `HandVisibility::Open`.
+    #[pallet::storage]
+    #[pallet::getter(fn hand_visibility_of)]
+    pub type GameHandVisibility<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, HandVisibility, OptionQuery>;
+
+    /// AI opponent of each PvE game.
+    #[pallet::storage]
+    #[pallet::getter(fn ai_profile_of)]
+    pub type GameAiProfiles<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, AiProfile, OptionQuery>;
+
+    /// Elemental cell modifiers, only present for games with `RuleSet::elemental`.
+    #[pallet::storage]
+    #[pallet::getter(fn game_elements_of)]
+    pub type GameElements<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, Elements, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn active_game_of)]
     /// Tracks if an account is currently in an active game. A player may have at most one.
@@ -110,11 +330,85 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Matchmade PvP games from casual queues, which are not reported to `OnGameFinished`.
+    #[pallet::storage]
+    pub type CasualGames<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, (), OptionQuery>;
+
+    /// Per-player stake of wagered games. Held from each player until the game ends.
+    #[pallet::storage]
+    #[pallet::getter(fn stake_of)]
+    pub type GameStakes<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, BalanceOf<T>, OptionQuery>;
+
+    /// Pending draw offer per game: the player who offered it.
+    #[pallet::storage]
+    #[pallet::getter(fn draw_offer_of)]
+    pub type DrawOffers<T: Config> =
+        StorageMap<_, Blake2_128Concat, GameId<T>, AccountIdOf<T>, OptionQuery>;
+
+    /// BlockNumber => games whose current turn runs out at that block.
+    /// Entries go stale when a move is made in time; `on_initialize` skips those.
+    #[pallet::storage]
+    #[pallet::getter(fn turn_timeouts)]
+    pub type TurnTimeouts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<GameId<T>, T::MaxTimeoutsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Games in `GameState::Dealing`, with the block each deal was requested in.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_deals)]
+    pub type PendingDeals<T: Config> =
+        StorageValue<_, BoundedVec<(GameId<T>, BlockNumberFor<T>), PendingDealLimit>, ValueQuery>;
+
+    /// Every placement made in a game, in order.
+    #[pallet::storage]
+    #[pallet::getter(fn move_log_of)]
+    pub type MoveLog<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        BoundedVec<MoveRecord, MoveLogLimit>,
+        ValueQuery,
+    >;
+
+    /// BlockNumber => games that finished at that block and still hold full state.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_prunes)]
+    pub type PendingPrunes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<GameId<T>, T::MaxPrunesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Next block of `PendingPrunes` that `on_idle` will look at. Unset until a game finishes.
+    #[pallet::storage]
+    #[pallet::getter(fn prune_cursor)]
+    pub type PruneCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Compact record of pruned games.
+    #[pallet::storage]
+    #[pallet::getter(fn game_summary_of)]
+    pub type GameArchive<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        GameSummary<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        OptionQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
+        /// `ai_profile` is the AI opponent of PvE games, `None` for PvP.
         GameCreated {
             game_id: GameId<T>,
+            ai_profile: Option<AiProfile>,
         },
         MovePlayed {
             game_id: GameId<T>,
@@ -135,10 +429,66 @@ pub mod pallet {
             game_id: GameId<T>,
             player: AccountIdOf<T>,
         },
+        /// The player to move let `BlocksToPlayLimit` pass and their turn was skipped.
+        TurnTimedOut {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+        },
         HandSubmitted {
             game_id: GameId<T>,
             player: AccountIdOf<T>,
         },
+        /// `player` resigned; followed by `GameFinished` with the opponent as winner.
+        GameResigned {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+        },
+        DrawOffered {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+        },
+        /// Both players agreed to a draw; followed by `GameFinished` with no winner.
+        DrawAgreed {
+            game_id: GameId<T>,
+        },
+        /// `player` left a PvE game; followed by `GameFinished` with the AI as winner.
+        GameAbandoned {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+        },
+        /// A wagered game awaits `accept_wager` from `opponent`; the creator's stake is held.
+        WagerProposed {
+            game_id: GameId<T>,
+            opponent: AccountIdOf<T>,
+            stake: BalanceOf<T>,
+        },
+        /// The opponent matched the stake and the game started.
+        WagerAccepted {
+            game_id: GameId<T>,
+        },
+        /// A pending wager was called off by `player`; the creator's stake was released.
+        WagerDeclined {
+            game_id: GameId<T>,
+            player: AccountIdOf<T>,
+        },
+        /// Stakes of a finished game were paid out. `winner` is `None` for a refunded draw.
+        StakesSettled {
+            game_id: GameId<T>,
+            winner: Option<AccountIdOf<T>>,
+            payout: BalanceOf<T>,
+            house_cut: BalanceOf<T>,
+        },
+        /// Ante cards moved from `from` to `to` under the game's trade rule.
+        CardsTraded {
+            game_id: GameId<T>,
+            from: AccountIdOf<T>,
+            to: AccountIdOf<T>,
+            card_ids: Vec<u32>,
+        },
+        /// Board and hands were deleted; the game now only lives in `GameArchive`.
+        GameArchived {
+            game_id: GameId<T>,
+        },
     }
 
     #[pallet::error]
@@ -164,13 +514,83 @@ pub mod pallet {
         CardNotOwned,
         PlayerAlreadyInGame,
         PresetHandMissing,
+        InvalidBoardSize,
+        GameNotPlaying,
+        NotPvPGame,
+        NotPvEGame,
+        NoDrawOffer,
+        CannotAcceptOwnDrawOffer,
+        WagerRequiresPvP,
+        InsufficientStake,
+        NotPendingWager,
+        NotWagerOpponent,
+        TradeRequiresPvP,
+        AiProfileRequiresPvE,
+        /// A Monte-Carlo difficulty above 100.
+        InvalidAiDifficulty,
+        /// Hidden hands are only available in PvP games.
+        HiddenHandsRequirePvP,
+        /// Trade rules need both hands on chain when the game ends.
+        TradeRequiresOpenHands,
+        /// The game's hands are hidden: commit with `commit_hand` and reveal cards as they
+        /// are played.
+        HandIsHidden,
+        /// The game's hands are open: submit with `submit_hand`, without reveals.
+        HandIsOpen,
+        /// Playing from a hidden hand needs a `CardReveal` for the slot.
+        RevealRequired,
+        /// The revealed card and salt don't match the slot's commitment.
+        BadReveal,
+        /// Something rides on this game's cards (a stake, an ante, a locked or hidden hand),
+        /// so moves must come from a hand via `play_from_hand`.
+        PlayFromHandRequired,
+        /// Hidden hands are committed before the first card is played.
+        CommitPhaseOver,
+        /// Too many games are waiting to be dealt; try again next block.
+        TooManyPendingDeals,
+        /// A card edge outside `eterra_rules::RANKS` (1..=9).
+        InvalidCardRank,
     }
 
-    /// Limit of cards per hand (defaults to 5 via Config::HandSize)
-    pub type HandLimit = ConstU32<5>;
+    /// Storage bound for hands; the exact size is `Config::HandSize`.
+    pub type HandLimit = ConstU32<{ eterra_rules::MAX_HAND_SIZE as u32 }>;
+
+    /// Max number of games waiting to be dealt.
+    pub type PendingDealLimit = ConstU32<256>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            // Deal the games requested before the randomness was last refreshed
+            let (_, drawn) = <T as Config>::Randomness::random(b"eterra/deal");
+            let mut weight = <T as Config>::WeightInfo::deal_games(Self::deal_games(drawn));
+
+            // Take the games whose turn deadline is now; the list is bounded by MaxTimeoutsPerBlock.
+            let games = TurnTimeouts::<T>::take(n);
+            weight.saturating_accrue(<T as Config>::WeightInfo::process_timeouts(
+                games.len() as u32
+            ));
+            for game_id in games.into_inner().into_iter() {
+                weight.saturating_accrue(Self::process_turn_timeout(&game_id, n));
+            }
+            weight
+        }
+
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_finished_games(n, remaining_weight)
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::HandSize::get() > 0 && T::HandSize::get() <= HandLimit::get(),
+                "HandSize must be within 1..=HandLimit"
+            );
+        }
+    }
 
     /// A single entry in a player's submitted hand
     #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
     pub struct HandEntry {
         pub card_id: u32,
         pub north: u8,
@@ -194,6 +614,20 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Commitments of hidden hands, one per slot, keyed like `HandsOfGame`. A slot turns to
+    /// `None` once its card is revealed and moved into `HandsOfGame`.
+    #[pallet::storage]
+    #[pallet::getter(fn hand_commitments)]
+    pub type HandCommitments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        BoundedVec<Option<T::Hash>, HandLimit>,
+        OptionQuery,
+    >;
+
     /// The player's current hand configuration (card IDs only). This is editable by the user in the UI.
     #[pallet::storage]
     #[pallet::getter(fn current_hand_of)]
@@ -208,13 +642,51 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_game())]
         pub fn create_game(
             origin: OriginFor<T>,
             mut players: Vec<AccountIdOf<T>>,
             game_mode: GameMode,
+            rules: RuleSet,
+            board_size: BoardSize,
+            stake: BalanceOf<T>,
+            trade_rule: TradeRule,
+            ai_profile: Option<AiProfile>,
+            hand_visibility: HandVisibility,
         ) -> DispatchResult {
             let who: AccountIdOf<T> = ensure_signed(origin)?;
+            ensure!(board_size.is_valid(), Error::<T>::InvalidBoardSize);
+            let wagered = !stake.is_zero();
+            ensure!(
+                !wagered || matches!(game_mode, GameMode::PvP),
+                Error::<T>::WagerRequiresPvP
+            );
+            ensure!(
+                trade_rule == TradeRule::None || matches!(game_mode, GameMode::PvP),
+                Error::<T>::TradeRequiresPvP
+            );
+            ensure!(
+                ai_profile.is_none() || matches!(game_mode, GameMode::PvE),
+                Error::<T>::AiProfileRequiresPvE
+            );
+            let hidden = hand_visibility == HandVisibility::Hidden;
+            ensure!(
+                !hidden || matches!(game_mode, GameMode::PvP),
+                Error::<T>::HiddenHandsRequirePvP
+            );
+            ensure!(
+                !hidden || trade_rule == TradeRule::None,
+                Error::<T>::TradeRequiresOpenHands
+            );
+            if let Some(AiProfile::MonteCarlo(difficulty)) = ai_profile {
+                ensure!(difficulty <= 100, Error::<T>::InvalidAiDifficulty);
+            }
+            let ai_profile = match game_mode {
+                GameMode::PvP => None,
+                GameMode::PvE => {
+                    Some(ai_profile.unwrap_or(AiProfile::MonteCarlo(T::AiDifficulty::get())))
+                }
+            };
 
             // Require the creator to have a current hand before starting a game
             ensure!(
@@ -303,8 +775,8 @@ pub mod pallet {
                 Error::<T>::GameNotFound
             );
 
-            let initial_board: Board = Default::default();
-            let initial_scores = (5, 5);
+            let initial_board = Board::new(board_size);
+            let initial_scores = Self::initial_scores();
 
             let mut game: Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers> = Game {
                 state: GameState::Playing,
@@ -315,12 +787,22 @@ pub mod pallet {
                     .map_err(|_| Error::<T>::InternalError)?,
                 player_turn: 0,
                 round: 0,
-                max_rounds: T::MaxRounds::get(),
+                max_rounds: Self::max_rounds_for(&board_size),
                 board: initial_board.clone(),
                 scores: initial_scores,
             };
 
             GameModes::<T>::insert(&game_id, game_mode.clone());
+            Self::store_rules(&game_id, rules);
+            if trade_rule != TradeRule::None {
+                GameTradeRules::<T>::insert(&game_id, trade_rule);
+            }
+            if hidden {
+                GameHandVisibility::<T>::insert(&game_id, hand_visibility);
+            }
+            if let Some(profile) = ai_profile {
+                GameAiProfiles::<T>::insert(&game_id, profile);
+            }
             // Mark participants as busy with this game
             match game_mode {
                 GameMode::PvP => {
@@ -352,37 +834,34 @@ pub mod pallet {
             push_recent(&creator);
             push_recent(&opponent);
 
-            // If PvE, create AI hand immediately so UI can render it.
-            if matches!(game_mode, GameMode::PvE) {
-                let ai_acc = T::AiAccount::get();
-                if HandsOfGame::<T>::get(&game_id, &ai_acc).is_none() {
-                    if let Some(ai_hand) = Self::generate_ai_hand_default(&game_id) {
-                        HandsOfGame::<T>::insert(&game_id, &ai_acc, ai_hand);
-                    }
-                }
-            }
-
-            // Set starting player: PvE -> creator always starts; PvP -> keep randomized start
-            if matches!(game_mode, GameMode::PvE) {
-                // players[0] is guaranteed to be the creator after normalization above
-                game.set_player_turn(0);
+            // The creator (players[0] after normalization above) starts PvE games. The PvP
+            // starting player, the elements and the AI's hand are drawn when the game is dealt,
+            // which for wagers waits until the opponent matches the stake via `accept_wager`
+            if wagered {
+                T::StakeCurrency::hold(&HoldReason::GameStake.into(), &creator, stake)
+                    .map_err(|_| Error::<T>::InsufficientStake)?;
+                GameStakes::<T>::insert(&game_id, stake);
+                game.set_state(GameState::Matchmaking);
             } else {
-                // PvP: randomize starting player based on creator hash
-                game.set_player_turn(
-                    if sp_io::hashing::blake2_128(&creator.encode())[0] % 2 == 0 {
-                        0
-                    } else {
-                        1
-                    },
-                );
+                Self::request_deal(&game_id, &mut game)?;
             }
 
             GameStorage::<T>::insert(&game_id, game.clone());
-            Self::deposit_event(Event::GameCreated { game_id });
+            Self::deposit_event(Event::GameCreated {
+                game_id,
+                ai_profile,
+            });
+            if wagered {
+                Self::deposit_event(Event::WagerProposed {
+                    game_id,
+                    opponent,
+                    stake,
+                });
+            }
             Ok(())
         }
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(<T as Config>::WeightInfo::play())]
         pub fn play(origin: OriginFor<T>, game_id: GameId<T>, player_move: Move) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -397,6 +876,7 @@ pub mod pallet {
 
             // Validate the current player's turn and move
             Self::validate_player_turn(&game, &who)?;
+            Self::validate_free_play(&game_id, &game)?;
             Self::validate_move(&game, &player_move)?;
 
             // Determine the current player's index (0 or 1)
@@ -406,7 +886,8 @@ pub mod pallet {
             Self::place_card_on_board(&mut game, &player_move, player_ix);
 
             // Capture logic
-            Self::apply_capture_logic(&mut game, &player_move, player_ix);
+            let flipped = Self::apply_capture_logic(&game_id, &mut game, &player_move, player_ix);
+            Self::record_move(&game_id, player_ix, None, &player_move, flipped);
 
             // Update the last_played_block to the current block number
             let current_block = <frame_system::Pallet<T>>::block_number();
@@ -442,6 +923,7 @@ pub mod pallet {
                 Self::end_game(&game_id, winner);
                 return Ok(());
             }
+            Self::schedule_turn_timeout(&game_id, current_block);
 
             log::debug!(
                 "Next turn belongs to: {:?}",
@@ -468,7 +950,7 @@ pub mod pallet {
         /// Submit your current 5-card hand for this game. The submitted hand is always loaded from your current hand configuration.
         /// The `card_ids` argument is ignored and exists for ABI compatibility only.
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(<T as Config>::WeightInfo::submit_hand())]
         pub fn submit_hand(
             origin: OriginFor<T>,
             game_id: GameId<T>,
@@ -480,6 +962,11 @@ pub mod pallet {
             let game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
             ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
 
+            ensure!(
+                !GameHandVisibility::<T>::contains_key(&game_id),
+                Error::<T>::HandIsHidden
+            );
+
             // Prevent resubmission for this game
             ensure!(
                 HandsOfGame::<T>::get(&game_id, &who).is_none(),
@@ -521,23 +1008,34 @@ pub mod pallet {
                     .map_err(|_| Error::<T>::HandSizeInvalid)?;
             }
 
+            // Submitted cards can't be sold or given away until the game ends
+            let reason = if GameTradeRules::<T>::contains_key(&game_id) {
+                LockReason::Ante
+            } else {
+                LockReason::InGame
+            };
+            for entry in hand.iter() {
+                <cards::Pallet<T> as CardLocker>::lock(entry.card_id, reason)?;
+            }
+
             HandsOfGame::<T>::insert(&game_id, &who, hand);
             Self::deposit_event(Event::HandSubmitted {
                 game_id,
                 player: who.clone(),
             });
 
-            // PvE: submitting player is always the human. Generate AI hand right away,
-            // and if it's AI's turn (e.g., AI won first move), let it act immediately.
+            // PvE: submitting player is always the human. Deal the AI its profile's hand in a
+            // later block now that the human's is known; otherwise, if it's AI's turn, let it
+            // act immediately.
             if matches!(GameModes::<T>::get(&game_id), Some(GameMode::PvE)) {
                 let ai_acc = T::AiAccount::get();
-                if HandsOfGame::<T>::get(&game_id, &ai_acc).is_none() {
-                    if let Some(ai_hand) = Self::generate_ai_hand_for_game(&game_id, &who) {
-                        HandsOfGame::<T>::insert(&game_id, &ai_acc, ai_hand);
-                    }
-                }
-                // If AI is up next, take its turn now that it has a hand.
-                if let Some(mut game) = GameStorage::<T>::get(&game_id) {
+                let untouched = HandsOfGame::<T>::get(&game_id, &ai_acc)
+                    .map_or(true, |hand| hand.iter().all(|e| !e.used));
+                let mut game = game;
+                if untouched && matches!(game.state, GameState::Playing | GameState::Dealing) {
+                    Self::request_deal(&game_id, &mut game)?;
+                    GameStorage::<T>::insert(&game_id, game);
+                } else {
                     Self::maybe_ai_take_turn(&game_id, &mut game);
                 }
             }
@@ -545,14 +1043,17 @@ pub mod pallet {
         }
 
         /// Play a card by referencing its index in the submitted hand (0..HandSize-1).
+        /// With hidden hands `hand_index` names a committed slot, which `reveal` must open;
+        /// open hands take no reveal.
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(<T as Config>::WeightInfo::play_from_hand())]
         pub fn play_from_hand(
             origin: OriginFor<T>,
             game_id: GameId<T>,
             hand_index: u8,
             x: u8,
             y: u8,
+            reveal: Option<CardReveal>,
         ) -> DispatchResult {
             let who: AccountIdOf<T> = ensure_signed(origin)?;
 
@@ -561,12 +1062,24 @@ pub mod pallet {
 
             // Validate it's the caller's turn and the target cell is open
             Self::validate_player_turn(&game, &who)?;
-            ensure!(x < 4 && y < 4, Error::<T>::InvalidMove);
+            ensure!(
+                game.board.in_bounds(x as usize, y as usize),
+                Error::<T>::InvalidMove
+            );
             ensure!(
                 game.board[x as usize][y as usize].is_none(),
                 Error::<T>::CellOccupied
             );
 
+            // A hidden card joins the stored hand once revealed; play it from there
+            let hand_index = if GameHandVisibility::<T>::contains_key(&game_id) {
+                let reveal = reveal.ok_or(Error::<T>::RevealRequired)?;
+                Self::reveal_card(&game_id, &who, hand_index, reveal)?
+            } else {
+                ensure!(reveal.is_none(), Error::<T>::HandIsOpen);
+                hand_index
+            };
+
             // Get caller's hand
             let mut hand =
                 HandsOfGame::<T>::get(&game_id, &who).ok_or(Error::<T>::HandNotSubmitted)?;
@@ -592,7 +1105,8 @@ pub mod pallet {
 
             // Place the card and resolve capture logic (mirrors `play`)
             Self::place_card_on_board(&mut game, &mv, player_ix);
-            Self::apply_capture_logic(&mut game, &mv, player_ix);
+            let flipped = Self::apply_capture_logic(&game_id, &mut game, &mv, player_ix);
+            Self::record_move(&game_id, player_ix, Some(hand_index), &mv, flipped);
 
             // Mark card as used and persist the hand
             hand[idx].used = true;
@@ -623,6 +1137,7 @@ pub mod pallet {
                 Self::end_game(&game_id, winner);
                 return Ok(());
             }
+            Self::schedule_turn_timeout(&game_id, current_block);
 
             // If this is a PvE game and it's now the AI's turn, let the AI act immediately.
             if matches!(GameModes::<T>::get(&game_id), Some(GameMode::PvE)) {
@@ -635,7 +1150,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1, 1).ref_time())]
+        #[pallet::weight(<T as Config>::WeightInfo::force_finish_turn())]
         pub fn force_finish_turn(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
             let who: AccountIdOf<T> = ensure_signed(origin)?;
 
@@ -644,6 +1159,10 @@ pub mod pallet {
 
             // Ensure the caller is a player in the game
             ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
+            ensure!(
+                matches!(game.state, GameState::Playing),
+                Error::<T>::GameNotPlaying
+            );
 
             // Ensure the caller is not the current player
             let current_player = game.players[game.player_turn as usize].clone();
@@ -681,6 +1200,7 @@ pub mod pallet {
 
             // Persist updated game state before emitting events
             GameStorage::<T>::insert(&game_id, game.clone());
+            Self::schedule_turn_timeout(&game_id, current_block);
 
             // Emit events
             let next_player = game.players[game.player_turn as usize].clone();
@@ -699,7 +1219,7 @@ pub mod pallet {
         /// Save/update your "current hand" (card IDs only) that the UI will use for future games.
         /// The hand must contain exactly `HandSize` unique cards owned by the caller.
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_current_hand())]
         pub fn set_current_hand(origin: OriginFor<T>, card_ids: Vec<u32>) -> DispatchResult {
             let who: AccountIdOf<T> = ensure_signed(origin)?;
 
@@ -733,213 +1253,475 @@ pub mod pallet {
 
         /// Deprecated alias for backwards compatibility. Calls `set_current_hand`.
         #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_preset_hand())]
         pub fn set_preset_hand(origin: OriginFor<T>, card_ids: Vec<u32>) -> DispatchResult {
             Self::set_current_hand(origin, card_ids)
         }
-    }
-}
 
-// Helper methods
-impl<T: Config> Pallet<T> {
-    /// Create a PvP game between two accounts without a signed origin.
-    /// Intended to be called from the matchmaking pallet via the `GameCreator` trait.
-    fn do_create_pvp_game(
-        a: &AccountIdOf<T>,
-        b: &AccountIdOf<T>,
-    ) -> Result<GameId<T>, sp_runtime::DispatchError> {
-        use sp_runtime::traits::SaturatedConversion;
+        /// Concede the game. The opponent is recorded as the winner.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::resign())]
+        pub fn resign(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let game = Self::ensure_playing_participant(&game_id, &who)?;
 
-        // Sanity checks
-        ensure!(a != b, Error::<T>::InvalidMove);
-        ensure!(
-            T::NumPlayers::get() == 2,
-            Error::<T>::InvalidNumberOfPlayers
-        );
+            let opponent = game
+                .players
+                .iter()
+                .find(|p| **p != who)
+                .cloned()
+                .ok_or(Error::<T>::InternalError)?;
 
-        // Both players must have a preset/current hand (defense in depth; the matchmaker checks this too)
-        ensure!(
-            CurrentHandOf::<T>::contains_key(a),
-            Error::<T>::PresetHandMissing
-        );
-        ensure!(
-            CurrentHandOf::<T>::contains_key(b),
-            Error::<T>::PresetHandMissing
-        );
+            Self::deposit_event(Event::GameResigned {
+                game_id,
+                player: who,
+            });
+            Self::end_game(&game_id, Some(opponent));
+            Ok(())
+        }
 
-        // Neither is currently in another game
-        ensure!(
-            ActiveGameOf::<T>::get(a).is_none(),
-            Error::<T>::PlayerAlreadyInGame
-        );
-        ensure!(
-            ActiveGameOf::<T>::get(b).is_none(),
-            Error::<T>::PlayerAlreadyInGame
-        );
+        /// Offer a draw in a PvP game. Replaces any earlier offer for this game.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::offer_draw())]
+        pub fn offer_draw(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            Self::ensure_playing_participant(&game_id, &who)?;
+            ensure!(
+                matches!(GameModes::<T>::get(&game_id), Some(GameMode::PvP)),
+                Error::<T>::NotPvPGame
+            );
 
-        // Create a deterministic game id from (a,b,block)
-        let current_block_number = <frame_system::Pallet<T>>::block_number();
-        let game_id = T::Hashing::hash_of(&(a.clone(), b.clone(), current_block_number));
+            DrawOffers::<T>::insert(&game_id, &who);
+            Self::deposit_event(Event::DrawOffered {
+                game_id,
+                player: who,
+            });
+            Ok(())
+        }
 
-        // Collision check (extremely unlikely)
-        ensure!(
-            !GameStorage::<T>::contains_key(&game_id),
-            Error::<T>::GameNotFound
-        );
+        /// Accept the opponent's pending draw offer, ending the game without a winner.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::accept_draw())]
+        pub fn accept_draw(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            Self::ensure_playing_participant(&game_id, &who)?;
 
-        // Build initial game struct
-        let initial_board: Board = Default::default();
-        let initial_scores = (5, 5);
-        let players_vec = sp_std::vec![a.clone(), b.clone()];
+            let offered_by = DrawOffers::<T>::get(&game_id).ok_or(Error::<T>::NoDrawOffer)?;
+            ensure!(offered_by != who, Error::<T>::CannotAcceptOwnDrawOffer);
 
-        let mut game: Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers> = Game {
-            state: GameState::Playing,
-            last_played_block: current_block_number,
-            players: players_vec
-                .clone()
-                .try_into()
-                .map_err(|_| Error::<T>::InternalError)?,
-            player_turn: 0,
-            round: 0,
-            max_rounds: T::MaxRounds::get(),
-            board: initial_board.clone(),
-            scores: initial_scores,
-        };
+            Self::deposit_event(Event::DrawAgreed { game_id });
+            Self::end_game(&game_id, None);
+            Ok(())
+        }
 
-        // Mark this as a PvP game and set active game markers
-        GameModes::<T>::insert(&game_id, GameMode::PvP);
-        ActiveGameOf::<T>::insert(a, game_id);
-        ActiveGameOf::<T>::insert(b, game_id);
+        /// Leave a PvE game early. The AI is recorded as the winner.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::abandon())]
+        pub fn abandon(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            Self::ensure_playing_participant(&game_id, &who)?;
+            ensure!(
+                matches!(GameModes::<T>::get(&game_id), Some(GameMode::PvE)),
+                Error::<T>::NotPvEGame
+            );
 
-        // Push into recent lists for each player (most-recent first, bounded to 10)
-        let mut push_recent = |acct: &AccountIdOf<T>| {
-            PlayerGames::<T>::mutate(acct, |list| {
-                if let Some(pos) = list.iter().position(|g| *g == game_id) {
-                    list.remove(pos);
-                }
-                if list.len() as u32 >= <ConstU32<10> as sp_runtime::traits::Get<u32>>::get() {
-                    let _ = list.pop();
-                }
-                let mut tmp = list.to_vec();
-                tmp.insert(0, game_id);
-                *list = BoundedVec::try_from(tmp).expect("<= 10; qed");
+            Self::deposit_event(Event::GameAbandoned {
+                game_id,
+                player: who,
+            });
+            Self::end_game(&game_id, Some(T::AiAccount::get()));
+            Ok(())
+        }
+
+        /// Match the creator's stake on a pending wager, starting the game.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::accept_wager())]
+        pub fn accept_wager(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let mut game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(
+                matches!(game.state, GameState::Matchmaking),
+                Error::<T>::NotPendingWager
+            );
+            ensure!(
+                game.players.get(1) == Some(&who),
+                Error::<T>::NotWagerOpponent
+            );
+            let stake = GameStakes::<T>::get(&game_id).ok_or(Error::<T>::NotPendingWager)?;
+
+            T::StakeCurrency::hold(&HoldReason::GameStake.into(), &who, stake)
+                .map_err(|_| Error::<T>::InsufficientStake)?;
+
+            Self::request_deal(&game_id, &mut game)?;
+            GameStorage::<T>::insert(&game_id, game);
+            Self::deposit_event(Event::WagerAccepted { game_id });
+            Ok(())
+        }
+
+        /// Call off a pending wager. Either player may do this before it is accepted;
+        /// the creator's stake is released and the game is removed.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::decline_wager())]
+        pub fn decline_wager(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
+            ensure!(
+                matches!(game.state, GameState::Matchmaking),
+                Error::<T>::NotPendingWager
+            );
+
+            if let Some(stake) = GameStakes::<T>::take(&game_id) {
+                let _ = T::StakeCurrency::release(
+                    &HoldReason::GameStake.into(),
+                    &game.players[0],
+                    stake,
+                    Precision::BestEffort,
+                );
+            }
+            Self::settle_trade(&game_id, &game, None);
+            Self::release_hand_locks(&game_id, &game);
+            // A declined wager never happened, so it leaves no trace in the recent lists
+            for p in game.players.iter() {
+                ActiveGameOf::<T>::remove(p);
+                PlayerGames::<T>::mutate(p, |list| list.retain(|g| *g != game_id));
+            }
+            Self::unschedule_turn_timeout(&game_id, game.last_played_block);
+            Self::clear_game_state(&game_id);
+
+            Self::deposit_event(Event::WagerDeclined {
+                game_id,
+                player: who,
+            });
+            Ok(())
+        }
+
+        /// Commit to a hidden hand with one [`Pallet::hand_commitment`] per slot, `HandSize`
+        /// in all. Cards are checked and locked only as `play_from_hand` reveals them, so a
+        /// card sold in the meantime can't be played.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::commit_hand())]
+        pub fn commit_hand(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            commitments: Vec<T::Hash>,
+        ) -> DispatchResult {
+            let who: AccountIdOf<T> = ensure_signed(origin)?;
+            let game = GameStorage::<T>::get(&game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(game.players.contains(&who), Error::<T>::PlayerNotInGame);
+            ensure!(
+                GameHandVisibility::<T>::contains_key(&game_id),
+                Error::<T>::HandIsOpen
+            );
+            ensure!(
+                !HandCommitments::<T>::contains_key(&game_id, &who),
+                Error::<T>::HandAlreadySubmitted
+            );
+            // Once a card is on the board the opponent may have seen it, so commitments close
+            ensure!(
+                !matches!(game.state, GameState::Finished { .. })
+                    && MoveLog::<T>::decode_len(&game_id).unwrap_or(0) == 0,
+                Error::<T>::CommitPhaseOver
+            );
+            ensure!(
+                commitments.len() as u32 == T::HandSize::get(),
+                Error::<T>::HandSizeInvalid
+            );
+            let slots: BoundedVec<Option<T::Hash>, HandLimit> = commitments
+                .into_iter()
+                .map(Some)
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| Error::<T>::HandSizeInvalid)?;
+
+            HandCommitments::<T>::insert(&game_id, &who, slots);
+            Self::deposit_event(Event::HandSubmitted {
+                game_id,
+                player: who,
+            });
+            Ok(())
+        }
+    }
+}
+
+// Helper methods
+impl<T: Config> Pallet<T> {
+    /// Stake each player of a matchmade game puts up: the queue's, but at least
+    /// `MatchmakingStake`.
+    fn matchmaking_stake(settings: &MatchSettings<BalanceOf<T>>) -> BalanceOf<T> {
+        settings.stake.max(T::MatchmakingStake::get())
+    }
+
+    /// Checks `do_create_pvp_game` makes of each player on their own: a preset hand, no game
+    /// in progress and enough free balance for `stake`.
+    fn ensure_can_be_matched(
+        who: &AccountIdOf<T>,
+        stake: BalanceOf<T>,
+    ) -> sp_runtime::DispatchResult {
+        ensure!(
+            CurrentHandOf::<T>::contains_key(who),
+            Error::<T>::PresetHandMissing
+        );
+        ensure!(
+            ActiveGameOf::<T>::get(who).is_none(),
+            Error::<T>::PlayerAlreadyInGame
+        );
+        ensure!(
+            stake.is_zero()
+                || T::StakeCurrency::can_hold(&HoldReason::GameStake.into(), who, stake),
+            Error::<T>::InsufficientStake
+        );
+        Ok(())
+    }
+
+    /// Create a PvP game between two accounts without a signed origin.
+    /// Intended to be called from the matchmaking pallet via the `GameCreator` trait.
+    fn do_create_pvp_game(
+        a: &AccountIdOf<T>,
+        b: &AccountIdOf<T>,
+        settings: &MatchSettings<BalanceOf<T>>,
+    ) -> Result<GameId<T>, sp_runtime::DispatchError> {
+        use sp_runtime::traits::SaturatedConversion;
+
+        // Sanity checks
+        ensure!(a != b, Error::<T>::InvalidMove);
+        ensure!(
+            T::NumPlayers::get() == 2,
+            Error::<T>::InvalidNumberOfPlayers
+        );
+        let board_size = settings.board_size;
+        ensure!(board_size.is_valid(), Error::<T>::InvalidBoardSize);
+        let hidden = settings.hand_visibility == HandVisibility::Hidden;
+        ensure!(
+            !hidden || settings.trade_rule == TradeRule::None,
+            Error::<T>::TradeRequiresOpenHands
+        );
+
+        // Both players must have a preset/current hand (defense in depth; the matchmaker checks this too)
+        ensure!(
+            CurrentHandOf::<T>::contains_key(a),
+            Error::<T>::PresetHandMissing
+        );
+        ensure!(
+            CurrentHandOf::<T>::contains_key(b),
+            Error::<T>::PresetHandMissing
+        );
+
+        // Neither is currently in another game
+        ensure!(
+            ActiveGameOf::<T>::get(a).is_none(),
+            Error::<T>::PlayerAlreadyInGame
+        );
+        ensure!(
+            ActiveGameOf::<T>::get(b).is_none(),
+            Error::<T>::PlayerAlreadyInGame
+        );
+
+        // Create a deterministic game id from (a,b,block)
+        let current_block_number = <frame_system::Pallet<T>>::block_number();
+        let game_id = T::Hashing::hash_of(&(a.clone(), b.clone(), current_block_number));
+
+        // Collision check (extremely unlikely)
+        ensure!(
+            !GameStorage::<T>::contains_key(&game_id),
+            Error::<T>::GameNotFound
+        );
+
+        // Escrow the stake from both players; undo `a`'s hold if `b` can't pay
+        let stake = Self::matchmaking_stake(settings);
+        if !stake.is_zero() {
+            let reason = HoldReason::GameStake.into();
+            T::StakeCurrency::hold(&reason, a, stake).map_err(|_| Error::<T>::InsufficientStake)?;
+            if T::StakeCurrency::hold(&reason, b, stake).is_err() {
+                let _ = T::StakeCurrency::release(&reason, a, stake, Precision::BestEffort);
+                return Err(Error::<T>::InsufficientStake.into());
+            }
+            GameStakes::<T>::insert(&game_id, stake);
+        }
+
+        // Build initial game struct
+        let initial_board = Board::new(board_size);
+        let initial_scores = Self::initial_scores();
+        let players_vec = sp_std::vec![a.clone(), b.clone()];
+
+        let mut game: Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers> = Game {
+            state: GameState::Playing,
+            last_played_block: current_block_number,
+            players: players_vec
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InternalError)?,
+            player_turn: 0,
+            round: 0,
+            max_rounds: Self::max_rounds_for(&board_size),
+            board: initial_board.clone(),
+            scores: initial_scores,
+        };
+
+        // Mark this as a PvP game with the queue's settings and set active game markers
+        GameModes::<T>::insert(&game_id, GameMode::PvP);
+        Self::store_rules(&game_id, settings.rules);
+        if settings.trade_rule != TradeRule::None {
+            GameTradeRules::<T>::insert(&game_id, settings.trade_rule);
+        }
+        if hidden {
+            GameHandVisibility::<T>::insert(&game_id, settings.hand_visibility);
+        }
+        if settings.casual {
+            CasualGames::<T>::insert(&game_id, ());
+        }
+        ActiveGameOf::<T>::insert(a, game_id);
+        ActiveGameOf::<T>::insert(b, game_id);
+
+        // Push into recent lists for each player (most-recent first, bounded to 10)
+        let mut push_recent = |acct: &AccountIdOf<T>| {
+            PlayerGames::<T>::mutate(acct, |list| {
+                if let Some(pos) = list.iter().position(|g| *g == game_id) {
+                    list.remove(pos);
+                }
+                if list.len() as u32 >= <ConstU32<10> as sp_runtime::traits::Get<u32>>::get() {
+                    let _ = list.pop();
+                }
+                let mut tmp = list.to_vec();
+                tmp.insert(0, game_id);
+                *list = BoundedVec::try_from(tmp).expect("<= 10; qed");
             });
         };
         push_recent(a);
         push_recent(b);
 
-        // Randomize starting player using `a` as seed (keep behavior similar to create_game PvP)
-        game.set_player_turn(if sp_io::hashing::blake2_128(&a.encode())[0] % 2 == 0 {
-            0
-        } else {
-            1
-        });
+        // The starting player is drawn when the game is dealt, as for create_game PvP
+        Self::request_deal(&game_id, &mut game)?;
 
         GameStorage::<T>::insert(&game_id, game.clone());
-        Self::deposit_event(Event::GameCreated { game_id });
+        Self::deposit_event(Event::GameCreated {
+            game_id,
+            ai_profile: None,
+        });
 
         Ok(game_id)
     }
-    fn map_card_to_ai(c: &Card) -> ai::Card {
-        ai::Card {
-            top: c.top,
-            right: c.right,
-            bottom: c.bottom,
-            left: c.left,
-            possession: c.possession.as_ref().map(|p| match p {
-                Player::PlayerOne => ai::Possession::PlayerOne,
-                Player::PlayerTwo => ai::Possession::PlayerTwo,
-            }),
-        }
-    }
     /// If the next player is the AI in a PvE game, let the AI take its move immediately.
+    /// Returns whether the AI played.
     fn maybe_ai_take_turn(
         game_id: &GameId<T>,
         game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
-    ) {
+    ) -> bool {
         // Only PvE
         if !matches!(GameModes::<T>::get(game_id), Some(GameMode::PvE)) {
-            return;
+            return false;
         }
         let ai_acc = T::AiAccount::get();
         let turn_acc = game.players[game.get_player_turn() as usize].clone();
         if turn_acc != ai_acc {
-            return;
+            return false;
+        }
+
+        // The offchain worker submits searched moves through `mc_ai::Call::submit_ai_move`.
+        let profile = Self::ai_profile(game_id);
+        if T::AiMovesOffchain::get() && profile.searches() {
+            return false;
         }
 
         // Build AI adapter state from on-chain state
         let state = match Self::build_ai_state(game_id, game) {
             Some(s) => s,
-            None => return,
+            None => return false,
         };
-        let diff = T::AiDifficulty::get();
-
-        if let Some(action) = mc_ai::pallet::Pallet::<T>::suggest::<ai::Adapter>(&state, diff) {
-            let x = action.x;
-            let y = action.y;
-            let idx = action.hand_index as usize;
-
-            // Play as AI (mirror play_from_hand)
-            if let Some(mut ai_hand) = HandsOfGame::<T>::get(game_id, &ai_acc) {
-                // Safely access the chosen hand entry without using idx < len comparisons
-                if let Some(slot) = ai_hand.get_mut(idx) {
-                    let xi: usize = x as usize;
-                    let yi: usize = y as usize;
-                    if !slot.used {
-                        if let Some(col) = game.board.get(xi) {
-                            if let Some(cell) = col.get(yi) {
-                                if cell.is_none() {
-                                    let h = slot.clone();
-                                    let placed = Card {
-                                        top: h.north,
-                                        right: h.east,
-                                        bottom: h.south,
-                                        left: h.west,
-                                        possession: None,
-                                    };
-                                    let mv = Move {
-                                        place_card: placed,
-                                        place_index_x: x,
-                                        place_index_y: y,
-                                    };
-
-                                    let player_ix = Self::get_current_player_index(game, &ai_acc);
-                                    Self::place_card_on_board(game, &mv, player_ix);
-                                    Self::apply_capture_logic(game, &mv, player_ix);
-
-                                    slot.used = true;
-                                    HandsOfGame::<T>::insert(game_id, &ai_acc, ai_hand);
-
-                                    let current_block = <frame_system::Pallet<T>>::block_number();
-                                    game.last_played_block = current_block;
-                                    game.next_turn();
-
-                                    let next_player =
-                                        game.players[game.get_player_turn() as usize].clone();
-                                    Self::deposit_event(Event::NewTurn {
-                                        game_id: *game_id,
-                                        next_player,
-                                    });
-                                    GameStorage::<T>::insert(game_id, game.clone());
-
-                                    if let Some(winner) = Self::is_game_won(game_id, game) {
-                                        Self::end_game(game_id, winner);
-                                        return;
-                                    }
-
-                                    Self::deposit_event(Event::MovePlayed {
-                                        game_id: *game_id,
-                                        player: ai_acc,
-                                        x,
-                                        y,
-                                    });
-                                }
-                            }
-                        }
-                    }
-                }
+
+        // Seeded like the offchain worker's searches, so anyone can replay the AI's move.
+        let seed = mc_ai::Pallet::<T>::seed_for(
+            &<T as frame_system::Config>::Hashing::hash_of(&state),
+            Some(game_id),
+            frame_system::Pallet::<T>::block_number(),
+        );
+        let action = match profile {
+            AiProfile::Novice => mc_ai::Pallet::<T>::random_move::<ai::Adapter>(&state, seed),
+            AiProfile::Greedy => mc_ai::Pallet::<T>::greedy_move::<ai::Adapter>(&state),
+            AiProfile::MonteCarlo(difficulty) => {
+                mc_ai::Pallet::<T>::suggest::<ai::Adapter>(&state, difficulty, seed)
             }
+            AiProfile::Solver => {
+                mc_ai::Pallet::<T>::solve_or_suggest::<ai::Adapter>(&state, 100, seed)
+            }
+        };
+        action.is_some_and(|action| Self::play_ai_action(game_id, game, &action).is_ok())
+    }
+
+    /// AI opponent of a PvE game. Games from before profiles play Monte-Carlo at `AiDifficulty`.
+    fn ai_profile(game_id: &GameId<T>) -> AiProfile {
+        GameAiProfiles::<T>::get(game_id).unwrap_or(AiProfile::MonteCarlo(T::AiDifficulty::get()))
+    }
+
+    /// Play `action` for the AI, with the same checks `play_from_hand` applies to players.
+    fn play_ai_action(
+        game_id: &GameId<T>,
+        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        action: &ai::Action,
+    ) -> Result<(), Error<T>> {
+        let ai_acc = T::AiAccount::get();
+        let (x, y) = (action.x, action.y);
+        let idx = action.hand_index as usize;
+        ensure!(
+            game.board.in_bounds(x as usize, y as usize),
+            Error::<T>::InvalidMove
+        );
+        ensure!(
+            game.board[x as usize][y as usize].is_none(),
+            Error::<T>::CellOccupied
+        );
+        let mut ai_hand =
+            HandsOfGame::<T>::get(game_id, &ai_acc).ok_or(Error::<T>::HandNotSubmitted)?;
+        ensure!(idx < ai_hand.len(), Error::<T>::HandIndexOutOfRange);
+        ensure!(!ai_hand[idx].used, Error::<T>::CardAlreadyUsed);
+
+        // Play as AI (mirror play_from_hand)
+        let h = ai_hand[idx].clone();
+        let placed = Card {
+            top: h.north,
+            right: h.east,
+            bottom: h.south,
+            left: h.west,
+            possession: None,
+        };
+        let mv = Move {
+            place_card: placed,
+            place_index_x: x,
+            place_index_y: y,
+        };
+
+        let player_ix = Self::get_current_player_index(game, &ai_acc);
+        Self::place_card_on_board(game, &mv, player_ix);
+        let flipped = Self::apply_capture_logic(game_id, game, &mv, player_ix);
+        Self::record_move(game_id, player_ix, Some(action.hand_index), &mv, flipped);
+
+        ai_hand[idx].used = true;
+        HandsOfGame::<T>::insert(game_id, &ai_acc, ai_hand);
+
+        let current_block = <frame_system::Pallet<T>>::block_number();
+        game.last_played_block = current_block;
+        game.next_turn();
+
+        let next_player = game.players[game.get_player_turn() as usize].clone();
+        Self::deposit_event(Event::NewTurn {
+            game_id: *game_id,
+            next_player,
+        });
+        GameStorage::<T>::insert(game_id, game.clone());
+
+        if let Some(winner) = Self::is_game_won(game_id, game) {
+            Self::end_game(game_id, winner);
+            return Ok(());
         }
+        Self::schedule_turn_timeout(game_id, current_block);
+
+        Self::deposit_event(Event::MovePlayed {
+            game_id: *game_id,
+            player: ai_acc,
+            x,
+            y,
+        });
+        Ok(())
     }
 
     fn build_ai_state(
@@ -952,104 +1734,97 @@ impl<T: Config> Pallet<T> {
         let hand1 = HandsOfGame::<T>::get(game_id, &p1)?;
 
         let map_hand = |h: &BoundedVec<HandEntry, HandLimit>| -> ai::Hand {
-            let mut arr: [ai::HandEntry; 5] = core::array::from_fn(|_| ai::HandEntry {
-                north: 1,
-                east: 1,
-                south: 1,
-                west: 1,
-                used: true,
-            });
-            for (i, he) in h.iter().enumerate().take(5) {
-                arr[i] = ai::HandEntry {
+            let entries: Vec<ai::HandEntry> = h
+                .iter()
+                .map(|he| ai::HandEntry {
                     north: he.north,
                     east: he.east,
                     south: he.south,
                     west: he.west,
                     used: he.used,
-                };
-            }
-            ai::Hand { entries: arr }
+                })
+                .collect();
+            ai::Hand::new(&entries)
         };
 
         let hands = [map_hand(&hand0), map_hand(&hand1)];
 
-        // Map on-chain board (card::Card) to adapter board (ai::Card)
-        let mut board_ai: [[Option<ai::Card>; 4]; 4] =
-            core::array::from_fn(|_| core::array::from_fn(|_| None));
-        for x in 0..4 {
-            for y in 0..4 {
-                if let Some(ref c) = game.board[x][y] {
-                    board_ai[x][y] = Some(Self::map_card_to_ai(c));
-                }
-            }
-        }
-
         Some(ai::State {
-            board: board_ai,
+            board: game.board.clone(),
             scores: game.scores,
             player_turn: game.player_turn,
             round: game.round,
             max_rounds: game.max_rounds,
             hands,
+            rules: GameRules::<T>::get(game_id),
+            elements: GameElements::<T>::get(game_id).unwrap_or_default(),
         })
     }
 
-    /// Build an AI hand whose average ranks are slightly below the human's submitted hand.
+    /// Build the AI's hand against the human's submitted hand, each profile its own way:
+    /// - `Novice` draws every rank uniformly from 1..=4.
+    /// - `Greedy` gets lopsided cards, one edge two ranks above the human's average and
+    ///   the others two below, so it always has something to flip with.
+    /// - `MonteCarlo(d)` sits around the human's average, up to two ranks below it at
+    ///   difficulty 0 and level with it at 100.
+    /// - `Solver` mirrors the human's hand.
     fn generate_ai_hand_for_game(
         game_id: &GameId<T>,
         human: &T::AccountId,
+        profile: AiProfile,
     ) -> Option<BoundedVec<HandEntry, HandLimit>> {
         let human_hand = HandsOfGame::<T>::get(game_id, human)?;
-        let mut sum: u32 = 0;
-        for h in human_hand.iter() {
-            sum += h.north as u32 + h.east as u32 + h.south as u32 + h.west as u32;
-        }
-        let avg = (sum as f32) / ((human_hand.len() as f32) * 4.0);
-        let target = (avg - 0.5).max(1.0); // slightly easier than human
+        let edges = |h: &HandEntry| [h.north, h.east, h.south, h.west];
+        // The human's average rank, in tenths.
+        let sum: u32 = human_hand.iter().flat_map(edges).map(u32::from).sum();
+        let avg = (sum * 10 / (human_hand.len() as u32 * 4).max(1)) as i32;
 
-        // Deterministic pseudo-randomization from (game_id, human)
-        let seed_hash = <T as frame_system::Config>::Hashing::hash_of(&(game_id, human));
+        // Randomization seeded per (game_id, human)
+        let seed_hash = Self::random_hash((b"eterra/ai-hand", game_id, human));
         let bytes = seed_hash.as_ref();
+        let byte = |i: usize| bytes.get(i % bytes.len().max(1)).copied().unwrap_or(0);
+        // Round a rank given in tenths to the nearest one in 1..=9.
+        let rank = |tenths: i32| ((tenths + 5) / 10).clamp(1, 9) as u8;
+        let entry = |[north, east, south, west]: [u8; 4]| HandEntry {
+            card_id: 0,
+            north,
+            east,
+            south,
+            west,
+            used: false,
+        };
 
-        let mut mk_val = |i: usize| -> u8 {
-            // clamp to [1.0, 9.0] then round without relying on FloatCore/round
-            let jitter = ((bytes.get(i % bytes.len()).copied().unwrap_or(0) as i8 % 3) - 1) as f32;
-            let mut base = target + jitter;
-            if base < 1.0 {
-                base = 1.0;
-            }
-            if base > 9.0 {
-                base = 9.0;
-            }
-            let b_int = base as i32;
-            let frac = base - (b_int as f32);
-            let mut clamped = if frac >= 0.5 {
-                (b_int + 1) as u8
-            } else {
-                b_int as u8
-            };
-            if clamped < 1 {
-                clamped = 1;
-            }
-            if clamped > 9 {
-                clamped = 9;
+        let cards = 0..T::HandSize::get() as usize;
+        let hand: Vec<HandEntry> = match profile {
+            AiProfile::Novice => cards
+                .map(|i| entry(core::array::from_fn(|e| byte(i * 4 + e) % 4 + 1)))
+                .collect(),
+            AiProfile::Greedy => cards
+                .map(|i| {
+                    let strong = byte(i) as usize % 4;
+                    entry(core::array::from_fn(|e| {
+                        if e == strong {
+                            rank(avg + 20)
+                        } else {
+                            rank(avg - 20)
+                        }
+                    }))
+                })
+                .collect(),
+            AiProfile::MonteCarlo(difficulty) => {
+                let target = avg - (100 - i32::from(difficulty)) / 5;
+                cards
+                    .map(|i| {
+                        entry(core::array::from_fn(|e| {
+                            // Jitter each rank by one either way.
+                            rank(target + (i32::from(byte(i * 4 + e) % 3) - 1) * 10)
+                        }))
+                    })
+                    .collect()
             }
-            clamped
+            AiProfile::Solver => human_hand.iter().map(|h| entry(edges(h))).collect(),
         };
-
-        let mut out: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
-        for i in 0..HandLimit::get() {
-            let e = HandEntry {
-                card_id: 0,
-                north: mk_val(i as usize),
-                east: mk_val(i as usize + 1),
-                south: mk_val(i as usize + 2),
-                west: mk_val(i as usize + 3),
-                used: false,
-            };
-            let _ = out.try_push(e);
-        }
-        Some(out)
+        BoundedVec::try_from(hand).ok()
     }
 
     fn is_game_won(
@@ -1071,8 +1846,8 @@ impl<T: Config> Pallet<T> {
             return None;
         }
 
-        if game.round >= game.max_rounds {
-            log::debug!("Max rounds reached. Determining winner...");
+        if game.round >= game.max_rounds || game.board.is_full() {
+            log::debug!("Max rounds reached or board full. Determining winner...");
         } else {
             log::debug!("Game continues. Not at max rounds yet.");
             return None;
@@ -1097,22 +1872,64 @@ impl<T: Config> Pallet<T> {
 
         Some(winner)
     }
+    /// Load a game that is still being played and that `who` takes part in.
+    fn ensure_playing_participant(
+        game_id: &GameId<T>,
+        who: &AccountIdOf<T>,
+    ) -> Result<Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>, Error<T>> {
+        let game = GameStorage::<T>::get(game_id).ok_or(Error::<T>::GameNotFound)?;
+        ensure!(game.players.contains(who), Error::<T>::PlayerNotInGame);
+        ensure!(
+            matches!(game.state, GameState::Playing),
+            Error::<T>::GameNotPlaying
+        );
+        Ok(game)
+    }
+
     fn validate_player_turn(
         game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
         who: &AccountIdOf<T>,
     ) -> Result<(), Error<T>> {
+        ensure!(
+            matches!(game.state, GameState::Playing),
+            Error::<T>::GameNotPlaying
+        );
         let current_turn_index = game.get_player_turn();
         let current_turn = game.players[current_turn_index as usize].clone();
         ensure!(current_turn == *who, Error::<T>::NotYourTurn);
         Ok(())
     }
 
+    /// `play` takes the card's stats from the caller, so it is only open to games where
+    /// nothing rides on the cards: no stake, no ante and no hand locked in by a player.
+    fn validate_free_play(
+        game_id: &GameId<T>,
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) -> Result<(), Error<T>> {
+        let ai = T::AiAccount::get();
+        let hand_locked = game
+            .players
+            .iter()
+            .any(|p| *p != ai && HandsOfGame::<T>::contains_key(game_id, p));
+        ensure!(
+            !hand_locked
+                && !GameStakes::<T>::contains_key(game_id)
+                && !GameTradeRules::<T>::contains_key(game_id)
+                && !GameHandVisibility::<T>::contains_key(game_id),
+            Error::<T>::PlayFromHandRequired
+        );
+        Ok(())
+    }
+
     fn validate_move(
         game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
         player_move: &Move,
     ) -> Result<(), Error<T>> {
         ensure!(
-            player_move.place_index_x < 4 && player_move.place_index_y < 4,
+            game.board.in_bounds(
+                player_move.place_index_x as usize,
+                player_move.place_index_y as usize
+            ),
             Error::<T>::InvalidMove
         );
         ensure!(
@@ -1120,6 +1937,10 @@ impl<T: Config> Pallet<T> {
                 .is_none(),
             Error::<T>::CellOccupied
         );
+        ensure!(
+            player_move.place_card.has_valid_ranks(),
+            Error::<T>::InvalidCardRank
+        );
         Ok(())
     }
 
@@ -1139,115 +1960,233 @@ impl<T: Config> Pallet<T> {
         player_move: &Move,
         player_ix: u8,
     ) {
-        let placed_card = player_move
-            .place_card
-            .clone()
-            .with_possession(match player_ix {
-                0 => Player::PlayerOne,
-                _ => Player::PlayerTwo,
-            });
-        game.board[player_move.place_index_x as usize][player_move.place_index_y as usize] =
-            Some(placed_card);
+        eterra_rules::place_card(
+            &mut game.board,
+            player_move.place_index_x as usize,
+            player_move.place_index_y as usize,
+            player_move.place_card.clone(),
+            player_ix,
+        );
     }
 
+    /// Resolve captures around a freshly placed card using the game's `RuleSet`.
+    /// Returns the flipped cells.
     fn apply_capture_logic(
+        game_id: &GameId<T>,
         game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
         player_move: &Move,
         player_ix: u8,
+    ) -> Vec<(u8, u8)> {
+        let rules = GameRules::<T>::get(game_id);
+        let elements = GameElements::<T>::get(game_id).unwrap_or_default();
+        let flipped = eterra_rules::resolve_captures(
+            &mut game.board,
+            &mut game.scores,
+            player_move.place_index_x as usize,
+            player_move.place_index_y as usize,
+            player_ix,
+            &rules,
+            &elements,
+        );
+        for (x, y) in flipped.iter() {
+            log::debug!("[Captured] ({},{}) now owned by player {}", x, y, player_ix);
+        }
+        flipped
+    }
+
+    /// Append a placement to the game's move log.
+    fn record_move(
+        game_id: &GameId<T>,
+        player_ix: u8,
+        hand_index: Option<u8>,
+        mv: &Move,
+        flipped: Vec<(u8, u8)>,
     ) {
-        // For each of the 4 orthogonal directions, compare the placed card's edge
-        // against the opposite edge of the neighboring card. Capture only if:
-        //  - There is a card
-        //  - It is owned by the opponent
-        //  - Our edge strictly beats their opposing edge (ties do NOT capture)
-        for &(dx, dy, my_rank) in &[
-            (0, -1, player_move.place_card.top), // Top: compare vs neighbor's bottom
-            (1, 0, player_move.place_card.right), // Right: compare vs neighbor's left
-            (0, 1, player_move.place_card.bottom), // Bottom: compare vs neighbor's top
-            (-1, 0, player_move.place_card.left), // Left: compare vs neighbor's right
-        ] {
-            let nx = player_move.place_index_x as isize + dx;
-            let ny = player_move.place_index_y as isize + dy;
-            if nx < 0 || nx >= 4 || ny < 0 || ny >= 4 {
-                continue;
+        let mut card = mv.place_card.clone();
+        card.possession = None;
+        let record = MoveRecord {
+            player: player_ix,
+            hand_index,
+            card,
+            x: mv.place_index_x,
+            y: mv.place_index_y,
+            // A placement flips at most the other cells of the board, so this never truncates.
+            flipped: BoundedVec::truncate_from(flipped),
+        };
+        MoveLog::<T>::mutate(game_id, |log| {
+            // One entry per cell, so a game can never outgrow the log.
+            if log.try_push(record).is_err() {
+                log::warn!("Move log full for game {:?}", game_id);
             }
+        });
+    }
 
-            let xi = nx as usize;
-            let yi = ny as usize;
+    /// Rounds needed to play out a board: capped by `MaxRounds`, the hand size and
+    /// the number of cells each player can fill.
+    fn max_rounds_for(size: &BoardSize) -> u8 {
+        let per_player = size.cells().div_ceil(2);
+        let rounds = T::HandSize::get().min(per_player);
+        T::MaxRounds::get().min(rounds.saturated_into::<u8>())
+    }
 
-            if let Some(mut neighbor) = game.board[xi][yi].clone() {
-                // Only attempt to capture if the neighbor is owned by the opponent
-                let is_opponent_owned = match (neighbor.possession.as_ref(), player_ix) {
-                    (Some(Player::PlayerOne), 1) => true,
-                    (Some(Player::PlayerTwo), 0) => true,
-                    _ => false,
-                };
-                if !is_opponent_owned {
-                    continue;
-                }
+    /// Starting scores: each player owns their whole hand before any card is placed.
+    fn initial_scores() -> (u8, u8) {
+        let cards = T::HandSize::get().saturated_into::<u8>();
+        (cards, cards)
+    }
 
-                // Determine neighbor's opposing edge rank based on direction
-                let opp_rank = match (dx, dy) {
-                    (0, -1) => neighbor.bottom,
-                    (1, 0) => neighbor.left,
-                    (0, 1) => neighbor.top,
-                    (-1, 0) => neighbor.right,
-                    _ => 0,
-                };
+    /// Output of the shared randomness source for `subject`, prefixed with a context string by
+    /// callers so different draws in the same block stay independent.
+    fn random_hash(subject: impl Encode) -> T::Hash {
+        <T as Config>::Randomness::random(&subject.encode()).0
+    }
 
-                log::debug!(
-                    "[CaptureCheck] at ({},{}) vs neighbor ({},{}): my_edge={}, opp_edge={}",
-                    player_move.place_index_x,
-                    player_move.place_index_y,
-                    xi,
-                    yi,
-                    my_rank,
-                    opp_rank
-                );
+    /// What `commit_hand` expects for `card_id` in a hidden hand. Binding the game and the
+    /// player keeps a commitment from being replayed elsewhere; `salt` must stay secret
+    /// until the card is played.
+    pub fn hand_commitment(
+        game_id: &GameId<T>,
+        who: &AccountIdOf<T>,
+        card_id: u32,
+        salt: &[u8; 32],
+    ) -> T::Hash {
+        <T as frame_system::Config>::Hashing::hash_of(&(game_id, who, card_id, salt))
+    }
 
-                // Strictly greater captures; ties do not capture
-                if my_rank > opp_rank {
-                    // Adjust scores: remove point from previous owner (opponent), give to current
-                    match neighbor.possession.as_ref() {
-                        Some(Player::PlayerOne) => {
-                            game.scores.0 = game.scores.0.saturating_sub(1);
-                        }
-                        Some(Player::PlayerTwo) => {
-                            game.scores.1 = game.scores.1.saturating_sub(1);
-                        }
-                        _ => {}
-                    }
+    /// Open `slot` of `who`'s hidden hand: check `reveal` against its commitment, lock the
+    /// card and append it to `HandsOfGame`. Returns the card's index there.
+    fn reveal_card(
+        game_id: &GameId<T>,
+        who: &AccountIdOf<T>,
+        slot: u8,
+        reveal: CardReveal,
+    ) -> Result<u8, sp_runtime::DispatchError> {
+        let mut slots =
+            HandCommitments::<T>::get(game_id, who).ok_or(Error::<T>::HandNotSubmitted)?;
+        let commitment = slots
+            .get_mut(slot as usize)
+            .ok_or(Error::<T>::HandIndexOutOfRange)?
+            .take()
+            .ok_or(Error::<T>::CardAlreadyUsed)?;
+        ensure!(
+            commitment == Self::hand_commitment(game_id, who, reveal.card_id, &reveal.salt),
+            Error::<T>::BadReveal
+        );
 
-                    match player_ix {
-                        0 => {
-                            game.scores.0 = game.scores.0.saturating_add(1);
-                            neighbor.possession = Some(Player::PlayerOne);
-                        }
-                        _ => {
-                            game.scores.1 = game.scores.1.saturating_add(1);
-                            neighbor.possession = Some(Player::PlayerTwo);
-                        }
-                    }
+        let info = pallet_eterra_simple_tcg::Cards::<T>::get(reveal.card_id)
+            .ok_or(Error::<T>::CardDoesNotExist)?;
+        ensure!(info.owner == *who, Error::<T>::CardNotOwned);
+        let mut hand = HandsOfGame::<T>::get(game_id, who).unwrap_or_default();
+        ensure!(
+            hand.iter().all(|e| e.card_id != reveal.card_id),
+            Error::<T>::DuplicateCardInHand
+        );
+        <pallet_eterra_simple_tcg::Pallet<T> as CardLocker>::lock(
+            reveal.card_id,
+            pallet_eterra_simple_tcg::LockReason::InGame,
+        )?;
+
+        let index = hand.len() as u8;
+        hand.try_push(HandEntry {
+            card_id: reveal.card_id,
+            north: info.north,
+            east: info.east,
+            south: info.south,
+            west: info.west,
+            used: false,
+        })
+        .map_err(|_| Error::<T>::HandSizeInvalid)?;
+        HandsOfGame::<T>::insert(game_id, who, hand);
+        HandCommitments::<T>::insert(game_id, who, slots);
+        Ok(index)
+    }
 
-                    log::debug!(
-                        "[Captured] neighbor ({},{}) now owned by player {}",
-                        xi,
-                        yi,
-                        player_ix
-                    );
+    /// Persist the rules for a new game. Elemental cells are rolled when the game is dealt.
+    fn store_rules(game_id: &GameId<T>, rules: RuleSet) {
+        GameRules::<T>::insert(game_id, rules);
+    }
 
-                    // Persist flipped neighbor back to the board
-                    game.board[xi][yi] = Some(neighbor);
+    /// Roll the elemental cells of `game_id`.
+    fn roll_elements(game_id: &GameId<T>) {
+        let seed = Self::random_hash((b"eterra/elements", game_id));
+        GameElements::<T>::insert(game_id, eterra_rules::elements_from_seed(seed.as_ref()));
+    }
+
+    /// Put `game` in `GameState::Dealing` until `deal_games` deals it from randomness later
+    /// than this block. A new request for a game replaces its earlier one.
+    fn request_deal(
+        game_id: &GameId<T>,
+        game: &mut Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) -> Result<(), Error<T>> {
+        let now = <frame_system::Pallet<T>>::block_number();
+        PendingDeals::<T>::try_mutate(|pending| {
+            pending.retain(|(g, _)| g != game_id);
+            pending.try_push((*game_id, now))
+        })
+        .map_err(|_| Error::<T>::TooManyPendingDeals)?;
+        game.set_state(GameState::Dealing);
+        Ok(())
+    }
+
+    /// Deal every pending game requested before `drawn`, the block the randomness source was
+    /// last refreshed in. Returns how many games were dealt.
+    pub fn deal_games(drawn: BlockNumberFor<T>) -> u32 {
+        let mut dealt = 0;
+        PendingDeals::<T>::mutate(|pending| {
+            pending.retain(|&(game_id, requested_at)| {
+                if requested_at >= drawn {
+                    return true;
                 }
+                Self::deal_game(&game_id);
+                dealt += 1;
+                false
+            });
+        });
+        dealt
+    }
+
+    /// Draw what `game_id` leaves to chance and start its first turn: the starting player of
+    /// PvP games, the elemental cells, and the AI's hand in PvE (fitted to the human's hand
+    /// once submitted).
+    fn deal_game(game_id: &GameId<T>) {
+        let mut game = match GameStorage::<T>::get(game_id) {
+            Some(g) if matches!(g.state, GameState::Dealing) => g,
+            _ => return,
+        };
+
+        if matches!(GameModes::<T>::get(game_id), Some(GameMode::PvE)) {
+            let ai_acc = T::AiAccount::get();
+            let human = game.players[0].clone();
+            let ai_hand = if HandsOfGame::<T>::contains_key(game_id, &human) {
+                Self::generate_ai_hand_for_game(game_id, &human, Self::ai_profile(game_id))
+            } else {
+                Self::generate_ai_hand_default(game_id)
+            };
+            if let Some(ai_hand) = ai_hand {
+                HandsOfGame::<T>::insert(game_id, &ai_acc, ai_hand);
             }
+        } else {
+            game.set_player_turn(Self::random_hash((b"eterra/start", game_id)).as_ref()[0] % 2);
+        }
+        if GameRules::<T>::get(game_id).elemental && !GameElements::<T>::contains_key(game_id) {
+            Self::roll_elements(game_id);
         }
+
+        let now = <frame_system::Pallet<T>>::block_number();
+        game.set_state(GameState::Playing);
+        game.last_played_block = now;
+        GameStorage::<T>::insert(game_id, game.clone());
+        Self::schedule_turn_timeout(game_id, now);
+        Self::deposit_event(Event::NewTurn {
+            game_id: *game_id,
+            next_player: game.players[game.get_player_turn() as usize].clone(),
+        });
     }
 
-    /// Create a default AI hand at game creation time so UI can display it even before human submits.
-    /// This hand uses deterministic pseudo-random stats (1..=9) derived from the game_id seed.
+    /// Deal a default AI hand so UI can display it even before human submits.
+    /// This hand uses random stats (1..=9) drawn for the game_id.
     fn generate_ai_hand_default(game_id: &GameId<T>) -> Option<BoundedVec<HandEntry, HandLimit>> {
-        // Derive bytes from the game_id itself for reproducible pseudo-randomness
-        let h = <T as frame_system::Config>::Hashing::hash_of(game_id);
+        let h = Self::random_hash((b"eterra/ai-hand", game_id));
         let bytes = h.as_ref();
         if bytes.is_empty() {
             return None;
@@ -1262,7 +2201,7 @@ impl<T: Config> Pallet<T> {
         };
 
         let mut out: BoundedVec<HandEntry, HandLimit> = BoundedVec::default();
-        for _ in 0..HandLimit::get() {
+        for _ in 0..T::HandSize::get() {
             let e = HandEntry {
                 card_id: 0,
                 north: next(),
@@ -1276,14 +2215,394 @@ impl<T: Config> Pallet<T> {
         Some(out)
     }
 
+    /// Schedule a timeout for the turn that started at `from`. If the deadline block is
+    /// already full, spill into the following blocks; `force_finish_turn` stays as the fallback.
+    fn schedule_turn_timeout(game_id: &GameId<T>, from: BlockNumberFor<T>) {
+        let limit: BlockNumberFor<T> = T::BlocksToPlayLimit::get().into();
+        let mut at = from.saturating_add(limit);
+        for _ in 0..MAX_SPILL_BLOCKS {
+            let scheduled = TurnTimeouts::<T>::mutate(at, |list| {
+                list.contains(game_id) || list.try_push(*game_id).is_ok()
+            });
+            if scheduled {
+                return;
+            }
+            at = at.saturating_add(1u32.into());
+        }
+        log::warn!("No room to schedule turn timeout for game {:?}", game_id);
+    }
+
+    /// Drop `game_id` from the timeout slot `schedule_turn_timeout` gave its turn that
+    /// started at `from`, looking through the same spill blocks.
+    fn unschedule_turn_timeout(game_id: &GameId<T>, from: BlockNumberFor<T>) {
+        let limit: BlockNumberFor<T> = T::BlocksToPlayLimit::get().into();
+        let mut at = from.saturating_add(limit);
+        for _ in 0..MAX_SPILL_BLOCKS {
+            let removed = TurnTimeouts::<T>::mutate_exists(at, |slot| {
+                let Some(list) = slot else { return false };
+                let Some(pos) = list.iter().position(|g| g == game_id) else {
+                    return false;
+                };
+                list.remove(pos);
+                if list.is_empty() {
+                    *slot = None;
+                }
+                true
+            });
+            if removed {
+                return;
+            }
+            at = at.saturating_add(1u32.into());
+        }
+    }
+
+    /// Skip the current turn of `game_id` if its deadline has really passed by block `now`.
+    /// Entries left behind by turns that were played in time are ignored.
+    ///
+    /// Returns the weight of what the skip set off beyond `process_timeouts`: finishing the
+    /// game, or an in-block AI reply (which may finish it in turn).
+    fn process_turn_timeout(game_id: &GameId<T>, now: BlockNumberFor<T>) -> Weight {
+        let mut game = match GameStorage::<T>::get(game_id) {
+            Some(g) => g,
+            None => return Weight::zero(),
+        };
+        let limit: BlockNumberFor<T> = T::BlocksToPlayLimit::get().into();
+        if !matches!(game.state, GameState::Playing)
+            || game.last_played_block.saturating_add(limit) > now
+        {
+            return Weight::zero();
+        }
+
+        let player = game.players[game.player_turn as usize].clone();
+        game.next_turn();
+        game.last_played_block = now;
+        Self::deposit_event(Event::TurnTimedOut {
+            game_id: *game_id,
+            player,
+        });
+
+        if let Some(winner) = Self::is_game_won(game_id, &game) {
+            GameStorage::<T>::insert(game_id, game);
+            Self::end_game(game_id, winner);
+            return <T as Config>::WeightInfo::timeout_end_game();
+        }
+
+        GameStorage::<T>::insert(game_id, game.clone());
+        Self::schedule_turn_timeout(game_id, now);
+        let next_player = game.players[game.player_turn as usize].clone();
+        Self::deposit_event(Event::NewTurn {
+            game_id: *game_id,
+            next_player,
+        });
+
+        // A skipped human turn in PvE hands the move to the AI, which plays right away.
+        if !Self::maybe_ai_take_turn(game_id, &mut game) {
+            return Weight::zero();
+        }
+        let mut weight = <T as Config>::WeightInfo::timeout_ai_turn();
+        let finished = GameStorage::<T>::get(game_id)
+            .is_some_and(|g| matches!(g.state, GameState::Finished { .. }));
+        if finished {
+            weight.saturating_accrue(<T as Config>::WeightInfo::timeout_end_game());
+        }
+        weight
+    }
+
+    /// Queue a finished game for pruning once `FinishedGameRetention` has passed.
+    fn schedule_prune(game_id: &GameId<T>, finished_at: BlockNumberFor<T>) {
+        let mut at = finished_at;
+        for _ in 0..MAX_SPILL_BLOCKS {
+            let scheduled = PendingPrunes::<T>::mutate(at, |list| {
+                list.contains(game_id) || list.try_push(*game_id).is_ok()
+            });
+            if scheduled {
+                if PruneCursor::<T>::get().is_none() {
+                    PruneCursor::<T>::put(at);
+                }
+                return;
+            }
+            at = at.saturating_add(1u32.into());
+        }
+        log::warn!("No room to queue finished game {:?} for pruning", game_id);
+    }
+
+    /// Walk `PendingPrunes` from the cursor, archiving games whose retention has passed,
+    /// for as long as `limit` allows. Returns the weight used.
+    fn prune_finished_games(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+        let mut used = <T as Config>::WeightInfo::prune_idle();
+        if !limit.all_gte(used) {
+            return Weight::zero();
+        }
+        let mut cursor = match PruneCursor::<T>::get() {
+            Some(c) => c,
+            None => return used,
+        };
+        let start = cursor;
+        let retention = T::FinishedGameRetention::get();
+
+        while cursor.saturating_add(retention) <= now {
+            let games = PendingPrunes::<T>::get(cursor);
+            let cost = <T as Config>::WeightInfo::prune_bucket(games.len() as u32);
+            if !limit.all_gte(used.saturating_add(cost)) {
+                break;
+            }
+            PendingPrunes::<T>::remove(cursor);
+            for game_id in games.iter() {
+                Self::archive_game(game_id);
+            }
+            used = used.saturating_add(cost);
+            cursor = cursor.saturating_add(1u32.into());
+        }
+
+        if cursor != start {
+            PruneCursor::<T>::put(cursor);
+        }
+        used
+    }
+
+    /// Replace a finished game's full state with its `GameSummary`.
+    fn archive_game(game_id: &GameId<T>) {
+        let game = match GameStorage::<T>::get(game_id) {
+            Some(g) => g,
+            None => return,
+        };
+        let winner = match game.state {
+            GameState::Finished { winner } => winner,
+            _ => return,
+        };
+
+        let moves = MoveLog::<T>::decode_len(game_id).unwrap_or(0) as u32;
+        let summary = GameSummary {
+            players: game.players,
+            scores: game.scores,
+            winner,
+            rules: GameRules::<T>::get(game_id),
+            moves,
+            finished_at: game.last_played_block,
+        };
+        GameArchive::<T>::insert(game_id, summary);
+        Self::clear_game_state(game_id);
+        Self::deposit_event(Event::GameArchived { game_id: *game_id });
+    }
+
+    /// Remove everything stored under `game_id`. Player-side entries (`ActiveGameOf`,
+    /// `PlayerGames`) and the timeout/prune schedules are left to the caller.
+    fn clear_game_state(game_id: &GameId<T>) {
+        GameStorage::<T>::remove(game_id);
+        GameModes::<T>::remove(game_id);
+        GameRules::<T>::remove(game_id);
+        GameTradeRules::<T>::remove(game_id);
+        GameAiProfiles::<T>::remove(game_id);
+        GameElements::<T>::remove(game_id);
+        GameHandVisibility::<T>::remove(game_id);
+        CasualGames::<T>::remove(game_id);
+        MoveLog::<T>::remove(game_id);
+        let _ = HandsOfGame::<T>::clear_prefix(game_id, T::NumPlayers::get(), None);
+        let _ = HandCommitments::<T>::clear_prefix(game_id, T::NumPlayers::get(), None);
+    }
+
+    /// Pay out a wagered game. The winner gets back their own stake plus the loser's,
+    /// less `HouseCut` which goes to `TreasuryAccount`; without a winner both stakes are
+    /// released. Resigning or abandoning forfeits the stake like any other loss.
+    fn settle_stakes(
+        game_id: &GameId<T>,
+        players: &[AccountIdOf<T>],
+        winner: Option<&AccountIdOf<T>>,
+    ) {
+        let Some(stake) = GameStakes::<T>::take(game_id) else {
+            return;
+        };
+        let reason = HoldReason::GameStake.into();
+        let loser = winner.and_then(|w| players.iter().find(|p| *p != w));
+
+        let (mut payout, mut house_cut) = (Zero::zero(), Zero::zero());
+        if let (Some(winner), Some(loser)) = (winner, loser) {
+            let cut = T::HouseCut::get().mul_floor(stake);
+            // A cut the treasury can't take (e.g. below its existential deposit) goes to the
+            // winner with the rest, rather than back to the loser
+            house_cut = match T::StakeCurrency::transfer_on_hold(
+                &reason,
+                loser,
+                &T::TreasuryAccount::get(),
+                cut,
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Polite,
+            ) {
+                Ok(taken) => taken,
+                Err(e) => {
+                    log::warn!("House cut of game {:?} not paid: {:?}", game_id, e);
+                    Zero::zero()
+                }
+            };
+            payout = T::StakeCurrency::transfer_on_hold(
+                &reason,
+                loser,
+                winner,
+                stake.saturating_sub(house_cut),
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Polite,
+            )
+            .unwrap_or_else(|_| Zero::zero());
+        }
+        // Whatever is still held (the winner's own stake, or both on a draw) goes back
+        for p in players.iter() {
+            let held = T::StakeCurrency::balance_on_hold(&reason, p);
+            if !held.is_zero() {
+                let _ =
+                    T::StakeCurrency::release(&reason, p, held.min(stake), Precision::BestEffort);
+            }
+        }
+
+        Self::deposit_event(Event::StakesSettled {
+            game_id: *game_id,
+            winner: winner.cloned(),
+            payout,
+            house_cut,
+        });
+    }
+
+    /// Move ante cards according to the game's trade rule; `release_hand_locks` frees the rest.
+    /// `winner` is a seat index; `None` (draw or called-off game) only trades under `Direct`.
+    /// For `One` and `Diff` the winner gets the loser's strongest cards by total rank.
+    fn settle_trade(
+        game_id: &GameId<T>,
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+        winner: Option<u8>,
+    ) {
+        let Some(rule) = GameTradeRules::<T>::take(game_id) else {
+            return;
+        };
+        let hands: Vec<Vec<HandEntry>> = game
+            .players
+            .iter()
+            .map(|p| {
+                HandsOfGame::<T>::get(game_id, p)
+                    .map(|h| h.into_inner())
+                    .unwrap_or_default()
+            })
+            .collect();
+        if hands.len() != 2 {
+            return;
+        }
+
+        // Cards leaving each seat
+        let mut lost: [Vec<u32>; 2] = [Vec::new(), Vec::new()];
+        match (rule, winner) {
+            (TradeRule::Direct, _) => {
+                for m in MoveLog::<T>::get(game_id).iter() {
+                    let Some(ix) = m.hand_index else { continue };
+                    let Some(entry) = hands
+                        .get(m.player as usize)
+                        .and_then(|h| h.get(ix as usize))
+                    else {
+                        continue;
+                    };
+                    let held_by = game.board[m.x as usize][m.y as usize]
+                        .as_ref()
+                        .and_then(|c| c.possession.as_ref())
+                        .map(|p| if *p == Player::PlayerOne { 0 } else { 1 });
+                    if held_by.is_some_and(|seat| seat != m.player) {
+                        lost[m.player as usize].push(entry.card_id);
+                    }
+                }
+            }
+            (TradeRule::One | TradeRule::Diff | TradeRule::All, Some(w)) => {
+                let loser = (1 - w.min(1)) as usize;
+                let count = match rule {
+                    TradeRule::One => 1,
+                    TradeRule::Diff => game.scores.0.abs_diff(game.scores.1).max(1) as usize,
+                    _ => hands[loser].len(),
+                };
+                let mut by_rank: Vec<&HandEntry> = hands[loser].iter().collect();
+                by_rank.sort_by_key(|e| {
+                    core::cmp::Reverse(
+                        e.north as u32 + e.east as u32 + e.south as u32 + e.west as u32,
+                    )
+                });
+                lost[loser] = by_rank.into_iter().take(count).map(|e| e.card_id).collect();
+            }
+            _ => {}
+        }
+
+        for (seat, card_ids) in lost.iter().enumerate() {
+            let (from, to) = (&game.players[seat], &game.players[1 - seat]);
+            let moved: Vec<u32> = card_ids
+                .iter()
+                .copied()
+                .filter(|id| {
+                    pallet_eterra_simple_tcg::Pallet::<T>::transfer_locked_card(from, to, *id)
+                        .is_ok()
+                })
+                .collect();
+            if !moved.is_empty() {
+                Self::deposit_event(Event::CardsTraded {
+                    game_id: *game_id,
+                    from: from.clone(),
+                    to: to.clone(),
+                    card_ids: moved,
+                });
+            }
+        }
+    }
+
+    /// Unlock the submitted cards of every human player. The AI's hand is synthetic.
+    fn release_hand_locks(
+        game_id: &GameId<T>,
+        game: &Game<AccountIdOf<T>, BlockNumberFor<T>, T::NumPlayers>,
+    ) {
+        let ai = T::AiAccount::get();
+        for player in game.players.iter().filter(|p| **p != ai) {
+            if let Some(hand) = HandsOfGame::<T>::get(game_id, player) {
+                for entry in hand.iter() {
+                    <pallet_eterra_simple_tcg::Pallet<T> as CardLocker>::unlock(entry.card_id);
+                }
+            }
+        }
+    }
+
+    /// Pass the result to `OnGameFinished`: both sides of PvP games, the human side of PvE.
+    fn report_result(
+        game_id: &GameId<T>,
+        players: &[AccountIdOf<T>],
+        winner: Option<&AccountIdOf<T>>,
+    ) {
+        let outcome = |who: &AccountIdOf<T>| match winner {
+            None => Outcome::Draw,
+            Some(w) if w == who => Outcome::Win,
+            Some(_) => Outcome::Loss,
+        };
+        let (Some(a), Some(b)) = (players.first(), players.get(1)) else {
+            return;
+        };
+        match GameModes::<T>::get(game_id) {
+            Some(GameMode::PvP) if CasualGames::<T>::contains_key(game_id) => {}
+            Some(GameMode::PvP) => T::OnGameFinished::on_pvp_finished(a, b, outcome(a)),
+            Some(GameMode::PvE) => {
+                let ai = T::AiAccount::get();
+                for human in [a, b].into_iter().filter(|p| **p != ai) {
+                    T::OnGameFinished::on_pve_finished(human, outcome(human));
+                }
+            }
+            None => {}
+        }
+    }
+
     fn end_game(game_id: &GameId<T>, winner: Option<T::AccountId>) {
+        DrawOffers::<T>::remove(game_id);
+        let now = <frame_system::Pallet<T>>::block_number();
+
         // Read and update game in storage to persist final state
         if let Some(mut g) = GameStorage::<T>::get(game_id) {
+            // Ratings first, so `GameFinished` stays the last event of the game
+            Self::report_result(game_id, &g.players, winner.as_ref());
             // Emit before we change pointers
             Self::deposit_event(Event::GameFinished {
                 game_id: *game_id,
                 winner: winner.clone(),
             });
+            Self::settle_stakes(game_id, &g.players, winner.as_ref());
 
             // Clear active-game markers for human participants
             if let Some(a) = g.players.get(0).cloned() {
@@ -1299,8 +2618,12 @@ impl<T: Config> Pallet<T> {
                 Some(acc) if *acc == g.players[1] => Some(1),
                 _ => None,
             };
+            Self::settle_trade(game_id, &g, winner_ix);
+            Self::release_hand_locks(game_id, &g);
             g.state = GameState::Finished { winner: winner_ix };
+            g.last_played_block = now;
             GameStorage::<T>::insert(game_id, g);
+            Self::schedule_prune(game_id, now);
         } else {
             // If the game wasn't found (should not happen), still emit the event
             Self::deposit_event(Event::GameFinished {
@@ -1311,14 +2634,291 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+// Read-only queries backing the `EterraGameApi` runtime API
+impl<T: Config> Pallet<T> {
+    /// Decoded view of a game, or `None` if it does not exist.
+    pub fn game_view(game_id: &GameId<T>) -> Option<GameView<AccountIdOf<T>, BlockNumberFor<T>>> {
+        let game = GameStorage::<T>::get(game_id)?;
+        Some(GameView {
+            mode: GameModes::<T>::get(game_id),
+            state: game.state,
+            last_played_block: game.last_played_block,
+            players: game.players.into_inner(),
+            player_turn: game.player_turn,
+            round: game.round,
+            max_rounds: game.max_rounds,
+            board: game.board,
+            scores: game.scores,
+        })
+    }
+
+    /// The game `who` is currently playing, if any.
+    pub fn active_game(who: &AccountIdOf<T>) -> Option<GameId<T>> {
+        ActiveGameOf::<T>::get(who)
+    }
+
+    /// Recent games of `who`, most recent first.
+    pub fn recent_games(who: &AccountIdOf<T>) -> Vec<GameId<T>> {
+        PlayerGames::<T>::get(who).into_inner()
+    }
+
+    /// Submitted hands of the game's players, in seat order. Players who have
+    /// not submitted yet are omitted; hidden hands only show the cards revealed so far.
+    pub fn hands(game_id: &GameId<T>) -> Vec<HandView<AccountIdOf<T>>> {
+        let game = match GameStorage::<T>::get(game_id) {
+            Some(g) => g,
+            None => return Vec::new(),
+        };
+        game.players
+            .iter()
+            .filter_map(|p| {
+                HandsOfGame::<T>::get(game_id, p).map(|h| HandView {
+                    player: p.clone(),
+                    entries: h.into_inner(),
+                })
+            })
+            .collect()
+    }
+
+    /// Game, rules, hands and move log, for re-simulating with `replay::replay` (std only).
+    /// `None` once the game has been archived.
+    pub fn game_record(
+        game_id: &GameId<T>,
+    ) -> Option<GameRecord<AccountIdOf<T>, BlockNumberFor<T>>> {
+        Some(GameRecord {
+            game: Self::game_view(game_id)?,
+            rules: GameRules::<T>::get(game_id),
+            elements: GameElements::<T>::get(game_id),
+            hand_size: T::HandSize::get().saturated_into::<u8>(),
+            hands: Self::hands(game_id),
+            moves: MoveLog::<T>::get(game_id).into_inner(),
+        })
+    }
+
+    /// Every `(hand_index, x, y)` the player to move could pass to `play_from_hand`; with
+    /// hidden hands, the slots still committed. Empty if the game is not in progress or
+    /// that player has no hand yet.
+    pub fn legal_moves(game_id: &GameId<T>) -> Vec<LegalMove> {
+        let mut out = Vec::new();
+        let game = match GameStorage::<T>::get(game_id) {
+            Some(g) => g,
+            None => return out,
+        };
+        if !matches!(game.state, GameState::Playing) {
+            return out;
+        }
+        let player = match game.players.get(game.player_turn as usize) {
+            Some(p) => p,
+            None => return out,
+        };
+        let playable: Vec<bool> = if GameHandVisibility::<T>::contains_key(game_id) {
+            match HandCommitments::<T>::get(game_id, player) {
+                Some(slots) => slots.iter().map(Option::is_some).collect(),
+                None => return out,
+            }
+        } else {
+            match HandsOfGame::<T>::get(game_id, player) {
+                Some(hand) => hand.iter().map(|entry| !entry.used).collect(),
+                None => return out,
+            }
+        };
+        for x in 0..game.board.width() as u8 {
+            for y in 0..game.board.height() as u8 {
+                if game.board[x as usize][y as usize].is_some() {
+                    continue;
+                }
+                for (i, _) in playable.iter().enumerate().filter(|(_, p)| **p) {
+                    out.push(LegalMove {
+                        hand_index: i as u8,
+                        x,
+                        y,
+                    });
+                }
+            }
+        }
+        out
+    }
+
+    /// Simulate the player to move playing `hand_index` at `(x, y)` on a copy of the
+    /// game. Fails with the same errors `play_from_hand` would; never writes storage.
+    /// Hidden hands can't be previewed, as the chain doesn't know their cards.
+    pub fn preview_move(
+        game_id: &GameId<T>,
+        hand_index: u8,
+        x: u8,
+        y: u8,
+    ) -> Result<MovePreview, sp_runtime::DispatchError> {
+        let mut game = GameStorage::<T>::get(game_id).ok_or(Error::<T>::GameNotFound)?;
+        ensure!(
+            matches!(game.state, GameState::Playing),
+            Error::<T>::InvalidMove
+        );
+        ensure!(
+            !GameHandVisibility::<T>::contains_key(game_id),
+            Error::<T>::HandIsHidden
+        );
+        let who = game
+            .players
+            .get(game.player_turn as usize)
+            .cloned()
+            .ok_or(Error::<T>::InternalError)?;
+        let hand = HandsOfGame::<T>::get(game_id, &who).ok_or(Error::<T>::HandNotSubmitted)?;
+        let h = hand
+            .get(hand_index as usize)
+            .ok_or(Error::<T>::HandIndexOutOfRange)?;
+        ensure!(!h.used, Error::<T>::CardAlreadyUsed);
+
+        let mv = Move {
+            place_card: Card::new(h.north, h.east, h.south, h.west),
+            place_index_x: x,
+            place_index_y: y,
+        };
+        Self::validate_move(&game, &mv)?;
+
+        let before = game.scores;
+        let player_ix = Self::get_current_player_index(&game, &who);
+        Self::place_card_on_board(&mut game, &mv, player_ix);
+        let flipped = Self::apply_capture_logic(game_id, &mut game, &mv, player_ix);
+
+        Ok(MovePreview {
+            board: game.board,
+            score_delta: (
+                game.scores.0 as i8 - before.0 as i8,
+                game.scores.1 as i8 - before.1 as i8,
+            ),
+            flipped,
+        })
+    }
+}
+
 // Expose GameCreator for the matchmaker pallet
 impl<T: Config> pallet_eterra_simple_matchmaker::GameCreator<AccountIdOf<T>> for Pallet<T> {
     type GameId = GameId<T>;
+    type MatchParams = MatchSettings<BalanceOf<T>>;
 
     fn create_from_matchmaking(
         a: &AccountIdOf<T>,
         b: &AccountIdOf<T>,
-    ) -> Result<GameId<T>, sp_runtime::DispatchError> {
-        Self::do_create_pvp_game(a, b)
+        settings: &MatchSettings<BalanceOf<T>>,
+    ) -> Result<GameId<T>, MatchError<AccountIdOf<T>>> {
+        // Blame the player whose own state rules them out so the matchmaker can requeue the other
+        let stake = Self::matchmaking_stake(settings);
+        for who in [a, b] {
+            Self::ensure_can_be_matched(who, stake).map_err(|reason| MatchError {
+                culprit: Some(who.clone()),
+                reason,
+            })?;
+        }
+        Self::do_create_pvp_game(a, b, settings).map_err(Into::into)
+    }
+}
+
+// Lets the Monte-Carlo pallet's offchain worker find and answer PvE turns waiting on the AI
+impl<T: Config> mc_ai::AiTurnProvider<ai::State, ai::Action> for Pallet<T> {
+    type GameKey = GameId<T>;
+
+    fn awaiting_ai(max: u32) -> Vec<(GameId<T>, ai::State)> {
+        // Every game with a running turn has an entry in `TurnTimeouts`.
+        let mut out: Vec<(GameId<T>, ai::State)> = Vec::new();
+        for game_id in TurnTimeouts::<T>::iter_values().flat_map(|games| games.into_inner()) {
+            if out.len() as u32 >= max {
+                break;
+            }
+            if out.iter().any(|(g, _)| *g == game_id) {
+                continue;
+            }
+            if let Some(state) = Self::ai_state(&game_id) {
+                out.push((game_id, state));
+            }
+        }
+        out
+    }
+
+    fn ai_state(game_id: &GameId<T>) -> Option<ai::State> {
+        let game = GameStorage::<T>::get(game_id)?;
+        if !matches!(game.state, GameState::Playing)
+            || !matches!(GameModes::<T>::get(game_id), Some(GameMode::PvE))
+            || *game.players.get(game.player_turn as usize)? != T::AiAccount::get()
+        {
+            return None;
+        }
+        Self::build_ai_state(game_id, &game)
+    }
+
+    fn apply_ai_action(game_id: &GameId<T>, action: &ai::Action) -> sp_runtime::DispatchResult {
+        let mut game = GameStorage::<T>::get(game_id).ok_or(Error::<T>::GameNotFound)?;
+        ensure!(
+            matches!(game.state, GameState::Playing),
+            Error::<T>::GameNotPlaying
+        );
+        ensure!(
+            matches!(GameModes::<T>::get(game_id), Some(GameMode::PvE)),
+            Error::<T>::NotPvEGame
+        );
+        Self::validate_player_turn(&game, &T::AiAccount::get())?;
+        Self::play_ai_action(game_id, &mut game, action)?;
+        Ok(())
+    }
+
+    fn search_budget(game_id: &GameId<T>) -> sp_runtime::Percent {
+        match Self::ai_profile(game_id) {
+            AiProfile::MonteCarlo(difficulty) => sp_runtime::Percent::from_percent(difficulty),
+            _ => sp_runtime::Percent::one(),
+        }
+    }
+
+    /// Largest board with every rule on and the AI to play its first card.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_up_ai_turn() -> GameId<T> {
+        let human: AccountIdOf<T> = frame_benchmarking::account("human", 0, 0);
+        let ai_acc = T::AiAccount::get();
+        let game_id = <T as frame_system::Config>::Hashing::hash_of(&(b"ai-turn", &human));
+        let dim = eterra_rules::MAX_BOARD_DIM as u8;
+        let size = BoardSize::new(dim, dim);
+        let now = <frame_system::Pallet<T>>::block_number();
+
+        let hand: BoundedVec<HandEntry, HandLimit> = (0..T::HandSize::get())
+            .map(|card_id| HandEntry {
+                card_id,
+                north: 5,
+                east: 5,
+                south: 5,
+                west: 5,
+                used: false,
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("HandSize is within HandLimit; qed");
+        HandsOfGame::<T>::insert(game_id, &human, hand);
+        if let Some(ai_hand) = Self::generate_ai_hand_default(&game_id) {
+            HandsOfGame::<T>::insert(game_id, &ai_acc, ai_hand);
+        }
+        GameModes::<T>::insert(game_id, GameMode::PvE);
+        Self::store_rules(
+            &game_id,
+            RuleSet {
+                same: true,
+                plus: true,
+                combo: true,
+                same_wall: true,
+                elemental: true,
+            },
+        );
+        Self::roll_elements(&game_id);
+        let game = Game {
+            state: GameState::Playing,
+            last_played_block: now,
+            players: sp_std::vec![human, ai_acc]
+                .try_into()
+                .expect("two players; qed"),
+            player_turn: 1,
+            round: 0,
+            max_rounds: Self::max_rounds_for(&size),
+            board: Board::new(size),
+            scores: Self::initial_scores(),
+        };
+        GameStorage::<T>::insert(game_id, game);
+        Self::schedule_turn_timeout(&game_id, now);
+        game_id
     }
 }
@@ -0,0 +1,63 @@
+//! Storage migrations for `pallet-eterra`.
+
+pub mod v1 {
+    //! v0 → v1: boards carry their own `BoardSize` over a 5x5 backing grid.
+    //! Existing games keep their 4x4 layout.
+
+    use crate::pallet::{Config, GameStorage, Pallet};
+    use crate::types::game::{Game, GameState, Players};
+    use crate::{Board, Card};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::{Get, UncheckedOnRuntimeUpgrade};
+    use frame_support::weights::Weight;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use parity_scale_codec::{Decode, Encode};
+
+    /// Game layout before boards became sized.
+    #[derive(Encode, Decode)]
+    pub struct OldGame<Account, BlockNumber, NumPlayers> {
+        pub state: GameState,
+        pub last_played_block: BlockNumber,
+        pub players: Players<Account, NumPlayers>,
+        pub player_turn: u8,
+        pub round: u8,
+        pub max_rounds: u8,
+        pub board: [[Option<Card>; 4]; 4],
+        pub scores: (u8, u8),
+    }
+
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            GameStorage::<T>::translate::<OldGame<T::AccountId, BlockNumberFor<T>, T::NumPlayers>, _>(
+                |_, old| {
+                    translated += 1;
+                    Some(Game {
+                        state: old.state,
+                        last_played_block: old.last_played_block,
+                        players: old.players,
+                        player_turn: old.player_turn,
+                        round: old.round,
+                        max_rounds: old.max_rounds,
+                        board: Board::from_legacy(old.board),
+                        scores: old.scores,
+                    })
+                },
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Versioned wrapper to list in the runtime's `Migrations`.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,7 +1,7 @@
 use crate as pallet_eterra;
 use frame_support::{
     parameter_types,
-    traits::{ConstU16, ConstU32, ConstU64, ConstU8, Currency, Get},
+    traits::{ConstU128, ConstU16, ConstU32, ConstU64, ConstU8, Currency, Get},
 };
 use frame_system as system;
 use pallet_balances;
@@ -11,7 +11,7 @@ use parity_scale_codec::{Decode, Encode}; // Ensure Encode and Decode are import
 use scale_info::TypeInfo;
 use sp_core::H256; // Ensure H256 is imported
 use sp_runtime::{
-    testing::Header,
+    testing::{Header, TestSignature, TestXt, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 }; // Import TypeInfo
@@ -26,6 +26,8 @@ frame_support::construct_runtime!(
         Cards: pallet_eterra_simple_tcg,
         Eterra: pallet_eterra,
         EterraMonteCarloAi: pallet_eterra_monte_carlo_ai,
+        EterraRandomness: pallet_eterra_randomness,
+        EterraRating: pallet_eterra_rating,
     }
 );
 
@@ -34,12 +36,11 @@ parameter_types! {
     pub const MaximumBlockWeight: u64 = 1024;
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(75);
-    pub const ExistentialDeposit: u128 = 1;
+    pub storage ExistentialDeposit: u128 = 1;
 }
 
 parameter_types! {
     pub const FaucetAccountId: u64 = 999; // arbitrary faucet for tests
-    pub const RandomnessSeedConst: u64 = 42;
     pub const MintFeeConst: u128 = 0; // zero-fee minting in tests to avoid funding hassle
 }
 
@@ -88,16 +89,32 @@ impl pallet_balances::Config for Test {
     type ReserveIdentifier = [u8; 8];
     type FreezeIdentifier = ();
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
 }
 
 impl pallet_eterra_simple_tcg::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type RandomnessSeed = RandomnessSeedConst;
+    type Randomness = EterraRandomness;
     type Currency = Balances;
     type MintFee = MintFeeConst;
     type FaucetAccount = FaucetAccountId;
+    type WeightInfo = ();
+}
+
+impl pallet_eterra_randomness::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RevealWindow = ConstU64<10>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const RatingTau: sp_runtime::FixedI64 = sp_runtime::FixedI64::from_inner(500_000_000);
+}
+
+impl pallet_eterra_rating::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Tau = RatingTau;
 }
 
 #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
@@ -128,31 +145,76 @@ impl Get<u8> for MockBlocksToPlayLimit {
 }
 
 parameter_types! {
-    pub const HandSizeConst: u32 = 5;
+    pub storage HandSizeConst: u32 = 5;
 }
 
 parameter_types! {
     pub const AiDifficultyConst: u8 = 60;
-    pub const AiRandomnessSeedConst: u64 = 12345;
+    pub const TreasuryAccountId: u64 = 500;
+    pub const HouseCutConst: sp_runtime::Permill = sp_runtime::Permill::from_percent(10);
+    pub storage AiMovesOffchain: bool = false;
 }
 
 impl pallet_eterra::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type Randomness = EterraRandomness;
     type NumPlayers = MockNumPlayers;
     type MaxRounds = MockMaxRounds;
     type BlocksToPlayLimit = MockBlocksToPlayLimit;
+    type MaxTimeoutsPerBlock = ConstU32<2>;
+    type FinishedGameRetention = ConstU64<10>;
+    type MaxPrunesPerBlock = ConstU32<4>;
     type HandSize = HandSizeConst;
     type AiAccount = FaucetAccountId;
     type AiDifficulty = ConstU8<60>;
+    type AiMovesOffchain = AiMovesOffchain;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type StakeCurrency = Balances;
+    type TreasuryAccount = TreasuryAccountId;
+    type HouseCut = HouseCutConst;
+    type MatchmakingStake = ConstU128<0>;
+    type OnGameFinished = EterraRating;
+    type WeightInfo = ();
 }
 
 impl mc_ai::pallet::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Adapter = eterra_card_ai_adapter::eterra_adapter::Adapter;
-    type MaxActions = ConstU32<64>;
+    type MaxActions = ConstU32<{ eterra_card_ai_adapter::eterra_adapter::MAX_ACTIONS as u32 }>;
     type BaseIterations = ConstU32<100>;
     type MaxPlayoutDepth = ConstU16<16>;
-    type RandomnessSeed = ConstU64<12345>;
+    type ExplorationConstant = ConstU32<1_000>;
+    type MaxTreeNodes = ConstU32<4_096>;
+    type SolverThreshold = ConstU32<21>;
+    type TurnProvider = Eterra;
+    type AuthorityId = TestAuthId;
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type OffchainIterations = ConstU32<200>;
+    type MaxOffchainGames = ConstU32<4>;
+    type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = crate::AiBenchmarkHelper;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = TestXt<RuntimeCall, ()>;
+}
+
+pub struct TestAuthId;
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -167,6 +229,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
         let _ = <Balances as Currency<u64>>::deposit_creating(&1u64, 1_000_000_000_000);
         let _ = <Balances as Currency<u64>>::deposit_creating(&2u64, 1_000_000_000_000);
         let _ = <Balances as Currency<u64>>::deposit_creating(&999u64, 1_000_000_000_000);
+        let _ = <Balances as Currency<u64>>::deposit_creating(&500u64, 1_000);
         // faucet
     });
     ext
@@ -0,0 +1,152 @@
+//! Deterministic re-simulation of a game's move log.
+//!
+//! Works on a [`GameRecord`] alone, so it can run in tests against pallet storage or
+//! in a node RPC against the `EterraGameApi::game_record` runtime API.
+
+use crate::pallet::HandEntry;
+use crate::types::board::Board;
+use crate::types::view::GameRecord;
+
+/// Final position reached by replaying a move log.
+#[derive(Clone, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ReplayReport {
+    pub moves: u32,
+    pub board: Board,
+    pub scores: (u8, u8),
+}
+
+/// Why a move log does not reproduce the stored game. `step` indexes the move log.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ReplayError {
+    /// The move names a seat other than 0 or 1, or a seat without a submitted hand.
+    UnknownPlayer {
+        step: u32,
+    },
+    OutOfBounds {
+        step: u32,
+    },
+    CellOccupied {
+        step: u32,
+    },
+    /// The hand index is missing, already used, or its stats differ from the placed card.
+    HandMismatch {
+        step: u32,
+    },
+    /// Captures resolved differently from what was recorded.
+    FlipMismatch {
+        step: u32,
+    },
+    /// Board or scores after the last move differ from the stored game.
+    FinalStateMismatch,
+    /// `used` flags of the stored hands differ from the cards the log played.
+    HandsMismatch,
+}
+
+impl core::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::UnknownPlayer { step } => write!(f, "move {step}: unknown player"),
+            Self::OutOfBounds { step } => write!(f, "move {step}: cell out of bounds"),
+            Self::CellOccupied { step } => write!(f, "move {step}: cell already occupied"),
+            Self::HandMismatch { step } => write!(f, "move {step}: card does not match hand"),
+            Self::FlipMismatch { step } => write!(f, "move {step}: flipped cells differ"),
+            Self::FinalStateMismatch => write!(f, "final board or scores differ"),
+            Self::HandsMismatch => write!(f, "hand usage differs"),
+        }
+    }
+}
+
+/// Replay `record.moves` from an empty board and the unused hands, checking each
+/// recorded flip and the final board, scores and hand usage against `record`.
+pub fn replay<AccountId: PartialEq, BlockNumber>(
+    record: &GameRecord<AccountId, BlockNumber>,
+) -> Result<ReplayReport, ReplayError> {
+    let mut board = Board::new(record.game.board.size);
+    let mut scores = (record.hand_size, record.hand_size);
+    let elements = record.elements.unwrap_or_default();
+
+    // Hands as they were before the first move
+    let mut hands: Vec<Option<Vec<HandEntry>>> = record
+        .game
+        .players
+        .iter()
+        .map(|p| {
+            record.hands.iter().find(|h| h.player == *p).map(|h| {
+                h.entries
+                    .iter()
+                    .cloned()
+                    .map(|e| HandEntry { used: false, ..e })
+                    .collect()
+            })
+        })
+        .collect();
+
+    for (step, m) in record.moves.iter().enumerate() {
+        let step = step as u32;
+        let (x, y) = (m.x as usize, m.y as usize);
+        if m.player > 1 || m.player as usize >= hands.len() {
+            return Err(ReplayError::UnknownPlayer { step });
+        }
+        if !board.in_bounds(x, y) {
+            return Err(ReplayError::OutOfBounds { step });
+        }
+        if board[x][y].is_some() {
+            return Err(ReplayError::CellOccupied { step });
+        }
+
+        if let Some(ix) = m.hand_index {
+            let hand = hands[m.player as usize]
+                .as_mut()
+                .ok_or(ReplayError::UnknownPlayer { step })?;
+            let entry = hand
+                .get_mut(ix as usize)
+                .ok_or(ReplayError::HandMismatch { step })?;
+            let same_stats = (entry.north, entry.east, entry.south, entry.west)
+                == (m.card.top, m.card.right, m.card.bottom, m.card.left);
+            if entry.used || !same_stats {
+                return Err(ReplayError::HandMismatch { step });
+            }
+            entry.used = true;
+        }
+
+        let flipped = eterra_rules::play_card(
+            &mut board,
+            &mut scores,
+            x,
+            y,
+            m.card.clone(),
+            m.player,
+            &record.rules,
+            &elements,
+        );
+        if flipped[..] != m.flipped[..] {
+            return Err(ReplayError::FlipMismatch { step });
+        }
+    }
+
+    if board != record.game.board || scores != record.game.scores {
+        return Err(ReplayError::FinalStateMismatch);
+    }
+    for (ix, player) in record.game.players.iter().enumerate() {
+        let stored = record.hands.iter().find(|h| h.player == *player);
+        let replayed = hands.get(ix).and_then(|h| h.as_ref());
+        let same = match (stored, replayed) {
+            (Some(s), Some(r)) => s
+                .entries
+                .iter()
+                .map(|e| e.used)
+                .eq(r.iter().map(|e| e.used)),
+            (None, None) => true,
+            _ => false,
+        };
+        if !same {
+            return Err(ReplayError::HandsMismatch);
+        }
+    }
+
+    Ok(ReplayReport {
+        moves: record.moves.len() as u32,
+        board,
+        scores,
+    })
+}
@@ -9,8 +9,7 @@ use crate::{mock::*, types::card::Card};
 use frame_support::traits::Get;
 use frame_support::traits::Hooks;
 use frame_support::{assert_err, assert_noop, assert_ok};
-use pallet_eterra_simple_matchmaker::GameCreator; // bring the trait into scope
-use sp_runtime::DispatchError;
+use pallet_eterra_simple_matchmaker::{GameCreator, MatchError}; // bring the trait into scope
 
 use crate::types::card::Possession;
 use frame_support::BoundedVec;
@@ -75,7 +74,14 @@ fn setup_new_game() -> (H256, u64, u64) {
         frame_system::RawOrigin::Signed(creator).into(),
         vec![creator, opponent],
         pallet::GameMode::PvP,
+        crate::RuleSet::default(),
+        crate::BoardSize::default(),
+        0,
+        crate::TradeRule::None,
+        None,
+        crate::HandVisibility::Open,
     ));
+    deal_pending();
     log::debug!(
         "Game created with ID: {:?}, Creator: {}, Opponent: {}, Block: {}",
         game_id,
@@ -110,7 +116,14 @@ fn setup_new_game_with(creator: u64, opponent: u64) -> (H256, u64, u64) {
         frame_system::RawOrigin::Signed(creator).into(),
         vec![creator, opponent],
         pallet::GameMode::PvP,
+        crate::RuleSet::default(),
+        crate::BoardSize::default(),
+        0,
+        crate::TradeRule::None,
+        None,
+        crate::HandVisibility::Open,
     ));
+    deal_pending();
     log::debug!(
         "Game created with ID: {:?}, Creator: {}, Opponent: {}, Block: {}",
         game_id,
@@ -141,6 +154,12 @@ fn create_game_fails_without_preset_hand_pvp() {
             frame_system::RawOrigin::Signed(creator).into(),
             vec![creator, opponent],
             pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         );
         assert!(
             res.is_err(),
@@ -161,7 +180,14 @@ fn create_game_succeeds_with_preset_hand_pvp() {
             frame_system::RawOrigin::Signed(creator).into(),
             vec![creator, opponent],
             pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
     });
 }
 #[test]
@@ -174,6 +200,12 @@ fn create_pve_game_fails_without_preset_hand_for_human() {
             RawOrigin::Signed(human).into(),
             vec![human],
             pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         );
         assert!(
             res.is_err(),
@@ -192,7 +224,14 @@ fn create_pve_game_succeeds_with_preset_hand_for_human() {
             RawOrigin::Signed(human).into(),
             vec![human],
             pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
     });
 }
 
@@ -210,6 +249,8 @@ fn mint_cards_for(owner: u64, n: usize) -> Vec<u32> {
             frame_system::RawOrigin::Signed(owner).into()
         ));
     }
+    // Roll their stats as the next block would, without moving the block number
+    cards::Pallet::<Test>::roll_minted_cards(System::block_number() + 1);
     // Read from OwnedCards index (bounded vec) and collect the most recent `n` ids
     let owned = card_pallet::OwnedCards::<Test>::get(owner);
     owned.into_iter().rev().take(n).rev().collect()
@@ -223,11 +264,18 @@ fn ensure_my_turn(game_id: H256, me: u64, other: u64) {
         if current == me {
             break;
         }
-        // Make `other` play a trivial move using the original `play` (not from hand)
+        // Make `other` play a trivial move using the original `play` (not from hand), or
+        // its first unused card once hands are locked in
         // Find the first empty slot
         'outer: for x in 0..4u8 {
             for y in 0..4u8 {
                 if game.board[x as usize][y as usize].is_none() {
+                    let hands_locked = HandsOfGame::<Test>::contains_key(game_id, me)
+                        || HandsOfGame::<Test>::contains_key(game_id, other);
+                    if hands_locked {
+                        play_first_unused(game_id, other, x, y);
+                        break 'outer;
+                    }
                     let m = Move {
                         place_index_x: x,
                         place_index_y: y,
@@ -245,6 +293,45 @@ fn ensure_my_turn(game_id: H256, me: u64, other: u64) {
     }
 }
 
+/// Deal every pending game as the next block would, without moving the block number.
+fn deal_pending() {
+    Eterra::deal_games(System::block_number() + 1);
+}
+
+/// Play `who`'s first unused card at `(x, y)`, submitting their preset hand first if needed.
+fn play_first_unused(game_id: H256, who: u64, x: u8, y: u8) {
+    if !HandsOfGame::<Test>::contains_key(game_id, who) {
+        assert_ok!(Eterra::submit_hand(
+            frame_system::RawOrigin::Signed(who).into(),
+            game_id,
+            vec![]
+        ));
+        // A PvE submission deals the AI's hand again
+        deal_pending();
+    }
+    let hand = HandsOfGame::<Test>::get(game_id, who).unwrap();
+    let index = hand
+        .iter()
+        .position(|e| !e.used)
+        .expect("a card left to play") as u8;
+    assert_ok!(Eterra::play_from_hand(
+        frame_system::RawOrigin::Signed(who).into(),
+        game_id,
+        index,
+        x,
+        y,
+        None,
+    ));
+}
+
+/// Hand the turn to `who` without playing, for games where `other` can't easily move.
+fn give_turn_to(game_id: H256, who: u64) {
+    GameStorage::<Test>::mutate(game_id, |g| {
+        let g = g.as_mut().unwrap();
+        g.player_turn = g.players.iter().position(|p| *p == who).unwrap() as u8;
+    });
+}
+
 #[test]
 fn create_game_with_same_players_fails() {
     init_logger();
@@ -255,6 +342,12 @@ fn create_game_with_same_players_fails() {
             frame_system::RawOrigin::Signed(player).into(),
             vec![player, player],
             pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         );
         assert_noop!(result, crate::Error::<Test>::InvalidMove);
     });
@@ -290,6 +383,37 @@ fn invalid_move_on_occupied_cell() {
     });
 }
 
+#[test]
+fn play_rejects_ranks_outside_one_to_nine() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        ensure_my_turn(game_id, creator, opponent);
+        for card in [Card::new(0, 5, 5, 5), Card::new(5, 5, 10, 5)] {
+            assert_noop!(
+                Eterra::play(
+                    frame_system::RawOrigin::Signed(creator).into(),
+                    game_id,
+                    Move {
+                        place_index_x: 0,
+                        place_index_y: 0,
+                        place_card: card,
+                    },
+                ),
+                crate::Error::<Test>::InvalidCardRank
+            );
+        }
+        assert_ok!(Eterra::play(
+            frame_system::RawOrigin::Signed(creator).into(),
+            game_id,
+            Move {
+                place_index_x: 0,
+                place_index_y: 0,
+                place_card: Card::new(1, 9, 1, 9),
+            },
+        ));
+    });
+}
+
 #[test]
 fn create_game_works() {
     init_logger();
@@ -910,6 +1034,12 @@ fn create_game_invalid_number_of_players() {
             RawOrigin::Signed(creator).into(),
             vec![],
             pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         );
         assert_noop!(res, crate::Error::<Test>::CreatorMustBeInGame);
 
@@ -918,6 +1048,12 @@ fn create_game_invalid_number_of_players() {
             RawOrigin::Signed(creator).into(),
             vec![creator],
             pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         );
         assert_noop!(res, crate::Error::<Test>::InvalidNumberOfPlayers);
 
@@ -926,6 +1062,12 @@ fn create_game_invalid_number_of_players() {
             RawOrigin::Signed(creator).into(),
             vec![creator, opponent, third_player],
             pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         );
         assert_noop!(res, crate::Error::<Test>::InvalidNumberOfPlayers);
 
@@ -933,8 +1075,15 @@ fn create_game_invalid_number_of_players() {
         assert_ok!(Eterra::create_game(
             RawOrigin::Signed(creator).into(),
             vec![creator, opponent],
-            pallet::GameMode::PvP
+            pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
     });
 }
 
@@ -1577,6 +1726,7 @@ fn play_from_hand_requires_hand_submission() {
             0, // index
             0, // x
             0, // y
+            None,
         );
         assert_noop!(res, crate::Error::<Test>::HandNotSubmitted);
     });
@@ -1604,6 +1754,7 @@ fn play_from_hand_marks_used_and_prevents_reuse() {
             0,
             0,
             0,
+            None,
         ));
 
         // Advance back to creator's turn
@@ -1616,6 +1767,7 @@ fn play_from_hand_marks_used_and_prevents_reuse() {
             0,
             1,
             0,
+            None,
         );
         assert_noop!(res, crate::Error::<Test>::CardAlreadyUsed);
     });
@@ -1641,6 +1793,7 @@ fn play_from_hand_index_out_of_range_fails() {
             5,
             0,
             1,
+            None,
         );
         assert_noop!(res, crate::Error::<Test>::HandIndexOutOfRange);
     });
@@ -1726,20 +1879,18 @@ fn play_from_hand_not_your_turn_fails() {
             ids
         ));
 
-        // Ensure it's NOT the creator's turn: if it is, have creator make a normal play to pass turn
+        // Ensure it's NOT the creator's turn: if it is, have creator play from hand to pass turn
         {
             let game = Eterra::game_board(game_id).unwrap();
             let current = game.players[game.player_turn as usize];
             if current == creator {
-                let m = Move {
-                    place_index_x: 0,
-                    place_index_y: 0,
-                    place_card: Card::new(1, 1, 1, 1),
-                };
-                assert_ok!(Eterra::play(
+                assert_ok!(Eterra::play_from_hand(
                     frame_system::RawOrigin::Signed(creator).into(),
                     game_id,
-                    m
+                    4,
+                    0,
+                    0,
+                    None,
                 ));
             }
         }
@@ -1751,6 +1902,7 @@ fn play_from_hand_not_your_turn_fails() {
             0,
             1,
             1,
+            None,
         );
         assert_noop!(res, crate::Error::<Test>::NotYourTurn);
     });
@@ -1773,30 +1925,19 @@ fn play_from_hand_cell_occupied_and_bounds_checked() {
 
         // Opponent occupies (0,0) first to trigger CellOccupied later
         {
-            let m = Move {
-                place_index_x: 0,
-                place_index_y: 0,
-                place_card: Card::new(1, 1, 1, 1),
-            };
-            // if it's not opponent's turn yet, make creator play one trivial move to pass
+            // if it's not opponent's turn yet, make creator play one card from hand to pass
             let game = Eterra::game_board(game_id).unwrap();
             if game.players[game.player_turn as usize] != opponent {
-                let m2 = Move {
-                    place_index_x: 1,
-                    place_index_y: 0,
-                    place_card: Card::new(1, 1, 1, 1),
-                };
-                assert_ok!(Eterra::play(
+                assert_ok!(Eterra::play_from_hand(
                     frame_system::RawOrigin::Signed(creator).into(),
                     game_id,
-                    m2
+                    4,
+                    1,
+                    0,
+                    None,
                 ));
             }
-            assert_ok!(Eterra::play(
-                frame_system::RawOrigin::Signed(opponent).into(),
-                game_id,
-                m
-            ));
+            play_first_unused(game_id, opponent, 0, 0);
         }
 
         // Bring turn back to creator
@@ -1809,6 +1950,7 @@ fn play_from_hand_cell_occupied_and_bounds_checked() {
             0,
             4, // x out of bounds
             0,
+            None,
         );
         assert_noop!(res, crate::Error::<Test>::InvalidMove);
 
@@ -1819,13 +1961,14 @@ fn play_from_hand_cell_occupied_and_bounds_checked() {
             0,
             0,
             0,
+            None,
         );
         assert_noop!(res, crate::Error::<Test>::CellOccupied);
     });
 }
 
 #[test]
-fn transfer_after_submit_does_not_block_play() {
+fn submitted_cards_are_locked_until_the_game_ends() {
     init_logger();
     new_test_ext().execute_with(|| {
         let (game_id, creator, opponent) = setup_new_game();
@@ -1836,15 +1979,38 @@ fn transfer_after_submit_does_not_block_play() {
             ids.clone()
         ));
 
-        // Transfer the first card to opponent AFTER submission
-        let first = ids[0];
+        // Cards outside the submitted hand stay tradeable
         assert_ok!(cards::Pallet::<Test>::transfer_card(
             frame_system::RawOrigin::Signed(creator).into(),
-            first,
+            ids[0],
             opponent,
         ));
 
-        // Ensure it's creator's turn and play from the hand index 0; should still work
+        // The submitted hand can't be sold or given away mid-game
+        let hand = HandsOfGame::<Test>::get(&game_id, &creator).unwrap();
+        let first = hand[0].card_id;
+        assert_eq!(
+            cards::Pallet::<Test>::lock_reason_of(first),
+            Some(cards::LockReason::InGame)
+        );
+        assert_noop!(
+            cards::Pallet::<Test>::transfer_card(
+                frame_system::RawOrigin::Signed(creator).into(),
+                first,
+                opponent,
+            ),
+            card_pallet::Error::<Test>::CardLocked
+        );
+        assert_noop!(
+            cards::Pallet::<Test>::set_price(
+                frame_system::RawOrigin::Signed(creator).into(),
+                first,
+                100,
+            ),
+            card_pallet::Error::<Test>::CardLocked
+        );
+
+        // Play from the hand index 0 still works
         ensure_my_turn(game_id, creator, opponent);
         assert_ok!(Eterra::play_from_hand(
             frame_system::RawOrigin::Signed(creator).into(),
@@ -1852,6 +2018,21 @@ fn transfer_after_submit_does_not_block_play() {
             0,
             0,
             0,
+            None,
+        ));
+
+        // Ending the game releases the hand
+        assert_ok!(Eterra::resign(
+            frame_system::RawOrigin::Signed(opponent).into(),
+            game_id
+        ));
+        for entry in hand.iter() {
+            assert!(cards::Pallet::<Test>::lock_reason_of(entry.card_id).is_none());
+        }
+        assert_ok!(cards::Pallet::<Test>::transfer_card(
+            frame_system::RawOrigin::Signed(creator).into(),
+            first,
+            opponent,
         ));
     });
 }
@@ -1888,7 +2069,14 @@ mod ai_integration_tests {
             RawOrigin::Signed(human).into(),
             vec![human],
             pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
         (game_id, human, ai_account)
     }
 
@@ -1916,6 +2104,7 @@ mod ai_integration_tests {
                 game_id,
                 ids.clone()
             ));
+            deal_pending();
             // Ensure it's the human's turn
             let game = GameStorage::<Test>::get(&game_id).unwrap();
             let human_idx = if game.players[0] == human { 0 } else { 1 };
@@ -1927,7 +2116,8 @@ mod ai_integration_tests {
                 game_id,
                 0,
                 0,
-                0
+                0,
+                None,
             ));
             // After the move: after AI auto-move, turn returns to human and board updated
             let updated = GameStorage::<Test>::get(&game_id).unwrap();
@@ -1955,29 +2145,11 @@ mod ai_integration_tests {
                 game_id,
                 ids.clone()
             ));
+            deal_pending();
             // Ensure both hands exist
             let game = GameStorage::<Test>::get(&game_id).unwrap();
-            // Use the AI adapter to map state explicitly (avoid relying on non-existent EterraState::from_game)
-            // Map board: crate Card -> adapter Card
-            let mut board: [[Option<ai::Card>; 4]; 4] =
-                core::array::from_fn(|_| core::array::from_fn(|_| None));
-            for x in 0..4usize {
-                for y in 0..4usize {
-                    if let Some(c) = &game.board[x][y] {
-                        let possession = c.get_possession().cloned().map(|p| match p {
-                            Player::PlayerOne => ai::Possession::PlayerOne,
-                            Player::PlayerTwo => ai::Possession::PlayerTwo,
-                        });
-                        board[x][y] = Some(ai::Card {
-                            top: c.top,
-                            right: c.right,
-                            bottom: c.bottom,
-                            left: c.left,
-                            possession,
-                        });
-                    }
-                }
-            }
+            // Board and cards are shared with the adapter via eterra-rules
+            let board = game.board.clone();
 
             // Map hands from on-chain storage into adapter hands
             let human_hand_bv = HandsOfGame::<Test>::get(&game_id, &human)
@@ -1998,17 +2170,17 @@ mod ai_integration_tests {
 
             let to_adapter_hand =
                 |bv: &BoundedVec<crate::pallet::HandEntry, crate::pallet::HandLimit>| -> ai::Hand {
-                    let entries: [ai::HandEntry; 5] = core::array::from_fn(|i| {
-                        let he = &bv[i];
-                        ai::HandEntry {
+                    let entries: Vec<ai::HandEntry> = bv
+                        .iter()
+                        .map(|he| ai::HandEntry {
                             north: he.north,
                             east: he.east,
                             south: he.south,
                             west: he.west,
                             used: he.used,
-                        }
-                    });
-                    ai::Hand { entries }
+                        })
+                        .collect();
+                    ai::Hand::new(&entries)
                 };
 
             let hands = [
@@ -2031,10 +2203,12 @@ mod ai_integration_tests {
                 round: game.round,
                 max_rounds: game.max_rounds,
                 hands,
+                rules: Default::default(),
+                elements: Default::default(),
             };
 
             let diff = <Test as crate::Config>::AiDifficulty::get();
-            let suggestion = mc_ai::Pallet::<Test>::suggest::<ai::Adapter>(&state, diff);
+            let suggestion = mc_ai::Pallet::<Test>::suggest::<ai::Adapter>(&state, diff, 0);
             assert!(
                 suggestion.is_some(),
                 "Monte Carlo AI should produce a suggestion"
@@ -2068,7 +2242,14 @@ fn multiple_pve_games_have_independent_ai_state() {
             RawOrigin::Signed(human1).into(),
             vec![human1],
             pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
 
         // Game B
         let current_block_b = <frame_system::Pallet<Test>>::block_number();
@@ -2078,7 +2259,14 @@ fn multiple_pve_games_have_independent_ai_state() {
             RawOrigin::Signed(human2).into(),
             vec![human2],
             pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
 
         // AI hands should start with all entries unused
         let ai_hand_a_initial =
@@ -2096,7 +2284,7 @@ fn multiple_pve_games_have_independent_ai_state() {
             "AI hand B should start unused"
         );
 
-        // --- Submit human hands (AI hand was auto-generated at game creation) ---
+        // --- Submit human hands (AI hands were dealt with the games) ---
         let ids1 = mint_cards_for(human1, 5);
         let ids2 = mint_cards_for(human2, 5);
         assert_ok!(Eterra::submit_hand(
@@ -2109,6 +2297,7 @@ fn multiple_pve_games_have_independent_ai_state() {
             game_id_b,
             ids2
         ));
+        deal_pending();
 
         // --- Human1 plays one move in Game A ---
         assert_ok!(Eterra::play_from_hand(
@@ -2117,6 +2306,7 @@ fn multiple_pve_games_have_independent_ai_state() {
             0,
             0,
             0,
+            None,
         ));
         // After human1 plays, AI in Game A should auto-move once.
         let g_a_after_h1 = GameStorage::<Test>::get(&game_id_a).unwrap();
@@ -2143,6 +2333,7 @@ fn multiple_pve_games_have_independent_ai_state() {
             0,
             0,
             0,
+            None,
         ));
         // After human2 plays, AI in Game B should auto-move once.
         let g_b_after_h2 = GameStorage::<Test>::get(&game_id_b).unwrap();
@@ -2182,13 +2373,26 @@ fn creator_cannot_start_second_pvp_game_while_active() {
             RawOrigin::Signed(creator).into(),
             vec![creator, opponent_a],
             pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
 
         // Attempt to start a second PvP game while the first is still active must fail.
         let res = Eterra::create_game(
             RawOrigin::Signed(creator).into(),
             vec![creator, opponent_b],
             pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         );
         assert_noop!(res, crate::Error::<Test>::PlayerAlreadyInGame);
 
@@ -2197,7 +2401,14 @@ fn creator_cannot_start_second_pvp_game_while_active() {
             RawOrigin::Signed(opponent_b).into(),
             vec![opponent_b, 4u64],
             pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
     });
 }
 
@@ -2214,13 +2425,26 @@ fn creator_cannot_start_second_pve_game_while_active() {
             RawOrigin::Signed(human).into(),
             vec![human],
             pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
 
         // Attempt to start a second PvE game for the same human while the first is active must fail.
         let res = Eterra::create_game(
             RawOrigin::Signed(human).into(),
             vec![human],
             pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         );
         assert_noop!(res, crate::Error::<Test>::PlayerAlreadyInGame);
 
@@ -2231,7 +2455,14 @@ fn creator_cannot_start_second_pve_game_while_active() {
             RawOrigin::Signed(other_human).into(),
             vec![other_human],
             pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
         ));
+        deal_pending();
     });
 }
 
@@ -2248,8 +2479,8 @@ fn create_from_matchmaking_creates_game_and_emits_event() {
         set_dummy_hand::<Test>(&b);
 
         // Call through the matchmaker trait (this is what the matchmaker pallet uses).
-        let game_id =
-            <P as GameCreator<Acc>>::create_from_matchmaking(&a, &b).expect("should create a game");
+        let game_id = <P as GameCreator<Acc>>::create_from_matchmaking(&a, &b, &Default::default())
+            .expect("should create a game");
 
         // Storage should contain the game
         assert!(crate::GameStorage::<Test>::contains_key(&game_id));
@@ -2257,13 +2488,16 @@ fn create_from_matchmaking_creates_game_and_emits_event() {
         assert_eq!(crate::ActiveGameOf::<Test>::get(&a), Some(game_id));
         assert_eq!(crate::ActiveGameOf::<Test>::get(&b), Some(game_id));
 
-        // Last event should be GameCreated { game_id }
+        // Last event should be GameCreated { game_id, ai_profile: None }
         let ev = frame_system::Pallet::<Test>::events()
             .last()
             .map(|r| r.event.clone())
             .expect("some event expected");
         match ev {
-            RuntimeEvent::Eterra(crate::Event::GameCreated { game_id: gid }) => {
+            RuntimeEvent::Eterra(crate::Event::GameCreated {
+                game_id: gid,
+                ai_profile: None,
+            }) => {
                 assert_eq!(gid, game_id)
             }
             other => panic!("unexpected event: {:?}", other),
@@ -2283,11 +2517,14 @@ fn create_from_matchmaking_requires_preset_hands() {
         // Only give `a` a hand; `b` lacks one.
         set_dummy_hand::<Test>(&a);
 
-        let res = <P as GameCreator<Acc>>::create_from_matchmaking(&a, &b);
-        // Should error with PresetHandMissing (your pallet's error)
+        let res = <P as GameCreator<Acc>>::create_from_matchmaking(&a, &b, &Default::default());
+        // Should error with PresetHandMissing, blaming `b` so the matchmaker can requeue `a`
         assert_err!(
             res,
-            DispatchError::from(crate::Error::<Test>::PresetHandMissing)
+            MatchError {
+                culprit: Some(b),
+                reason: crate::Error::<Test>::PresetHandMissing.into(),
+            }
         );
 
         // No game should exist and no ActiveGameOf should be set
@@ -2295,3 +2532,2052 @@ fn create_from_matchmaking_requires_preset_hands() {
         assert_eq!(crate::ActiveGameOf::<Test>::get(&b), None);
     });
 }
+
+#[test]
+fn create_from_matchmaking_blames_player_already_in_game() {
+    new_test_ext().execute_with(|| {
+        type P = crate::Pallet<Test>;
+        type Acc = <Test as frame_system::Config>::AccountId;
+
+        let (game_id, busy, _) = setup_new_game();
+        let free: Acc = 9;
+        set_dummy_hand::<Test>(&busy);
+        set_dummy_hand::<Test>(&free);
+
+        assert_err!(
+            <P as GameCreator<Acc>>::create_from_matchmaking(&free, &busy, &Default::default()),
+            MatchError {
+                culprit: Some(busy),
+                reason: crate::Error::<Test>::PlayerAlreadyInGame.into(),
+            }
+        );
+        assert_eq!(crate::ActiveGameOf::<Test>::get(&busy), Some(game_id));
+        assert_eq!(crate::ActiveGameOf::<Test>::get(&free), None);
+    });
+}
+
+#[test]
+fn create_from_matchmaking_applies_the_queue_settings() {
+    new_test_ext().execute_with(|| {
+        type P = crate::Pallet<Test>;
+        type Acc = <Test as frame_system::Config>::AccountId;
+
+        let (a, b): (Acc, Acc) = (1, 2);
+        ensure_preset_hand(a);
+        ensure_preset_hand(b);
+        let settings = crate::MatchSettings {
+            rules: crate::RuleSet {
+                same: true,
+                ..Default::default()
+            },
+            board_size: crate::BoardSize::new(3, 3),
+            stake: 1_000,
+            trade_rule: crate::TradeRule::One,
+            hand_visibility: crate::HandVisibility::Open,
+            casual: true,
+        };
+
+        let game_id = <P as GameCreator<Acc>>::create_from_matchmaking(&a, &b, &settings)
+            .expect("should create a game");
+        deal_pending();
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!((game.board.width(), game.board.height()), (3, 3));
+        assert!(Eterra::game_rules_of(game_id).same);
+        assert_eq!(Eterra::trade_rule_of(game_id), Some(crate::TradeRule::One));
+        assert_eq!(Eterra::stake_of(game_id), Some(1_000));
+        assert_eq!((staked(a), staked(b)), (1_000, 1_000));
+        assert!(crate::CasualGames::<Test>::contains_key(game_id));
+
+        // Casual games leave ratings alone.
+        assert_ok!(Eterra::resign(RawOrigin::Signed(a).into(), game_id));
+        assert_eq!(EterraRating::rating_of(b), Default::default());
+    });
+}
+
+#[test]
+fn create_from_matchmaking_rejects_unplayable_settings_for_the_pair() {
+    new_test_ext().execute_with(|| {
+        type P = crate::Pallet<Test>;
+        type Acc = <Test as frame_system::Config>::AccountId;
+
+        let (a, b): (Acc, Acc) = (1, 2);
+        set_dummy_hand::<Test>(&a);
+        set_dummy_hand::<Test>(&b);
+        let settings = crate::MatchSettings {
+            trade_rule: crate::TradeRule::All,
+            hand_visibility: crate::HandVisibility::Hidden,
+            ..Default::default()
+        };
+
+        assert_err!(
+            <P as GameCreator<Acc>>::create_from_matchmaking(&a, &b, &settings),
+            MatchError {
+                culprit: None,
+                reason: crate::Error::<Test>::TradeRequiresOpenHands.into(),
+            }
+        );
+        assert_eq!(crate::ActiveGameOf::<Test>::get(&a), None);
+    });
+}
+
+#[test]
+fn game_view_mirrors_storage_and_mode() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+
+        let view = Eterra::game_view(&game_id).expect("view for existing game");
+        let game = Eterra::game_board(game_id).unwrap();
+        assert_eq!(view.mode, Some(pallet::GameMode::PvP));
+        assert_eq!(view.players, vec![creator, opponent]);
+        assert_eq!(view.player_turn, game.player_turn);
+        assert_eq!(view.scores, game.scores);
+        assert_eq!(view.board, game.board);
+
+        assert!(Eterra::game_view(&H256::repeat_byte(7)).is_none());
+        assert_eq!(Eterra::active_game(&creator), Some(game_id));
+        assert_eq!(Eterra::recent_games(&opponent), vec![game_id]);
+    });
+}
+
+#[test]
+fn hands_and_legal_moves_follow_submissions() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert!(Eterra::hands(&game_id).is_empty());
+
+        assert_ok!(Eterra::submit_hand(
+            frame_system::RawOrigin::Signed(creator).into(),
+            game_id,
+            vec![],
+        ));
+        let hands = Eterra::hands(&game_id);
+        assert_eq!(hands.len(), 1);
+        assert_eq!(hands[0].player, creator);
+
+        give_turn_to(game_id, creator);
+        let game = Eterra::game_board(game_id).unwrap();
+        let empty = game.board.iter().flatten().filter(|c| c.is_none()).count();
+        let moves = Eterra::legal_moves(&game_id);
+        assert_eq!(moves.len(), empty * 5);
+
+        // Every listed move must be accepted by play_from_hand.
+        let mv = moves[moves.len() - 1];
+        assert_ok!(Eterra::play_from_hand(
+            frame_system::RawOrigin::Signed(creator).into(),
+            game_id,
+            mv.hand_index,
+            mv.x,
+            mv.y,
+            None,
+        ));
+
+        // Opponent has no hand yet, so nothing is playable from hand.
+        assert!(Eterra::legal_moves(&game_id).is_empty());
+    });
+}
+
+/// Seat `who` to move with a single 9-9-9-9 card in hand and an opponent card at (1,0).
+fn setup_preview_board(game_id: H256, who: u64) {
+    GameStorage::<Test>::mutate(game_id, |g| {
+        let g = g.as_mut().unwrap();
+        let me = if g.players[0] == who { 0 } else { 1 };
+        let them = if me == 0 {
+            Player::PlayerTwo
+        } else {
+            Player::PlayerOne
+        };
+        g.player_turn = me;
+        g.board[1][0] = Some(Card::new(1, 1, 1, 1).with_possession(them));
+    });
+    let hand: BoundedVec<crate::HandEntry, crate::HandLimit> = vec![crate::HandEntry {
+        card_id: 1,
+        north: 9,
+        east: 9,
+        south: 9,
+        west: 9,
+        used: false,
+    }]
+    .try_into()
+    .unwrap();
+    HandsOfGame::<Test>::insert(game_id, who, hand);
+}
+
+#[test]
+fn preview_move_reports_flips_without_writing() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, _opponent) = setup_new_game();
+        setup_preview_board(game_id, creator);
+        let before = Eterra::game_board(game_id).unwrap();
+
+        let preview = Eterra::preview_move(&game_id, 0, 0, 0).expect("legal move");
+        assert_eq!(preview.flipped, vec![(1, 0)]);
+        let creator_ix = if before.players[0] == creator { 0 } else { 1 };
+        if creator_ix == 0 {
+            assert_eq!(preview.score_delta, (1, -1));
+        } else {
+            assert_eq!(preview.score_delta, (-1, 1));
+        }
+        assert!(preview.board[0][0].is_some());
+
+        // Nothing was persisted.
+        let after = Eterra::game_board(game_id).unwrap();
+        assert_eq!(after.board, before.board);
+        assert_eq!(after.scores, before.scores);
+        assert!(!HandsOfGame::<Test>::get(game_id, creator).unwrap()[0].used);
+    });
+}
+
+#[test]
+fn preview_move_rejects_illegal_moves() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, _opponent) = setup_new_game();
+        setup_preview_board(game_id, creator);
+
+        assert_eq!(
+            Eterra::preview_move(&game_id, 0, 1, 0),
+            Err(crate::Error::<Test>::CellOccupied.into())
+        );
+        assert_eq!(
+            Eterra::preview_move(&game_id, 0, 4, 0),
+            Err(crate::Error::<Test>::InvalidMove.into())
+        );
+        assert_eq!(
+            Eterra::preview_move(&game_id, 3, 0, 0),
+            Err(crate::Error::<Test>::HandIndexOutOfRange.into())
+        );
+    });
+}
+
+#[test]
+fn create_game_stores_rules_and_rolls_elements_only_when_elemental() {
+    new_test_ext().execute_with(|| {
+        let (basic_id, _, _) = setup_new_game();
+        assert_eq!(Eterra::game_rules_of(basic_id), crate::RuleSet::default());
+        assert!(Eterra::game_elements_of(basic_id).is_none());
+
+        let human = 30;
+        ensure_preset_hand(human);
+        let rules = crate::RuleSet {
+            same: true,
+            elemental: true,
+            ..Default::default()
+        };
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+            rules,
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
+        ));
+        let game_id = Eterra::active_game_of(human).unwrap();
+        // Elements are only rolled once the game is dealt
+        assert!(Eterra::game_elements_of(game_id).is_none());
+        deal_pending();
+        assert_eq!(Eterra::game_rules_of(game_id), rules);
+        let elements = Eterra::game_elements_of(game_id).expect("elements rolled");
+        assert!(elements.iter().flatten().all(|e| (-1..=1).contains(e)));
+    });
+}
+
+#[test]
+fn same_and_combo_rules_chain_captures() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, _opponent) = setup_new_game();
+        crate::GameRules::<Test>::insert(
+            game_id,
+            crate::RuleSet {
+                same: true,
+                combo: true,
+                ..Default::default()
+            },
+        );
+        let creator_ix = GameStorage::<Test>::mutate(game_id, |g| {
+            let g = g.as_mut().unwrap();
+            let me = if g.players[0] == creator { 0 } else { 1 };
+            let them = if me == 0 {
+                Player::PlayerTwo
+            } else {
+                Player::PlayerOne
+            };
+            g.player_turn = me;
+            // Both neighbours of (0,0) tie with the placed card; (2,0) is only reachable by combo.
+            g.board[1][0] = Some(Card::new(3, 3, 3, 5).with_possession(them.clone()));
+            g.board[0][1] = Some(Card::new(4, 3, 3, 3).with_possession(them.clone()));
+            g.board[2][0] = Some(Card::new(1, 1, 1, 1).with_possession(them));
+            me
+        });
+        let hand: BoundedVec<crate::HandEntry, crate::HandLimit> = vec![crate::HandEntry {
+            card_id: 1,
+            north: 1,
+            east: 5,
+            south: 4,
+            west: 1,
+            used: false,
+        }]
+        .try_into()
+        .unwrap();
+        HandsOfGame::<Test>::insert(game_id, creator, hand);
+
+        let preview = Eterra::preview_move(&game_id, 0, 0, 0).unwrap();
+        assert_eq!(preview.flipped, vec![(1, 0), (0, 1), (2, 0)]);
+
+        assert_ok!(Eterra::play_from_hand(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            0,
+            0,
+            0,
+            None,
+        ));
+        let game = Eterra::game_board(game_id).unwrap();
+        let mine = if creator_ix == 0 {
+            Player::PlayerOne
+        } else {
+            Player::PlayerTwo
+        };
+        for (x, y) in [(1usize, 0usize), (0, 1), (2, 0)] {
+            assert_eq!(
+                game.board[x][y].as_ref().unwrap().possession,
+                Some(mine.clone())
+            );
+        }
+    });
+}
+
+fn create_sized_game(width: u8, height: u8) -> (H256, u64, u64) {
+    let (creator, opponent) = (1, 2);
+    ensure_preset_hand(creator);
+    ensure_preset_hand(opponent);
+    assert_ok!(Eterra::create_game(
+        RawOrigin::Signed(creator).into(),
+        vec![creator, opponent],
+        pallet::GameMode::PvP,
+        crate::RuleSet::default(),
+        crate::BoardSize::new(width, height),
+        0,
+        crate::TradeRule::None,
+        None,
+        crate::HandVisibility::Open,
+    ));
+    deal_pending();
+    (Eterra::active_game_of(creator).unwrap(), creator, opponent)
+}
+
+#[test]
+fn create_game_rejects_unsupported_board_sizes() {
+    new_test_ext().execute_with(|| {
+        ensure_preset_hand(1);
+        ensure_preset_hand(2);
+        for (w, h) in [(2u8, 4u8), (4, 2), (6, 4), (4, 6)] {
+            assert_noop!(
+                Eterra::create_game(
+                    RawOrigin::Signed(1).into(),
+                    vec![1, 2],
+                    pallet::GameMode::PvP,
+                    crate::RuleSet::default(),
+                    crate::BoardSize::new(w, h),
+                    0,
+                    crate::TradeRule::None,
+                    None,
+                    crate::HandVisibility::Open,
+                ),
+                crate::Error::<Test>::InvalidBoardSize
+            );
+        }
+    });
+}
+
+#[test]
+fn five_by_five_board_accepts_outer_cells() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, _) = create_sized_game(5, 5);
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!((game.board.width(), game.board.height()), (5, 5));
+        assert_eq!(
+            game.max_rounds,
+            <Test as crate::Config>::HandSize::get() as u8
+        );
+
+        assert_ok!(Eterra::play(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            Move {
+                place_index_x: 4,
+                place_index_y: 4,
+                place_card: Card::new(1, 1, 1, 1),
+            },
+        ));
+        assert!(GameStorage::<Test>::get(&game_id).unwrap().board[4][4].is_some());
+    });
+}
+
+#[test]
+fn three_by_three_game_ends_when_board_fills() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = create_sized_game(3, 3);
+        assert_noop!(
+            Eterra::play(
+                RawOrigin::Signed(creator).into(),
+                game_id,
+                Move {
+                    place_index_x: 3,
+                    place_index_y: 0,
+                    place_card: Card::new(1, 1, 1, 1),
+                },
+            ),
+            crate::Error::<Test>::InvalidMove
+        );
+
+        for i in 0..9u8 {
+            let who = if i % 2 == 0 { creator } else { opponent };
+            assert_ok!(Eterra::play(
+                RawOrigin::Signed(who).into(),
+                game_id,
+                Move {
+                    place_index_x: i % 3,
+                    place_index_y: i / 3,
+                    place_card: Card::new(1, 1, 1, 1),
+                },
+            ));
+        }
+
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert!(game.board.is_full());
+        assert!(matches!(
+            game.state,
+            crate::types::game::GameState::Finished { .. }
+        ));
+    });
+}
+
+#[test]
+fn v1_migration_resizes_stored_boards() {
+    use crate::migrations::v1::{MigrateV0ToV1, OldGame};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Eterra>();
+        let game_id = H256::repeat_byte(7);
+
+        let mut old_board: [[Option<Card>; 4]; 4] = Default::default();
+        old_board[3][2] = Some(Card::new(4, 3, 2, 1).with_possession(Player::PlayerTwo));
+        let old = OldGame::<u64, u64, <Test as crate::Config>::NumPlayers> {
+            state: crate::types::game::GameState::Playing,
+            last_played_block: 1,
+            players: vec![1, 2].try_into().unwrap(),
+            player_turn: 1,
+            round: 2,
+            max_rounds: 5,
+            board: old_board,
+            scores: (4, 6),
+        };
+        frame_support::storage::unhashed::put(&GameStorage::<Test>::hashed_key_for(game_id), &old);
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Eterra::on_chain_storage_version(), StorageVersion::new(1));
+        let game = GameStorage::<Test>::get(&game_id).expect("game survives migration");
+        assert_eq!((game.board.width(), game.board.height()), (4, 4));
+        assert_eq!(
+            game.board[3][2],
+            Some(Card::new(4, 3, 2, 1).with_possession(Player::PlayerTwo))
+        );
+        assert_eq!((game.round, game.scores), (2, (4, 6)));
+    });
+}
+
+/// Advance block by block up to `n`, running `on_initialize` for each new block.
+fn run_to_block_with_hooks(n: u64) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        Eterra::on_initialize(System::block_number());
+    }
+}
+
+#[test]
+fn on_initialize_skips_timed_out_turn() {
+    new_test_ext().execute_with(|| {
+        let (game_id, _, _) = setup_new_game();
+        let limit = <Test as crate::Config>::BlocksToPlayLimit::get() as u64;
+        let before = GameStorage::<Test>::get(&game_id).unwrap();
+        let stalled = before.players[before.player_turn as usize];
+        assert_eq!(Eterra::turn_timeouts(1 + limit).to_vec(), vec![game_id]);
+
+        run_to_block_with_hooks(limit);
+        assert_eq!(
+            GameStorage::<Test>::get(&game_id).unwrap().player_turn,
+            before.player_turn
+        );
+
+        run_to_block_with_hooks(1 + limit);
+        let after = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_ne!(after.player_turn, before.player_turn);
+        assert_eq!(after.last_played_block, 1 + limit);
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::TurnTimedOut {
+            game_id,
+            player: stalled,
+        }));
+        assert!(Eterra::turn_timeouts(1 + limit).is_empty());
+        assert_eq!(Eterra::turn_timeouts(1 + 2 * limit).to_vec(), vec![game_id]);
+    });
+}
+
+#[test]
+fn turn_played_in_time_leaves_stale_timeout_inert() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let limit = <Test as crate::Config>::BlocksToPlayLimit::get() as u64;
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        let mover = if game.players[game.player_turn as usize] == creator {
+            creator
+        } else {
+            opponent
+        };
+
+        run_to_block_with_hooks(3);
+        assert_ok!(Eterra::play(
+            RawOrigin::Signed(mover).into(),
+            game_id,
+            Move {
+                place_index_x: 0,
+                place_index_y: 0,
+                place_card: Card::new(1, 1, 1, 1),
+            },
+        ));
+        let played = GameStorage::<Test>::get(&game_id).unwrap();
+
+        // The original deadline passes without touching the game
+        run_to_block_with_hooks(1 + limit);
+        let unchanged = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(unchanged.player_turn, played.player_turn);
+        assert_eq!(unchanged.last_played_block, 3);
+
+        // The deadline of the new turn still applies
+        run_to_block_with_hooks(3 + limit);
+        assert_ne!(
+            GameStorage::<Test>::get(&game_id).unwrap().player_turn,
+            played.player_turn
+        );
+    });
+}
+
+#[test]
+fn repeated_timeouts_finish_the_game() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        let limit = <Test as crate::Config>::BlocksToPlayLimit::get() as u64;
+        let max_rounds = GameStorage::<Test>::get(&game_id).unwrap().max_rounds as u64;
+
+        run_to_block_with_hooks(1 + limit * 2 * max_rounds);
+
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(game.round as u64, max_rounds);
+        assert_eq!(
+            game.state,
+            crate::types::game::GameState::Finished { winner: None }
+        );
+        assert!(Eterra::active_game_of(creator).is_none());
+        assert!(Eterra::active_game_of(opponent).is_none());
+    });
+}
+
+#[test]
+fn on_initialize_charges_what_each_timeout_sets_off() {
+    use crate::weights::WeightInfo;
+
+    new_test_ext().execute_with(|| {
+        let limit = <Test as crate::Config>::BlocksToPlayLimit::get() as u64;
+        let (game_id, _, _) = setup_new_game();
+        let human = 30;
+        ensure_preset_hand(human);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
+        ));
+        deal_pending();
+        let pve_id = Eterra::active_game_of(human).unwrap();
+        assert_ok!(Eterra::submit_hand(
+            RawOrigin::Signed(human).into(),
+            pve_id,
+            vec![]
+        ));
+        deal_pending();
+
+        // The PvP skip is the last turn of the game; the PvE skip hands the move to the AI
+        GameStorage::<Test>::mutate(&game_id, |game| {
+            let game = game.as_mut().unwrap();
+            game.player_turn = 1;
+            game.round = game.max_rounds - 1;
+        });
+        System::set_block_number(1 + limit);
+        let weight = Eterra::on_initialize(1 + limit);
+
+        assert!(Eterra::active_game_of(human).is_some());
+        assert_eq!(Eterra::move_log_of(pve_id).len(), 1);
+        assert!(matches!(
+            GameStorage::<Test>::get(&game_id).unwrap().state,
+            crate::types::game::GameState::Finished { .. }
+        ));
+        assert_eq!(
+            weight,
+            <() as WeightInfo>::deal_games(0)
+                + <() as WeightInfo>::process_timeouts(2)
+                + <() as WeightInfo>::timeout_end_game()
+                + <() as WeightInfo>::timeout_ai_turn()
+        );
+    });
+}
+
+#[test]
+fn turn_timeouts_spill_into_next_block_when_full() {
+    new_test_ext().execute_with(|| {
+        let limit = <Test as crate::Config>::BlocksToPlayLimit::get() as u64;
+        let mut ids = Vec::new();
+        for (a, b) in [(1u64, 2u64), (3, 4), (5, 6)] {
+            ensure_preset_hand(a);
+            ensure_preset_hand(b);
+            assert_ok!(Eterra::create_game(
+                RawOrigin::Signed(a).into(),
+                vec![a, b],
+                pallet::GameMode::PvP,
+                crate::RuleSet::default(),
+                crate::BoardSize::default(),
+                0,
+                crate::TradeRule::None,
+                None,
+                crate::HandVisibility::Open,
+            ));
+            deal_pending();
+            ids.push(Eterra::active_game_of(a).unwrap());
+        }
+
+        // MaxTimeoutsPerBlock is 2 in the mock
+        assert_eq!(Eterra::turn_timeouts(1 + limit).to_vec(), ids[..2].to_vec());
+        assert_eq!(Eterra::turn_timeouts(2 + limit).to_vec(), ids[2..].to_vec());
+
+        run_to_block_with_hooks(2 + limit);
+        for id in ids {
+            assert!(System::events().iter().any(|r| matches!(
+                &r.event,
+                RuntimeEvent::Eterra(crate::Event::TurnTimedOut { game_id, .. }) if *game_id == id
+            )));
+        }
+    });
+}
+
+#[test]
+fn games_are_dealt_in_a_block_after_they_are_created() {
+    new_test_ext().execute_with(|| {
+        let (creator, opponent) = (1, 2);
+        ensure_preset_hand(creator);
+        ensure_preset_hand(opponent);
+        System::set_block_number(1);
+        let game_id = BlakeTwo256::hash_of(&(creator, opponent, 1u64));
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(creator).into(),
+            vec![creator, opponent],
+            pallet::GameMode::PvP,
+            crate::RuleSet {
+                elemental: true,
+                ..Default::default()
+            },
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
+        ));
+        assert_eq!(Eterra::pending_deals().to_vec(), vec![(game_id, 1)]);
+        assert_eq!(
+            GameStorage::<Test>::get(&game_id).unwrap().state,
+            crate::types::game::GameState::Dealing
+        );
+        assert!(Eterra::game_elements_of(game_id).is_none());
+        assert_noop!(
+            Eterra::resign(RawOrigin::Signed(creator).into(), game_id),
+            crate::Error::<Test>::GameNotPlaying
+        );
+
+        // The seed is refreshed after this pallet's hook, so block 2 still draws from block 1.
+        for n in 2..=3 {
+            System::set_block_number(n);
+            <AllPalletsWithSystem as frame_support::traits::OnInitialize<u64>>::on_initialize(n);
+            let dealt = n == 3;
+            assert_eq!(Eterra::pending_deals().is_empty(), dealt);
+            assert_eq!(Eterra::game_elements_of(game_id).is_some(), dealt);
+        }
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(game.state, crate::types::game::GameState::Playing);
+        assert_eq!(game.last_played_block, 3);
+        assert_ok!(Eterra::resign(RawOrigin::Signed(creator).into(), game_id));
+    });
+}
+
+#[test]
+fn resign_awards_the_opponent_and_frees_both_players() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::resign(RawOrigin::Signed(creator).into(), game_id));
+
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(
+            game.state,
+            crate::types::game::GameState::Finished { winner: Some(1) }
+        );
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::GameResigned {
+            game_id,
+            player: creator,
+        }));
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::GameFinished {
+            game_id,
+            winner: Some(opponent),
+        }));
+        assert!(Eterra::active_game_of(creator).is_none());
+        assert!(Eterra::active_game_of(opponent).is_none());
+
+        assert_noop!(
+            Eterra::resign(RawOrigin::Signed(opponent).into(), game_id),
+            crate::Error::<Test>::GameNotPlaying
+        );
+        assert_noop!(
+            Eterra::resign(RawOrigin::Signed(3).into(), game_id),
+            crate::Error::<Test>::PlayerNotInGame
+        );
+    });
+}
+
+#[test]
+fn draw_needs_an_offer_from_the_other_player() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_noop!(
+            Eterra::accept_draw(RawOrigin::Signed(opponent).into(), game_id),
+            crate::Error::<Test>::NoDrawOffer
+        );
+
+        assert_ok!(Eterra::offer_draw(
+            RawOrigin::Signed(creator).into(),
+            game_id
+        ));
+        assert_eq!(Eterra::draw_offer_of(game_id), Some(creator));
+        assert_noop!(
+            Eterra::accept_draw(RawOrigin::Signed(creator).into(), game_id),
+            crate::Error::<Test>::CannotAcceptOwnDrawOffer
+        );
+
+        assert_ok!(Eterra::accept_draw(
+            RawOrigin::Signed(opponent).into(),
+            game_id
+        ));
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(
+            game.state,
+            crate::types::game::GameState::Finished { winner: None }
+        );
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::DrawAgreed { game_id }));
+        assert!(Eterra::draw_offer_of(game_id).is_none());
+        assert!(Eterra::active_game_of(creator).is_none());
+    });
+}
+
+#[test]
+fn abandon_is_pve_only_and_hands_the_win_to_the_ai() {
+    new_test_ext().execute_with(|| {
+        let (pvp_id, creator, _) = setup_new_game();
+        assert_noop!(
+            Eterra::abandon(RawOrigin::Signed(creator).into(), pvp_id),
+            crate::Error::<Test>::NotPvEGame
+        );
+
+        let human = 30;
+        ensure_preset_hand(human);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
+        ));
+        deal_pending();
+        let game_id = Eterra::active_game_of(human).unwrap();
+        assert_noop!(
+            Eterra::offer_draw(RawOrigin::Signed(human).into(), game_id),
+            crate::Error::<Test>::NotPvPGame
+        );
+
+        assert_ok!(Eterra::abandon(RawOrigin::Signed(human).into(), game_id));
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(
+            game.state,
+            crate::types::game::GameState::Finished { winner: Some(1) }
+        );
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::GameAbandoned {
+            game_id,
+            player: human,
+        }));
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::GameFinished {
+            game_id,
+            winner: Some(<Test as crate::Config>::AiAccount::get()),
+        }));
+        assert!(Eterra::active_game_of(human).is_none());
+    });
+}
+
+#[test]
+fn finished_games_feed_ratings_and_pve_records() {
+    use pallet_eterra_rating::Outcome;
+    use sp_runtime::FixedI64;
+
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::resign(RawOrigin::Signed(creator).into(), game_id));
+
+        let (winner, loser) = (
+            EterraRating::rating_of(opponent),
+            EterraRating::rating_of(creator),
+        );
+        assert!(winner.rating > FixedI64::from_u32(1500));
+        assert!(loser.rating < FixedI64::from_u32(1500));
+        assert_eq!((winner.record.wins, loser.record.losses), (1, 1));
+        System::assert_has_event(RuntimeEvent::EterraRating(
+            pallet_eterra_rating::Event::RatingUpdated {
+                who: opponent,
+                outcome: Outcome::Win,
+                rating: winner.rating,
+                deviation: winner.deviation,
+            },
+        ));
+
+        // Games against the AI only count towards the human's PvE record.
+        let human = 31;
+        ensure_preset_hand(human);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
+        ));
+        deal_pending();
+        let game_id = Eterra::active_game_of(human).unwrap();
+        assert_ok!(Eterra::abandon(RawOrigin::Signed(human).into(), game_id));
+
+        assert_eq!(EterraRating::pve_record_of(human).losses, 1);
+        assert_eq!(EterraRating::rating_of(human), Default::default());
+        let ai = <Test as crate::Config>::AiAccount::get();
+        assert_eq!(EterraRating::pve_record_of(ai), Default::default());
+    });
+}
+
+#[test]
+fn finished_games_are_archived_once_retention_passes() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::submit_hand(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            vec![]
+        ));
+        run_to_block(2);
+        assert_ok!(Eterra::resign(RawOrigin::Signed(opponent).into(), game_id));
+        assert_eq!(Eterra::pending_prunes(2).to_vec(), vec![game_id]);
+
+        let retention = <Test as crate::Config>::FinishedGameRetention::get();
+        Eterra::on_idle(1 + retention, frame_support::weights::Weight::MAX);
+        assert!(GameStorage::<Test>::contains_key(&game_id));
+        assert!(Eterra::game_summary_of(game_id).is_none());
+
+        Eterra::on_idle(2 + retention, frame_support::weights::Weight::MAX);
+        assert!(!GameStorage::<Test>::contains_key(&game_id));
+        assert!(Eterra::game_mode_of(game_id).is_none());
+        assert!(HandsOfGame::<Test>::get(&game_id, &creator).is_none());
+        assert!(Eterra::pending_prunes(2).is_empty());
+        assert_eq!(Eterra::prune_cursor(), Some(3));
+
+        let summary = Eterra::game_summary_of(game_id).expect("archived");
+        assert_eq!(summary.players.to_vec(), vec![creator, opponent]);
+        assert_eq!(summary.scores, (5, 5));
+        assert_eq!(summary.winner, Some(0));
+        assert_eq!(summary.rules, crate::RuleSet::default());
+        assert_eq!(summary.moves, 0);
+        assert_eq!(summary.finished_at, 2);
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::GameArchived { game_id }));
+    });
+}
+
+#[test]
+fn on_idle_pruning_stays_within_the_weight_limit() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, _) = setup_new_game();
+        assert_ok!(Eterra::resign(RawOrigin::Signed(creator).into(), game_id));
+        let retention = <Test as crate::Config>::FinishedGameRetention::get();
+
+        assert_eq!(
+            Eterra::on_idle(1 + retention, frame_support::weights::Weight::zero()),
+            frame_support::weights::Weight::zero()
+        );
+        assert!(GameStorage::<Test>::contains_key(&game_id));
+        assert_eq!(Eterra::prune_cursor(), Some(1));
+
+        Eterra::on_idle(1 + retention, frame_support::weights::Weight::MAX);
+        assert!(!GameStorage::<Test>::contains_key(&game_id));
+        assert!(Eterra::game_summary_of(game_id).is_some());
+    });
+}
+
+/// PvE game for account 30 with both hands in place and three rounds played via `legal_moves`.
+fn play_logged_pve_game() -> H256 {
+    let human = 30;
+    ensure_preset_hand(human);
+    assert_ok!(Eterra::create_game(
+        RawOrigin::Signed(human).into(),
+        vec![human],
+        pallet::GameMode::PvE,
+        crate::RuleSet {
+            same: true,
+            combo: true,
+            ..Default::default()
+        },
+        crate::BoardSize::default(),
+        0,
+        crate::TradeRule::None,
+        None,
+        crate::HandVisibility::Open,
+    ));
+    deal_pending();
+    let game_id = Eterra::active_game_of(human).unwrap();
+    assert_ok!(Eterra::submit_hand(
+        RawOrigin::Signed(human).into(),
+        game_id,
+        vec![]
+    ));
+    deal_pending();
+    for _ in 0..3 {
+        let mv = Eterra::legal_moves(&game_id)[0];
+        assert_ok!(Eterra::play_from_hand(
+            RawOrigin::Signed(human).into(),
+            game_id,
+            mv.hand_index,
+            mv.x,
+            mv.y,
+            None,
+        ));
+    }
+    game_id
+}
+
+#[test]
+fn move_log_records_human_and_ai_moves_and_replays() {
+    new_test_ext().execute_with(|| {
+        let game_id = play_logged_pve_game();
+
+        let log = Eterra::move_log_of(game_id);
+        assert_eq!(log.len(), 6);
+        for (i, m) in log.iter().enumerate() {
+            assert_eq!(m.player as usize, i % 2, "human and AI alternate");
+            assert!(m.hand_index.is_some());
+            assert!(m.card.possession.is_none());
+        }
+
+        let record = Eterra::game_record(&game_id).unwrap();
+        let report = crate::replay::replay(&record).expect("log reproduces the game");
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(report.moves, 6);
+        assert_eq!(report.board, game.board);
+        assert_eq!(report.scores, game.scores);
+    });
+}
+
+#[test]
+fn replay_detects_tampered_logs() {
+    use crate::replay::{replay, ReplayError};
+
+    new_test_ext().execute_with(|| {
+        let game_id = play_logged_pve_game();
+        let record = Eterra::game_record(&game_id).unwrap();
+
+        let mut bad = record.clone();
+        bad.moves[1].card.top = bad.moves[1].card.top % 10 + 1;
+        assert_eq!(replay(&bad), Err(ReplayError::HandMismatch { step: 1 }));
+
+        let mut bad = record.clone();
+        bad.moves[2].x = 9;
+        assert_eq!(replay(&bad), Err(ReplayError::OutOfBounds { step: 2 }));
+
+        let mut bad = record.clone();
+        bad.moves.pop();
+        assert_eq!(replay(&bad), Err(ReplayError::FinalStateMismatch));
+
+        let mut bad = record;
+        bad.game.scores = (0, 10);
+        assert_eq!(replay(&bad), Err(ReplayError::FinalStateMismatch));
+    });
+}
+
+#[test]
+fn scores_start_at_the_configured_hand_size() {
+    new_test_ext().execute_with(|| {
+        HandSizeConst::set(&7);
+        let game_id = play_logged_pve_game();
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        // Flips only move points between players; both hands together still hold 14 cards
+        assert_eq!(game.scores.0 + game.scores.1, 14);
+
+        let record = Eterra::game_record(&game_id).unwrap();
+        assert_eq!(record.hand_size, 7);
+        let report = crate::replay::replay(&record).expect("log reproduces the game");
+        assert_eq!(report.scores, game.scores);
+
+        let (pvp_id, _, _) = setup_new_game_with(40, 41);
+        assert_eq!(GameStorage::<Test>::get(&pvp_id).unwrap().scores, (7, 7));
+    });
+}
+
+/// Stake held from `who` for wagered games.
+fn staked(who: u64) -> u128 {
+    <Balances as frame_support::traits::fungible::InspectHold<u64>>::balance_on_hold(
+        &RuntimeHoldReason::Eterra(crate::HoldReason::GameStake),
+        &who,
+    )
+}
+
+/// Players 1 and 2 with preset hands; 1 proposes a PvP game staking `stake`.
+fn propose_wager(stake: u128) -> (H256, u64, u64) {
+    let (creator, opponent) = (1, 2);
+    ensure_preset_hand(creator);
+    ensure_preset_hand(opponent);
+    let game_id = BlakeTwo256::hash_of(&(creator, opponent, System::block_number()));
+    assert_ok!(Eterra::create_game(
+        RawOrigin::Signed(creator).into(),
+        vec![creator, opponent],
+        pallet::GameMode::PvP,
+        crate::RuleSet::default(),
+        crate::BoardSize::default(),
+        stake,
+        crate::TradeRule::None,
+        None,
+        crate::HandVisibility::Open,
+    ));
+    (game_id, creator, opponent)
+}
+
+#[test]
+fn wagered_game_waits_for_the_opponent_and_pays_the_winner() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = propose_wager(1_000);
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        assert_eq!(game.state, crate::types::game::GameState::Matchmaking);
+        assert_eq!(Eterra::stake_of(game_id), Some(1_000));
+        assert_eq!((staked(creator), staked(opponent)), (1_000, 0));
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::WagerProposed {
+            game_id,
+            opponent,
+            stake: 1_000,
+        }));
+        assert_noop!(
+            Eterra::resign(RawOrigin::Signed(creator).into(), game_id),
+            crate::Error::<Test>::GameNotPlaying
+        );
+        assert_noop!(
+            Eterra::accept_wager(RawOrigin::Signed(creator).into(), game_id),
+            crate::Error::<Test>::NotWagerOpponent
+        );
+
+        assert_ok!(Eterra::accept_wager(
+            RawOrigin::Signed(opponent).into(),
+            game_id
+        ));
+        deal_pending();
+        assert_eq!((staked(creator), staked(opponent)), (1_000, 1_000));
+        assert_noop!(
+            Eterra::accept_wager(RawOrigin::Signed(opponent).into(), game_id),
+            crate::Error::<Test>::NotPendingWager
+        );
+
+        let creator_free = Balances::free_balance(creator);
+        let opponent_free = Balances::free_balance(opponent);
+        let treasury_free = Balances::free_balance(500);
+        assert_ok!(Eterra::resign(RawOrigin::Signed(creator).into(), game_id));
+
+        // 10% house cut of the forfeited stake, the rest plus their own stake to the winner
+        assert_eq!((staked(creator), staked(opponent)), (0, 0));
+        assert_eq!(Balances::free_balance(creator), creator_free);
+        assert_eq!(Balances::free_balance(opponent), opponent_free + 1_900);
+        assert_eq!(Balances::free_balance(500), treasury_free + 100);
+        assert!(Eterra::stake_of(game_id).is_none());
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::StakesSettled {
+            game_id,
+            winner: Some(opponent),
+            payout: 900,
+            house_cut: 100,
+        }));
+    });
+}
+
+#[test]
+fn house_cut_the_treasury_cannot_take_goes_to_the_winner() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = propose_wager(1_000);
+        assert_ok!(Eterra::accept_wager(
+            RawOrigin::Signed(opponent).into(),
+            game_id
+        ));
+        deal_pending();
+        // An empty treasury can't be opened with a 100 cut below the existential deposit
+        <Balances as frame_support::traits::Currency<u64>>::make_free_balance_be(&500, 0);
+        ExistentialDeposit::set(&200);
+
+        let creator_free = Balances::free_balance(creator);
+        let opponent_free = Balances::free_balance(opponent);
+        assert_ok!(Eterra::resign(RawOrigin::Signed(creator).into(), game_id));
+
+        assert_eq!((staked(creator), staked(opponent)), (0, 0));
+        assert_eq!(Balances::free_balance(creator), creator_free);
+        assert_eq!(Balances::free_balance(opponent), opponent_free + 2_000);
+        assert_eq!(Balances::free_balance(500), 0);
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::StakesSettled {
+            game_id,
+            winner: Some(opponent),
+            payout: 1_000,
+            house_cut: 0,
+        }));
+    });
+}
+
+#[test]
+fn play_is_refused_when_cards_or_stakes_are_at_risk() {
+    new_test_ext().execute_with(|| {
+        let made_up = |x: u8| Move {
+            place_index_x: x,
+            place_index_y: 0,
+            place_card: Card::new(9, 9, 9, 9),
+        };
+        let to_move = |game_id: H256| {
+            let game = GameStorage::<Test>::get(&game_id).unwrap();
+            game.players[game.player_turn as usize]
+        };
+
+        // Wagered game
+        let (game_id, _, opponent) = propose_wager(1_000);
+        assert_ok!(Eterra::accept_wager(
+            RawOrigin::Signed(opponent).into(),
+            game_id
+        ));
+        deal_pending();
+        assert_noop!(
+            Eterra::play(
+                RawOrigin::Signed(to_move(game_id)).into(),
+                game_id,
+                made_up(0)
+            ),
+            crate::Error::<Test>::PlayFromHandRequired
+        );
+        assert_ok!(Eterra::resign(RawOrigin::Signed(opponent).into(), game_id));
+
+        // Ante game
+        System::set_block_number(2);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(1).into(),
+            vec![1, 2],
+            pallet::GameMode::PvP,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::One,
+            None,
+            crate::HandVisibility::Open,
+        ));
+        deal_pending();
+        let game_id = Eterra::active_game_of(1).unwrap();
+        assert_noop!(
+            Eterra::play(
+                RawOrigin::Signed(to_move(game_id)).into(),
+                game_id,
+                made_up(0)
+            ),
+            crate::Error::<Test>::PlayFromHandRequired
+        );
+        assert_ok!(Eterra::resign(RawOrigin::Signed(1).into(), game_id));
+
+        // Plain game, free play until either hand is locked in
+        System::set_block_number(3);
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_ok!(Eterra::play(
+            RawOrigin::Signed(to_move(game_id)).into(),
+            game_id,
+            made_up(0)
+        ));
+        let waiting = if to_move(game_id) == creator {
+            opponent
+        } else {
+            creator
+        };
+        assert_ok!(Eterra::submit_hand(
+            RawOrigin::Signed(waiting).into(),
+            game_id,
+            vec![]
+        ));
+        assert_noop!(
+            Eterra::play(
+                RawOrigin::Signed(to_move(game_id)).into(),
+                game_id,
+                made_up(1)
+            ),
+            crate::Error::<Test>::PlayFromHandRequired
+        );
+    });
+}
+
+#[test]
+fn drawn_wager_refunds_both_stakes() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = propose_wager(1_000);
+        assert_ok!(Eterra::accept_wager(
+            RawOrigin::Signed(opponent).into(),
+            game_id
+        ));
+        deal_pending();
+        let creator_free = Balances::free_balance(creator);
+        let opponent_free = Balances::free_balance(opponent);
+
+        assert_ok!(Eterra::offer_draw(
+            RawOrigin::Signed(creator).into(),
+            game_id
+        ));
+        assert_ok!(Eterra::accept_draw(
+            RawOrigin::Signed(opponent).into(),
+            game_id
+        ));
+
+        assert_eq!((staked(creator), staked(opponent)), (0, 0));
+        assert_eq!(Balances::free_balance(creator), creator_free + 1_000);
+        assert_eq!(Balances::free_balance(opponent), opponent_free + 1_000);
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::StakesSettled {
+            game_id,
+            winner: None,
+            payout: 0,
+            house_cut: 0,
+        }));
+    });
+}
+
+#[test]
+fn declined_wager_releases_the_stake_and_removes_the_game() {
+    new_test_ext().execute_with(|| {
+        let (game_id, creator, opponent) = propose_wager(1_000);
+        assert_noop!(
+            Eterra::decline_wager(RawOrigin::Signed(3).into(), game_id),
+            crate::Error::<Test>::PlayerNotInGame
+        );
+
+        assert_ok!(Eterra::decline_wager(
+            RawOrigin::Signed(opponent).into(),
+            game_id
+        ));
+        assert_eq!(staked(creator), 0);
+        assert!(Eterra::stake_of(game_id).is_none());
+        assert!(!GameStorage::<Test>::contains_key(&game_id));
+        assert!(Eterra::active_game_of(creator).is_none());
+        assert!(Eterra::active_game_of(opponent).is_none());
+        assert!(!Eterra::recent_games(creator).contains(&game_id));
+        assert!(!Eterra::recent_games(opponent).contains(&game_id));
+        assert!(!crate::TurnTimeouts::<Test>::iter_values().any(|games| games.contains(&game_id)));
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::WagerDeclined {
+            game_id,
+            player: opponent,
+        }));
+    });
+}
+
+#[test]
+fn stakes_need_pvp_and_enough_free_balance() {
+    new_test_ext().execute_with(|| {
+        let human = 30;
+        ensure_preset_hand(human);
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(human).into(),
+                vec![human],
+                pallet::GameMode::PvE,
+                crate::RuleSet::default(),
+                crate::BoardSize::default(),
+                1_000,
+                crate::TradeRule::None,
+                None,
+                crate::HandVisibility::Open,
+            ),
+            crate::Error::<Test>::WagerRequiresPvP
+        );
+
+        ensure_preset_hand(1);
+        ensure_preset_hand(2);
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(1).into(),
+                vec![1, 2],
+                pallet::GameMode::PvP,
+                crate::RuleSet::default(),
+                crate::BoardSize::default(),
+                Balances::free_balance(1) + 1,
+                crate::TradeRule::None,
+                None,
+                crate::HandVisibility::Open,
+            ),
+            crate::Error::<Test>::InsufficientStake
+        );
+    });
+}
+
+/// Players 1 and 2 start an ante game under `rule` and both submit their preset hands.
+fn create_ante_game(rule: crate::TradeRule) -> H256 {
+    let (creator, opponent) = (1, 2);
+    let game_id = BlakeTwo256::hash_of(&(creator, opponent, System::block_number()));
+    assert_ok!(Eterra::create_game(
+        RawOrigin::Signed(creator).into(),
+        vec![creator, opponent],
+        pallet::GameMode::PvP,
+        crate::RuleSet::default(),
+        crate::BoardSize::default(),
+        0,
+        rule,
+        None,
+        crate::HandVisibility::Open,
+    ));
+    deal_pending();
+    for who in [creator, opponent] {
+        assert_ok!(Eterra::submit_hand(
+            RawOrigin::Signed(who).into(),
+            game_id,
+            vec![]
+        ));
+    }
+    game_id
+}
+
+fn set_card_ranks(card_id: u32, rank: u8) {
+    card_pallet::Cards::<Test>::mutate(card_id, |c| {
+        let c = c.as_mut().unwrap();
+        (c.north, c.east, c.south, c.west) = (rank, rank, rank, rank);
+    });
+}
+
+#[test]
+fn ante_cards_are_locked_and_the_winner_takes_one() {
+    new_test_ext().execute_with(|| {
+        let (creator, opponent) = (1, 2);
+        let creator_hand = ensure_preset_hand(creator);
+        let opponent_hand = ensure_preset_hand(opponent);
+        for (ix, id) in creator_hand.iter().enumerate() {
+            set_card_ranks(*id, if ix == 3 { 9 } else { 5 });
+        }
+        let game_id = create_ante_game(crate::TradeRule::One);
+        assert_eq!(Eterra::trade_rule_of(game_id), Some(crate::TradeRule::One));
+        for id in creator_hand.iter().chain(opponent_hand.iter()) {
+            assert_eq!(
+                cards::Pallet::<Test>::lock_reason_of(*id),
+                Some(cards::LockReason::Ante)
+            );
+        }
+        assert_noop!(
+            cards::Pallet::<Test>::transfer_card(
+                RawOrigin::Signed(creator).into(),
+                creator_hand[0],
+                opponent
+            ),
+            card_pallet::Error::<Test>::CardLocked
+        );
+
+        assert_ok!(Eterra::resign(RawOrigin::Signed(creator).into(), game_id));
+
+        // The strongest card changes hands; every lock is released
+        let taken = creator_hand[3];
+        assert_eq!(cards::Pallet::<Test>::cards(taken).unwrap().owner, opponent);
+        for id in creator_hand.iter().filter(|id| **id != taken) {
+            assert_eq!(cards::Pallet::<Test>::cards(id).unwrap().owner, creator);
+        }
+        for id in creator_hand.iter().chain(opponent_hand.iter()) {
+            assert!(cards::Pallet::<Test>::lock_reason_of(*id).is_none());
+        }
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::CardsTraded {
+            game_id,
+            from: creator,
+            to: opponent,
+            card_ids: vec![taken],
+        }));
+        assert!(Eterra::trade_rule_of(game_id).is_none());
+    });
+}
+
+#[test]
+fn ante_all_keeps_cards_on_a_draw() {
+    new_test_ext().execute_with(|| {
+        let (creator, opponent) = (1, 2);
+        let creator_hand = ensure_preset_hand(creator);
+        let opponent_hand = ensure_preset_hand(opponent);
+        let game_id = create_ante_game(crate::TradeRule::All);
+        assert_ok!(Eterra::offer_draw(
+            RawOrigin::Signed(creator).into(),
+            game_id
+        ));
+        assert_ok!(Eterra::accept_draw(
+            RawOrigin::Signed(opponent).into(),
+            game_id
+        ));
+
+        for (owner, hand) in [(creator, &creator_hand), (opponent, &opponent_hand)] {
+            for id in hand.iter() {
+                assert_eq!(cards::Pallet::<Test>::cards(id).unwrap().owner, owner);
+                assert!(cards::Pallet::<Test>::lock_reason_of(*id).is_none());
+            }
+        }
+        assert!(!System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::Eterra(crate::Event::CardsTraded { .. })
+        )));
+    });
+}
+
+#[test]
+fn ante_direct_hands_over_captured_cards() {
+    new_test_ext().execute_with(|| {
+        let (creator, opponent) = (1, 2);
+        let creator_hand = ensure_preset_hand(creator);
+        let opponent_hand = ensure_preset_hand(opponent);
+        set_card_ranks(creator_hand[0], 1);
+        set_card_ranks(opponent_hand[0], 1);
+        set_card_ranks(creator_hand[1], 9);
+        set_card_ranks(opponent_hand[1], 9);
+        let game_id = create_ante_game(crate::TradeRule::Direct);
+
+        // Whoever moves first lays a weak card that the other captures with a strong one
+        let game = GameStorage::<Test>::get(&game_id).unwrap();
+        let first = game.players[game.player_turn as usize];
+        let second = if first == creator { opponent } else { creator };
+        assert_ok!(Eterra::play_from_hand(
+            RawOrigin::Signed(first).into(),
+            game_id,
+            0,
+            0,
+            0,
+            None,
+        ));
+        assert_ok!(Eterra::play_from_hand(
+            RawOrigin::Signed(second).into(),
+            game_id,
+            1,
+            1,
+            0,
+            None,
+        ));
+        assert_ok!(Eterra::resign(RawOrigin::Signed(second).into(), game_id));
+
+        let first_hand = if first == creator {
+            &creator_hand
+        } else {
+            &opponent_hand
+        };
+        assert_eq!(
+            cards::Pallet::<Test>::cards(first_hand[0]).unwrap().owner,
+            second
+        );
+        System::assert_has_event(RuntimeEvent::Eterra(crate::Event::CardsTraded {
+            game_id,
+            from: first,
+            to: second,
+            card_ids: vec![first_hand[0]],
+        }));
+    });
+}
+
+#[test]
+fn trade_rules_need_pvp() {
+    new_test_ext().execute_with(|| {
+        let human = 30;
+        ensure_preset_hand(human);
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(human).into(),
+                vec![human],
+                pallet::GameMode::PvE,
+                crate::RuleSet::default(),
+                crate::BoardSize::default(),
+                0,
+                crate::TradeRule::All,
+                None,
+                crate::HandVisibility::Open,
+            ),
+            crate::Error::<Test>::TradeRequiresPvP
+        );
+    });
+}
+
+/// Hidden-hand PvP game between accounts 1 and 2, with `ids` committed by 1, each slot
+/// salted with its own index.
+fn create_hidden_game(ids: &[u32]) -> H256 {
+    let (creator, opponent) = (1, 2);
+    ensure_preset_hand(creator);
+    let game_id = BlakeTwo256::hash_of(&(creator, opponent, System::block_number()));
+    assert_ok!(Eterra::create_game(
+        RawOrigin::Signed(creator).into(),
+        vec![creator, opponent],
+        pallet::GameMode::PvP,
+        crate::RuleSet::default(),
+        crate::BoardSize::default(),
+        0,
+        crate::TradeRule::None,
+        None,
+        crate::HandVisibility::Hidden,
+    ));
+    deal_pending();
+    let commitments = ids
+        .iter()
+        .enumerate()
+        .map(|(slot, id)| Eterra::hand_commitment(&game_id, &creator, *id, &[slot as u8; 32]))
+        .collect();
+    assert_ok!(Eterra::commit_hand(
+        RawOrigin::Signed(creator).into(),
+        game_id,
+        commitments
+    ));
+    game_id
+}
+
+fn reveal(card_id: u32, slot: u8) -> Option<crate::CardReveal> {
+    Some(crate::CardReveal {
+        card_id,
+        salt: [slot; 32],
+    })
+}
+
+#[test]
+fn hidden_hands_reveal_each_card_only_when_played() {
+    new_test_ext().execute_with(|| {
+        let (creator, opponent) = (1, 2);
+        let ids = mint_cards_for(creator, 5);
+        let game_id = create_hidden_game(&ids);
+
+        // Nothing about the cards is on chain yet
+        assert!(HandsOfGame::<Test>::get(&game_id, &creator).is_none());
+        assert!(Eterra::hands(&game_id).is_empty());
+        assert!(ids
+            .iter()
+            .all(|id| cards::Pallet::<Test>::lock_reason_of(*id).is_none()));
+        assert_noop!(
+            Eterra::submit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]),
+            crate::Error::<Test>::HandIsHidden
+        );
+        assert_noop!(
+            Eterra::commit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]),
+            crate::Error::<Test>::HandAlreadySubmitted
+        );
+
+        give_turn_to(game_id, creator);
+        let options_at = |x: u8| {
+            Eterra::legal_moves(&game_id)
+                .iter()
+                .filter(|m| (m.x, m.y) == (x, 3))
+                .count()
+        };
+        assert_eq!(options_at(0), 5);
+        let play = |slot: u8, x: u8, reveal: Option<crate::CardReveal>| {
+            Eterra::play_from_hand(
+                RawOrigin::Signed(creator).into(),
+                game_id,
+                slot,
+                x,
+                3,
+                reveal,
+            )
+        };
+        assert_noop!(play(2, 0, None), crate::Error::<Test>::RevealRequired);
+        assert_noop!(
+            play(2, 0, reveal(ids[2], 1)),
+            crate::Error::<Test>::BadReveal
+        );
+        assert_noop!(
+            play(2, 0, reveal(ids[1], 2)),
+            crate::Error::<Test>::BadReveal
+        );
+
+        assert_ok!(play(2, 0, reveal(ids[2], 2)));
+        let card = card_pallet::Cards::<Test>::get(ids[2]).unwrap();
+        let placed = GameStorage::<Test>::get(&game_id).unwrap().board[0][3]
+            .clone()
+            .unwrap();
+        assert_eq!(
+            (placed.top, placed.right, placed.bottom, placed.left),
+            (card.north, card.east, card.south, card.west)
+        );
+        let hand = HandsOfGame::<Test>::get(&game_id, &creator).unwrap();
+        assert_eq!(hand.len(), 1);
+        assert!(hand[0].card_id == ids[2] && hand[0].used);
+        assert_eq!(
+            cards::Pallet::<Test>::lock_reason_of(ids[2]),
+            Some(cards::LockReason::InGame)
+        );
+        assert!(cards::Pallet::<Test>::lock_reason_of(ids[3]).is_none());
+
+        give_turn_to(game_id, creator);
+        assert_eq!(options_at(1), 4);
+        assert_noop!(
+            play(2, 1, reveal(ids[2], 2)),
+            crate::Error::<Test>::CardAlreadyUsed
+        );
+
+        // The log replays against the revealed cards, and ending the game unlocks them
+        assert_ok!(Eterra::resign(RawOrigin::Signed(opponent).into(), game_id));
+        let record = Eterra::game_record(&game_id).unwrap();
+        assert!(crate::replay::replay(&record).is_ok());
+        assert!(cards::Pallet::<Test>::lock_reason_of(ids[2]).is_none());
+    });
+}
+
+#[test]
+fn hidden_hands_are_committed_before_the_first_card_and_played_from_the_commitment() {
+    new_test_ext().execute_with(|| {
+        let (creator, opponent) = (1, 2);
+        let ids = mint_cards_for(creator, 5);
+        let game_id = create_hidden_game(&ids);
+        give_turn_to(game_id, creator);
+        assert_noop!(
+            Eterra::play(
+                RawOrigin::Signed(creator).into(),
+                game_id,
+                Move {
+                    place_index_x: 0,
+                    place_index_y: 0,
+                    place_card: Card::new(9, 9, 9, 9),
+                }
+            ),
+            crate::Error::<Test>::PlayFromHandRequired
+        );
+
+        assert_ok!(Eterra::play_from_hand(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            0,
+            0,
+            0,
+            reveal(ids[0], 0),
+        ));
+        let commitments: Vec<H256> = (0..5)
+            .map(|slot| Eterra::hand_commitment(&game_id, &opponent, slot, &[0; 32]))
+            .collect();
+        assert_noop!(
+            Eterra::commit_hand(RawOrigin::Signed(opponent).into(), game_id, commitments),
+            crate::Error::<Test>::CommitPhaseOver
+        );
+    });
+}
+
+#[test]
+fn hidden_hands_need_pvp_without_trades_and_open_hands_take_no_reveals() {
+    new_test_ext().execute_with(|| {
+        let human = 30;
+        ensure_preset_hand(human);
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(human).into(),
+                vec![human],
+                pallet::GameMode::PvE,
+                crate::RuleSet::default(),
+                crate::BoardSize::default(),
+                0,
+                crate::TradeRule::None,
+                None,
+                crate::HandVisibility::Hidden,
+            ),
+            crate::Error::<Test>::HiddenHandsRequirePvP
+        );
+
+        ensure_preset_hand(1);
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(1).into(),
+                vec![1, 2],
+                pallet::GameMode::PvP,
+                crate::RuleSet::default(),
+                crate::BoardSize::default(),
+                0,
+                crate::TradeRule::One,
+                None,
+                crate::HandVisibility::Hidden,
+            ),
+            crate::Error::<Test>::TradeRequiresOpenHands
+        );
+
+        let (game_id, creator, opponent) = setup_new_game();
+        assert_noop!(
+            Eterra::commit_hand(RawOrigin::Signed(creator).into(), game_id, vec![]),
+            crate::Error::<Test>::HandIsOpen
+        );
+        assert_ok!(Eterra::submit_hand(
+            RawOrigin::Signed(creator).into(),
+            game_id,
+            vec![]
+        ));
+        ensure_my_turn(game_id, creator, opponent);
+        assert_noop!(
+            Eterra::play_from_hand(
+                RawOrigin::Signed(creator).into(),
+                game_id,
+                0,
+                0,
+                0,
+                reveal(1, 0),
+            ),
+            crate::Error::<Test>::HandIsOpen
+        );
+    });
+}
+
+#[test]
+fn offchain_ai_moves_wait_for_a_signed_submission() {
+    use mc_ai::AiTurnProvider;
+    use parity_scale_codec::Encode;
+    use sp_runtime::{app_crypto::RuntimeAppPublic, testing::UintAuthorityId};
+
+    new_test_ext().execute_with(|| {
+        AiMovesOffchain::set(&true);
+        let human = 30;
+        ensure_preset_hand(human);
+        assert_ok!(Eterra::create_game(
+            RawOrigin::Signed(human).into(),
+            vec![human],
+            pallet::GameMode::PvE,
+            crate::RuleSet::default(),
+            crate::BoardSize::default(),
+            0,
+            crate::TradeRule::None,
+            None,
+            crate::HandVisibility::Open,
+        ));
+        deal_pending();
+        let game_id = Eterra::active_game_of(human).unwrap();
+        assert_ok!(Eterra::submit_hand(
+            RawOrigin::Signed(human).into(),
+            game_id,
+            vec![]
+        ));
+        deal_pending();
+        assert!(Eterra::awaiting_ai(4).is_empty());
+
+        let mv = Eterra::legal_moves(&game_id)[0];
+        assert_ok!(Eterra::play_from_hand(
+            RawOrigin::Signed(human).into(),
+            game_id,
+            mv.hand_index,
+            mv.x,
+            mv.y,
+            None,
+        ));
+
+        // The AI did not answer inside the human's extrinsic.
+        assert_eq!(Eterra::move_log_of(game_id).len(), 1);
+        let awaiting = Eterra::awaiting_ai(4);
+        assert_eq!(awaiting.len(), 1);
+        let (_, state) = awaiting[0].clone();
+        assert_eq!(awaiting[0].0, game_id);
+
+        // AI moves get the same checks as a player's.
+        assert_noop!(
+            Eterra::apply_ai_action(
+                &game_id,
+                &ai::Action {
+                    hand_index: 0,
+                    x: mv.x,
+                    y: mv.y,
+                }
+            ),
+            crate::Error::<Test>::CellOccupied
+        );
+
+        let state_hash = BlakeTwo256::hash_of(&state);
+        let block = System::block_number();
+        let seed = mc_ai::Pallet::<Test>::seed_for(&state_hash, Some(&game_id), block);
+        let action = mc_ai::Pallet::<Test>::search::<ai::Adapter>(&state, 50, seed).unwrap();
+        let payload = mc_ai::AiMovePayload {
+            public: UintAuthorityId(7),
+            game: game_id,
+            state_hash,
+            block,
+            action: action.clone(),
+        };
+        let signature = UintAuthorityId(7).sign(&payload.encode()).unwrap();
+        assert_ok!(mc_ai::Pallet::<Test>::submit_ai_move(
+            RuntimeOrigin::none(),
+            payload,
+            signature
+        ));
+
+        assert_eq!(Eterra::move_log_of(game_id).len(), 2);
+        assert!(Eterra::ai_state(&game_id).is_none());
+        assert_noop!(
+            Eterra::apply_ai_action(&game_id, &action),
+            crate::Error::<Test>::NotYourTurn
+        );
+    });
+}
+
+fn create_pve_game(human: u64, ai_profile: Option<crate::AiProfile>) -> DispatchResult {
+    Eterra::create_game(
+        RawOrigin::Signed(human).into(),
+        vec![human],
+        pallet::GameMode::PvE,
+        crate::RuleSet::default(),
+        crate::BoardSize::default(),
+        0,
+        crate::TradeRule::None,
+        ai_profile,
+        crate::HandVisibility::Open,
+    )
+}
+
+#[test]
+fn pve_games_store_and_report_their_ai_profile() {
+    use crate::AiProfile;
+
+    new_test_ext().execute_with(|| {
+        ensure_preset_hand(40);
+        assert_ok!(create_pve_game(40, Some(AiProfile::Greedy)));
+        let game_id = Eterra::active_game_of(40).unwrap();
+        assert_eq!(Eterra::ai_profile_of(game_id), Some(AiProfile::Greedy));
+        System::assert_last_event(RuntimeEvent::Eterra(crate::Event::GameCreated {
+            game_id,
+            ai_profile: Some(AiProfile::Greedy),
+        }));
+
+        // Without a choice the AI plays Monte-Carlo at the runtime's difficulty.
+        ensure_preset_hand(41);
+        assert_ok!(create_pve_game(41, None));
+        let game_id = Eterra::active_game_of(41).unwrap();
+        assert_eq!(
+            Eterra::ai_profile_of(game_id),
+            Some(AiProfile::MonteCarlo(
+                <Test as crate::Config>::AiDifficulty::get()
+            ))
+        );
+    });
+}
+
+#[test]
+fn ai_profiles_are_checked_at_creation() {
+    use crate::AiProfile;
+
+    new_test_ext().execute_with(|| {
+        ensure_preset_hand(1);
+        ensure_preset_hand(2);
+        assert_noop!(
+            Eterra::create_game(
+                RawOrigin::Signed(1).into(),
+                vec![1, 2],
+                pallet::GameMode::PvP,
+                crate::RuleSet::default(),
+                crate::BoardSize::default(),
+                0,
+                crate::TradeRule::None,
+                Some(AiProfile::Novice),
+                crate::HandVisibility::Open,
+            ),
+            crate::Error::<Test>::AiProfileRequiresPvE
+        );
+        assert_noop!(
+            create_pve_game(1, Some(AiProfile::MonteCarlo(101))),
+            crate::Error::<Test>::InvalidAiDifficulty
+        );
+        assert_ok!(create_pve_game(1, Some(AiProfile::MonteCarlo(100))));
+    });
+}
+
+#[test]
+fn ai_hand_strength_follows_the_profile() {
+    use crate::AiProfile;
+
+    new_test_ext().execute_with(|| {
+        let human = 42;
+        let game_id = H256::repeat_byte(7);
+        // Ranks average five.
+        let human_hand: Vec<crate::HandEntry> = (0..5)
+            .map(|card_id| crate::HandEntry {
+                card_id,
+                north: 3,
+                east: 7,
+                south: 5,
+                west: 5,
+                used: false,
+            })
+            .collect();
+        HandsOfGame::<Test>::insert(game_id, human, BoundedVec::try_from(human_hand).unwrap());
+
+        let ranks = |profile| -> Vec<[u8; 4]> {
+            Eterra::generate_ai_hand_for_game(&game_id, &human, profile)
+                .unwrap()
+                .iter()
+                .map(|e| [e.north, e.east, e.south, e.west])
+                .collect()
+        };
+
+        let novice = ranks(AiProfile::Novice);
+        assert_eq!(novice.len(), 5);
+        assert!(novice.iter().flatten().all(|r| (1..=4).contains(r)));
+
+        // One edge two ranks above the human's average, the others two below.
+        for card in ranks(AiProfile::Greedy) {
+            let mut sorted = card;
+            sorted.sort();
+            assert_eq!(sorted, [3, 3, 3, 7]);
+        }
+
+        // Same game and player, so the same jitter: difficulty alone moves the ranks.
+        let easy = ranks(AiProfile::MonteCarlo(0)).concat();
+        let hard = ranks(AiProfile::MonteCarlo(100)).concat();
+        assert!(easy.iter().all(|r| (2..=4).contains(r)));
+        assert!(easy.iter().zip(&hard).all(|(e, h)| e + 2 == *h));
+
+        assert_eq!(ranks(AiProfile::Solver), vec![[3, 7, 5, 5]; 5]);
+    });
+}
+
+#[test]
+fn cheap_ai_profiles_answer_on_chain_even_when_moves_are_offchain() {
+    use crate::AiProfile;
+
+    new_test_ext().execute_with(|| {
+        AiMovesOffchain::set(&true);
+        for (human, profile) in [(43, AiProfile::Novice), (44, AiProfile::Greedy)] {
+            ensure_preset_hand(human);
+            assert_ok!(create_pve_game(human, Some(profile)));
+            let game_id = Eterra::active_game_of(human).unwrap();
+            assert_ok!(Eterra::submit_hand(
+                RawOrigin::Signed(human).into(),
+                game_id,
+                vec![]
+            ));
+            deal_pending();
+            let mv = Eterra::legal_moves(&game_id)[0];
+            assert_ok!(Eterra::play_from_hand(
+                RawOrigin::Signed(human).into(),
+                game_id,
+                mv.hand_index,
+                mv.x,
+                mv.y,
+                None,
+            ));
+            assert_eq!(Eterra::move_log_of(game_id).len(), 2);
+        }
+    });
+}
+
+/// The on-chain path (`play_from_hand`) and the AI simulation path
+/// (`Adapter::apply_pure` on `build_ai_state`) must agree move for move.
+mod rules_equivalence {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn entry() -> impl Strategy<Value = crate::HandEntry> {
+        (1u8..=9, 1u8..=9, 1u8..=9, 1u8..=9).prop_map(|(north, east, south, west)| {
+            crate::HandEntry {
+                card_id: 0,
+                north,
+                east,
+                south,
+                west,
+                used: false,
+            }
+        })
+    }
+
+    fn rule_set() -> impl Strategy<Value = crate::RuleSet> {
+        any::<(bool, bool, bool, bool, bool)>().prop_map(
+            |(same, plus, combo, same_wall, elemental)| crate::RuleSet {
+                same,
+                plus,
+                combo,
+                same_wall,
+                elemental,
+            },
+        )
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn on_chain_and_simulated_play_agree(
+            rules in rule_set(),
+            elements in prop::array::uniform5(prop::array::uniform5(-1i8..=1)),
+            hand0 in prop::collection::vec(entry(), 5),
+            hand1 in prop::collection::vec(entry(), 5),
+            picks in prop::collection::vec(any::<u16>(), 10),
+        ) {
+            new_test_ext().execute_with(|| {
+                let (game_id, _, _) = setup_new_game();
+                crate::GameRules::<Test>::insert(game_id, rules);
+                crate::GameElements::<Test>::insert(game_id, elements);
+                let players = Eterra::game_board(game_id).unwrap().players;
+                for (p, h) in players.iter().zip([hand0, hand1]) {
+                    HandsOfGame::<Test>::insert(game_id, p, BoundedVec::truncate_from(h));
+                }
+
+                let mut sim = Eterra::build_ai_state(&game_id, &Eterra::game_board(game_id).unwrap())
+                    .expect("both hands present");
+                for pick in picks {
+                    let moves = Eterra::legal_moves(&game_id);
+                    if moves.is_empty() {
+                        break;
+                    }
+                    let mv = moves[pick as usize % moves.len()];
+                    let game = Eterra::game_board(game_id).unwrap();
+                    let who = game.players[game.player_turn as usize];
+                    assert_ok!(Eterra::play_from_hand(
+                        RawOrigin::Signed(who).into(),
+                        game_id,
+                        mv.hand_index,
+                        mv.x,
+                        mv.y,
+                        None,
+                    ));
+                    sim = ai::Adapter::apply_pure(
+                        &sim,
+                        &ai::Action {
+                            hand_index: mv.hand_index,
+                            x: mv.x,
+                            y: mv.y,
+                        },
+                    );
+
+                    let game = Eterra::game_board(game_id).unwrap();
+                    assert_eq!(game.board, sim.board);
+                    assert_eq!(game.scores, sim.scores);
+                    assert_eq!(game.player_turn, sim.player_turn);
+                    assert_eq!(game.round, sim.round);
+                    for (i, p) in players.iter().enumerate() {
+                        let hand = HandsOfGame::<Test>::get(game_id, p).unwrap();
+                        for (j, e) in hand.iter().enumerate() {
+                            assert_eq!(e.used, sim.hands[i].entries[j].used);
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
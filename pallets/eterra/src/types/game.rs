@@ -1,5 +1,7 @@
 use crate::types::board::Board;
 use crate::types::card::Card;
+use crate::types::rules::RuleSet;
+use frame_support::pallet_prelude::ConstU32;
 use frame_support::BoundedVec;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen}; // For Encode, Decode, MaxEncodedLen
 use scale_info::TypeInfo; // For TypeInfo
@@ -8,10 +10,12 @@ use scale_info::TypeInfo; // For TypeInfo
 pub type Players<Account, NumPlayers> = BoundedVec<Account, NumPlayers>;
 
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameState {
     Matchmaking,
     Playing,
     Finished { winner: Option<u8> }, // Ready to reward players
+    Dealing,                         // Waiting for the randomness of a later block
 }
 
 pub trait GameProperties<Account, NumPlayers> {
@@ -44,6 +48,35 @@ where
     pub scores: (u8, u8), // Scores for each player
 }
 
+/// Bound for per-game move logs and per-move flips: one entry per cell of the largest board.
+pub type MoveLogLimit =
+    ConstU32<{ (eterra_rules::MAX_BOARD_DIM * eterra_rules::MAX_BOARD_DIM) as u32 }>;
+
+/// One placement in a game's move log.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+pub struct MoveRecord {
+    pub player: u8,             // Seat index (0 or 1)
+    pub hand_index: Option<u8>, // `None` for cards placed via `play`
+    pub card: Card,             // Stats as placed, without possession
+    pub x: u8,
+    pub y: u8,
+    pub flipped: BoundedVec<(u8, u8), MoveLogLimit>,
+}
+
+/// What is kept of a finished game once its board and hands have been pruned.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Debug)]
+pub struct GameSummary<Account, BlockNumber, NumPlayers>
+where
+    NumPlayers: Clone,
+{
+    pub players: Players<Account, NumPlayers>,
+    pub scores: (u8, u8),
+    pub winner: Option<u8>, // Index into `players`, `None` for a draw
+    pub rules: RuleSet,
+    pub moves: u32, // Cards placed on the board
+    pub finished_at: BlockNumber,
+}
+
 impl<Account, BlockNumber, NumPlayers> GameProperties<Account, NumPlayers>
     for Game<Account, BlockNumber, NumPlayers>
 where
@@ -84,13 +117,8 @@ where
             return;
         }
 
-        // Switch turn
-        self.player_turn = (self.player_turn + 1) % 2;
-
-        // Increment round only when player_turn wraps back to 0
-        if self.player_turn == 0 {
-            self.round += 1;
-        }
+        // Switch turn; the round increments only when player_turn wraps back to 0
+        eterra_rules::advance_turn(&mut self.player_turn, &mut self.round);
 
         log::debug!(
             "After next_turn: player_turn = {}, round = {}",
@@ -100,9 +128,4 @@ where
     }
 }
 
-#[derive(Encode, Decode, TypeInfo, PartialEq, Clone, Debug)]
-pub struct Move {
-    pub place_index_x: u8,
-    pub place_index_y: u8,
-    pub place_card: Card,
-}
+pub use eterra_rules::Move;
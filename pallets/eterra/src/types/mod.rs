@@ -3,5 +3,7 @@ use frame_system::Config;
 pub mod board;
 pub mod card;
 pub mod game;
+pub mod rules;
+pub mod view;
 
 pub type GameId<T> = <T as Config>::Hash;
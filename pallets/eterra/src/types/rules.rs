@@ -0,0 +1 @@
+pub use eterra_rules::{elements_from_seed, resolve_captures, Elements, RuleSet, WALL_RANK};
@@ -0,0 +1,62 @@
+use crate::pallet::{GameMode, HandEntry};
+use crate::types::board::Board;
+use crate::types::game::{GameState, MoveRecord};
+use crate::types::rules::{Elements, RuleSet};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Decoded snapshot of a game, as returned by the `EterraGameApi` runtime API.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameView<AccountId, BlockNumber> {
+    pub mode: Option<GameMode>,
+    pub state: GameState,
+    pub last_played_block: BlockNumber,
+    pub players: Vec<AccountId>,
+    pub player_turn: u8,
+    pub round: u8,
+    pub max_rounds: u8,
+    pub board: Board,
+    pub scores: (u8, u8),
+}
+
+/// A player's submitted hand for a game.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandView<AccountId> {
+    pub player: AccountId,
+    pub entries: Vec<HandEntry>,
+}
+
+/// A placement the player to move may submit via `play_from_hand`.
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct LegalMove {
+    pub hand_index: u8,
+    pub x: u8,
+    pub y: u8,
+}
+
+/// Outcome of a hypothetical `play_from_hand`, computed without touching storage.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct MovePreview {
+    /// Board after the card is placed and captures resolved.
+    pub board: Board,
+    /// Change to `(player 0, player 1)` scores.
+    pub score_delta: (i8, i8),
+    /// Cells `(x, y)` whose owner changed, in resolution order.
+    pub flipped: Vec<(u8, u8)>,
+}
+
+/// Everything needed to re-simulate a game: its current state, rules, hands and move log.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Debug)]
+pub struct GameRecord<AccountId, BlockNumber> {
+    pub game: GameView<AccountId, BlockNumber>,
+    pub rules: RuleSet,
+    pub elements: Option<Elements>,
+    pub hand_size: u8, // Cards dealt to each player; both scores start here
+    pub hands: Vec<HandView<AccountId>>,
+    pub moves: Vec<MoveRecord>,
+}
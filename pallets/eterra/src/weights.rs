@@ -0,0 +1,597 @@
+//! Weights for pallet_eterra
+//!
+//! These values are estimates, not benchmark results. They were worked out by hand from
+//! the storage each call touches, sized for the worst cases set up in `benchmarking.rs`.
+//! Replace them with measured weights from reference hardware before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_eterra.
+pub trait WeightInfo {
+	fn create_game() -> Weight;
+	fn play() -> Weight;
+	fn submit_hand() -> Weight;
+	fn play_from_hand() -> Weight;
+	fn force_finish_turn() -> Weight;
+	fn set_current_hand() -> Weight;
+	fn set_preset_hand() -> Weight;
+	fn resign() -> Weight;
+	fn offer_draw() -> Weight;
+	fn accept_draw() -> Weight;
+	fn abandon() -> Weight;
+	fn accept_wager() -> Weight;
+	fn decline_wager() -> Weight;
+	fn commit_hand() -> Weight;
+	fn process_timeouts(n: u32) -> Weight;
+	fn timeout_end_game() -> Weight;
+	fn timeout_ai_turn() -> Weight;
+	fn prune_idle() -> Weight;
+	fn prune_bucket(g: u32) -> Weight;
+	fn deal_games(n: u32) -> Weight;
+}
+
+/// Estimated weights for pallet_eterra.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Eterra CurrentHandOf (r:1 w:0)
+	/// Storage: Eterra ActiveGameOf (r:2 w:2)
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra PlayerGames (r:2 w:2)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Eterra GameStakes (r:0 w:1)
+	/// Storage: Eterra GameModes (r:0 w:1)
+	/// Storage: Eterra GameRules (r:0 w:1)
+	/// Storage: Eterra GameTradeRules (r:0 w:1)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	/// Storage: Eterra GameHandVisibility (r:0 w:1)
+	fn create_game() -> Weight {
+		Weight::from_parts(73_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameRules (r:1 w:0)
+	/// Storage: Eterra GameElements (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:3 w:1)
+	/// Storage: Eterra GameStakes (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:0)
+	/// Storage: Eterra GameHandVisibility (r:1 w:0)
+	/// Storage: Eterra MoveLog (r:1 w:1)
+	/// Storage: Eterra TurnTimeouts (r:2 w:2)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn play() -> Weight {
+		Weight::from_parts(356_000_000, 9510)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:1)
+	/// Storage: Eterra CurrentHandOf (r:1 w:0)
+	/// Storage: EterraSimpleTCG Cards (r:5 w:0)
+	/// Storage: EterraSimpleTCG LockedCards (r:5 w:5)
+	/// Storage: Eterra GameHandVisibility (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:0)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	fn submit_hand() -> Weight {
+		Weight::from_parts(61_000_000, 14106)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameRules (r:1 w:0)
+	/// Storage: Eterra GameElements (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:2 w:2)
+	/// Storage: Eterra MoveLog (r:1 w:1)
+	/// Storage: Eterra TurnTimeouts (r:2 w:2)
+	/// Storage: Eterra GameHandVisibility (r:1 w:0)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn play_from_hand() -> Weight {
+		Weight::from_parts(362_000_000, 9510)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:0)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: EterraSimpleTCG Cards (r:5 w:5)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn force_finish_turn() -> Weight {
+		Weight::from_parts(182_000_000, 26380)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(33_u64))
+	}
+	/// Storage: EterraSimpleTCG Cards (r:5 w:0)
+	/// Storage: Eterra CurrentHandOf (r:0 w:1)
+	fn set_current_hand() -> Weight {
+		Weight::from_parts(24_000_000, 14106)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraSimpleTCG Cards (r:5 w:0)
+	/// Storage: Eterra CurrentHandOf (r:0 w:1)
+	fn set_preset_hand() -> Weight {
+		Weight::from_parts(24_000_000, 14106)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:0)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: EterraSimpleTCG Cards (r:5 w:5)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn resign() -> Weight {
+		Weight::from_parts(176_000_000, 26380)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(33_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:0)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra DrawOffers (r:1 w:1)
+	fn offer_draw() -> Weight {
+		Weight::from_parts(19_000_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra DrawOffers (r:1 w:1)
+	/// Storage: Eterra GameTradeRules (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:0)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn accept_draw() -> Weight {
+		Weight::from_parts(121_000_000, 26380)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:1 w:0)
+	/// Storage: EterraSimpleTCG LockedCards (r:5 w:5)
+	/// Storage: Eterra ActiveGameOf (r:0 w:1)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating PveRecords (r:1 w:1)
+	fn abandon() -> Weight {
+		Weight::from_parts(52_000_000, 14106)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameStakes (r:1 w:0)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	fn accept_wager() -> Weight {
+		Weight::from_parts(47_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:2)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra GameModes (r:0 w:1)
+	/// Storage: Eterra GameRules (r:0 w:1)
+	/// Storage: Eterra GameTradeRules (r:0 w:1)
+	/// Storage: Eterra GameElements (r:0 w:1)
+	/// Storage: Eterra PlayerGames (r:2 w:2)
+	/// Storage: Eterra TurnTimeouts (r:8 w:1)
+	/// Storage: Eterra GameAiProfiles (r:0 w:1)
+	/// Storage: Eterra GameHandVisibility (r:0 w:1)
+	/// Storage: Eterra CasualGames (r:0 w:1)
+	/// Storage: Eterra MoveLog (r:0 w:1)
+	fn decline_wager() -> Weight {
+		Weight::from_parts(121_000_000, 31942)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:0)
+	/// Storage: Eterra GameHandVisibility (r:1 w:0)
+	/// Storage: Eterra HandCommitments (r:1 w:1)
+	/// Storage: Eterra MoveLog (r:1 w:0)
+	fn commit_hand() -> Weight {
+		Weight::from_parts(26_000_000, 6264)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Eterra TurnTimeouts (r:65 w:65)
+	/// Storage: Eterra GameStorage (r:64 w:64)
+	/// Storage: Eterra GameModes (r:64 w:0)
+	fn process_timeouts(n: u32) -> Weight {
+		// The range of component `n` is `[0, 64]`.
+		Weight::from_parts(8_000_000, 1489)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:0)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: EterraSimpleTCG Cards (r:5 w:5)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	/// Storage: Eterra TurnTimeouts (r:1 w:1)
+	fn timeout_end_game() -> Weight {
+		Weight::from_parts(184_000_000, 26380)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(34_u64))
+	}
+	/// Storage: Eterra TurnTimeouts (r:2 w:2)
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameAiProfiles (r:1 w:0)
+	/// Storage: Eterra GameRules (r:1 w:0)
+	/// Storage: Eterra GameElements (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:2 w:1)
+	/// Storage: Eterra MoveLog (r:1 w:1)
+	fn timeout_ai_turn() -> Weight {
+		Weight::from_parts(338_000_000, 9510)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Eterra PruneCursor (r:1 w:0)
+	fn prune_idle() -> Weight {
+		Weight::from_parts(3_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:0 w:1)
+	/// Storage: Eterra GameStorage (r:64 w:64)
+	/// Storage: Eterra GameRules (r:64 w:64)
+	/// Storage: Eterra MoveLog (r:0 w:64)
+	/// Storage: Eterra GameArchive (r:0 w:64)
+	/// Storage: Eterra HandsOfGame (r:0 w:128)
+	fn prune_bucket(g: u32) -> Weight {
+		// The range of component `g` is `[0, 64]`.
+		Weight::from_parts(9_000_000, 3521)
+			.saturating_add(Weight::from_parts(38_000_000, 0).saturating_mul(g.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(g.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(g.into())))
+	}
+	/// Storage: EterraRandomness Seed (r:1 w:0)
+	/// Storage: EterraRandomness SeededAt (r:1 w:0)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	/// Storage: Eterra GameStorage (r:256 w:256)
+	/// Storage: Eterra GameModes (r:256 w:0)
+	/// Storage: Eterra HandsOfGame (r:512 w:256)
+	/// Storage: Eterra GameAiProfiles (r:256 w:0)
+	/// Storage: Eterra GameRules (r:256 w:0)
+	/// Storage: Eterra GameElements (r:256 w:256)
+	/// Storage: Eterra TurnTimeouts (r:256 w:256)
+	fn deal_games(n: u32) -> Weight {
+		// The range of component `n` is `[0, 256]`.
+		Weight::from_parts(8_000_000, 1489)
+			.saturating_add(Weight::from_parts(64_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Eterra CurrentHandOf (r:1 w:0)
+	/// Storage: Eterra ActiveGameOf (r:2 w:2)
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra PlayerGames (r:2 w:2)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: Eterra GameStakes (r:0 w:1)
+	/// Storage: Eterra GameModes (r:0 w:1)
+	/// Storage: Eterra GameRules (r:0 w:1)
+	/// Storage: Eterra GameTradeRules (r:0 w:1)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	/// Storage: Eterra GameHandVisibility (r:0 w:1)
+	fn create_game() -> Weight {
+		Weight::from_parts(73_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameRules (r:1 w:0)
+	/// Storage: Eterra GameElements (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:3 w:1)
+	/// Storage: Eterra GameStakes (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:0)
+	/// Storage: Eterra GameHandVisibility (r:1 w:0)
+	/// Storage: Eterra MoveLog (r:1 w:1)
+	/// Storage: Eterra TurnTimeouts (r:2 w:2)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn play() -> Weight {
+		Weight::from_parts(356_000_000, 9510)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:1)
+	/// Storage: Eterra CurrentHandOf (r:1 w:0)
+	/// Storage: EterraSimpleTCG Cards (r:5 w:0)
+	/// Storage: EterraSimpleTCG LockedCards (r:5 w:5)
+	/// Storage: Eterra GameHandVisibility (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:0)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	fn submit_hand() -> Weight {
+		Weight::from_parts(61_000_000, 14106)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameRules (r:1 w:0)
+	/// Storage: Eterra GameElements (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:2 w:2)
+	/// Storage: Eterra MoveLog (r:1 w:1)
+	/// Storage: Eterra TurnTimeouts (r:2 w:2)
+	/// Storage: Eterra GameHandVisibility (r:1 w:0)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn play_from_hand() -> Weight {
+		Weight::from_parts(362_000_000, 9510)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:0)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: EterraSimpleTCG Cards (r:5 w:5)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn force_finish_turn() -> Weight {
+		Weight::from_parts(182_000_000, 26380)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(33_u64))
+	}
+	/// Storage: EterraSimpleTCG Cards (r:5 w:0)
+	/// Storage: Eterra CurrentHandOf (r:0 w:1)
+	fn set_current_hand() -> Weight {
+		Weight::from_parts(24_000_000, 14106)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EterraSimpleTCG Cards (r:5 w:0)
+	/// Storage: Eterra CurrentHandOf (r:0 w:1)
+	fn set_preset_hand() -> Weight {
+		Weight::from_parts(24_000_000, 14106)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:0)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: EterraSimpleTCG Cards (r:5 w:5)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn resign() -> Weight {
+		Weight::from_parts(176_000_000, 26380)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(33_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:0)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra DrawOffers (r:1 w:1)
+	fn offer_draw() -> Weight {
+		Weight::from_parts(19_000_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra DrawOffers (r:1 w:1)
+	/// Storage: Eterra GameTradeRules (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:0)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	fn accept_draw() -> Weight {
+		Weight::from_parts(121_000_000, 26380)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:1 w:0)
+	/// Storage: EterraSimpleTCG LockedCards (r:5 w:5)
+	/// Storage: Eterra ActiveGameOf (r:0 w:1)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating PveRecords (r:1 w:1)
+	fn abandon() -> Weight {
+		Weight::from_parts(52_000_000, 14106)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameStakes (r:1 w:0)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	fn accept_wager() -> Weight {
+		Weight::from_parts(47_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:2)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra GameModes (r:0 w:1)
+	/// Storage: Eterra GameRules (r:0 w:1)
+	/// Storage: Eterra GameTradeRules (r:0 w:1)
+	/// Storage: Eterra GameElements (r:0 w:1)
+	/// Storage: Eterra PlayerGames (r:2 w:2)
+	/// Storage: Eterra TurnTimeouts (r:8 w:1)
+	/// Storage: Eterra GameAiProfiles (r:0 w:1)
+	/// Storage: Eterra GameHandVisibility (r:0 w:1)
+	/// Storage: Eterra CasualGames (r:0 w:1)
+	/// Storage: Eterra MoveLog (r:0 w:1)
+	fn decline_wager() -> Weight {
+		Weight::from_parts(121_000_000, 31942)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(29_u64))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:0)
+	/// Storage: Eterra GameHandVisibility (r:1 w:0)
+	/// Storage: Eterra HandCommitments (r:1 w:1)
+	/// Storage: Eterra MoveLog (r:1 w:0)
+	fn commit_hand() -> Weight {
+		Weight::from_parts(26_000_000, 6264)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Eterra TurnTimeouts (r:65 w:65)
+	/// Storage: Eterra GameStorage (r:64 w:64)
+	/// Storage: Eterra GameModes (r:64 w:0)
+	fn process_timeouts(n: u32) -> Weight {
+		// The range of component `n` is `[0, 64]`.
+		Weight::from_parts(8_000_000, 1489)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameTradeRules (r:1 w:1)
+	/// Storage: Eterra HandsOfGame (r:2 w:0)
+	/// Storage: Eterra GameStakes (r:1 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: EterraSimpleTCG Cards (r:5 w:5)
+	/// Storage: EterraSimpleTCG LockedCards (r:10 w:10)
+	/// Storage: EterraSimpleTCG OwnedCards (r:2 w:2)
+	/// Storage: Eterra ActiveGameOf (r:0 w:2)
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:1 w:1)
+	/// Storage: EterraRating Ratings (r:2 w:2)
+	/// Storage: Eterra TurnTimeouts (r:1 w:1)
+	fn timeout_end_game() -> Weight {
+		Weight::from_parts(184_000_000, 26380)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(34_u64))
+	}
+	/// Storage: Eterra TurnTimeouts (r:2 w:2)
+	/// Storage: Eterra GameStorage (r:1 w:1)
+	/// Storage: Eterra GameModes (r:1 w:0)
+	/// Storage: Eterra GameAiProfiles (r:1 w:0)
+	/// Storage: Eterra GameRules (r:1 w:0)
+	/// Storage: Eterra GameElements (r:1 w:0)
+	/// Storage: Eterra HandsOfGame (r:2 w:1)
+	/// Storage: Eterra MoveLog (r:1 w:1)
+	fn timeout_ai_turn() -> Weight {
+		Weight::from_parts(338_000_000, 9510)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Eterra PruneCursor (r:1 w:0)
+	fn prune_idle() -> Weight {
+		Weight::from_parts(3_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: Eterra PendingPrunes (r:1 w:1)
+	/// Storage: Eterra PruneCursor (r:0 w:1)
+	/// Storage: Eterra GameStorage (r:64 w:64)
+	/// Storage: Eterra GameRules (r:64 w:64)
+	/// Storage: Eterra MoveLog (r:0 w:64)
+	/// Storage: Eterra GameArchive (r:0 w:64)
+	/// Storage: Eterra HandsOfGame (r:0 w:128)
+	fn prune_bucket(g: u32) -> Weight {
+		// The range of component `g` is `[0, 64]`.
+		Weight::from_parts(9_000_000, 3521)
+			.saturating_add(Weight::from_parts(38_000_000, 0).saturating_mul(g.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(g.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((9_u64).saturating_mul(g.into())))
+	}
+	/// Storage: EterraRandomness Seed (r:1 w:0)
+	/// Storage: EterraRandomness SeededAt (r:1 w:0)
+	/// Storage: Eterra PendingDeals (r:1 w:1)
+	/// Storage: Eterra GameStorage (r:256 w:256)
+	/// Storage: Eterra GameModes (r:256 w:0)
+	/// Storage: Eterra HandsOfGame (r:512 w:256)
+	/// Storage: Eterra GameAiProfiles (r:256 w:0)
+	/// Storage: Eterra GameRules (r:256 w:0)
+	/// Storage: Eterra GameElements (r:256 w:256)
+	/// Storage: Eterra TurnTimeouts (r:256 w:256)
+	fn deal_games(n: u32) -> Weight {
+		// The range of component `n` is `[0, 256]`.
+		Weight::from_parts(8_000_000, 1489)
+			.saturating_add(Weight::from_parts(64_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+}
@@ -42,8 +42,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-    RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+    AccountId, Aura, Balance, Block, BlockNumber, Eterra, EterraMonteCarloAi, EterraRating,
+    EterraSimpleMatchMaker, Executive, Grandpa, Hash, InherentDataExt, Nonce, Runtime, RuntimeCall,
+    RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -214,6 +215,74 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_eterra_runtime_api::EterraGameApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+        fn game(game_id: Hash) -> Option<pallet_eterra::GameView<AccountId, BlockNumber>> {
+            Eterra::game_view(&game_id)
+        }
+        fn active_game(who: AccountId) -> Option<Hash> {
+            Eterra::active_game(&who)
+        }
+        fn recent_games(who: AccountId) -> Vec<Hash> {
+            Eterra::recent_games(&who)
+        }
+        fn hands(game_id: Hash) -> Vec<pallet_eterra::HandView<AccountId>> {
+            Eterra::hands(&game_id)
+        }
+        fn legal_moves(game_id: Hash) -> Vec<pallet_eterra::LegalMove> {
+            Eterra::legal_moves(&game_id)
+        }
+        fn preview_move(
+            game_id: Hash,
+            hand_index: u8,
+            x: u8,
+            y: u8,
+        ) -> Result<pallet_eterra::MovePreview, sp_runtime::DispatchError> {
+            Eterra::preview_move(&game_id, hand_index, x, y)
+        }
+        fn game_record(game_id: Hash) -> Option<pallet_eterra::GameRecord<AccountId, BlockNumber>> {
+            Eterra::game_record(&game_id)
+        }
+    }
+
+    impl pallet_eterra_monte_carlo_ai_runtime_api::MonteCarloAiApi<
+        Block,
+        eterra_card_ai_adapter::eterra_adapter::State,
+        eterra_card_ai_adapter::eterra_adapter::Action,
+        Hash,
+    > for Runtime {
+        fn suggest(
+            state: eterra_card_ai_adapter::eterra_adapter::State,
+            game: Option<Hash>,
+            difficulty: u8,
+        ) -> Option<eterra_card_ai_adapter::eterra_adapter::Action> {
+            EterraMonteCarloAi::suggestion(&state, game.as_ref(), difficulty)
+        }
+    }
+
+    impl pallet_eterra_rating_runtime_api::RatingApi<Block, AccountId> for Runtime {
+        fn rating(who: AccountId) -> pallet_eterra_rating::PlayerRating {
+            EterraRating::rating_of(who)
+        }
+        fn pve_record(who: AccountId) -> pallet_eterra_rating::Record {
+            EterraRating::pve_record_of(who)
+        }
+    }
+
+    impl pallet_eterra_simple_matchmaker_runtime_api::MatchmakerApi<
+        Block,
+        AccountId,
+        pallet_eterra::MatchSettings<Balance>,
+    > for Runtime {
+        fn queues() -> Vec<
+            pallet_eterra_simple_matchmaker::QueueView<pallet_eterra::MatchSettings<Balance>>,
+        > {
+            EterraSimpleMatchMaker::queues()
+        }
+        fn queue_of(who: AccountId) -> Option<pallet_eterra_simple_matchmaker::QueueId> {
+            EterraSimpleMatchMaker::in_queue(who).map(|(queue, _)| queue)
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
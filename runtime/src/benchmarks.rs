@@ -30,4 +30,11 @@ frame_benchmarking::define_benchmarks!(
     [pallet_timestamp, Timestamp]
     [pallet_sudo, Sudo]
     [pallet_template, TemplateModule]
+    [pallet_eterra, Eterra]
+    [pallet_eterra_tcg, EterraTCG]
+    [pallet_eterra_daily_slots, EterraDailySlots]
+    [pallet_eterra_simple_tcg, EterraSimpleTCG]
+    [pallet_eterra_monte_carlo_ai, EterraMonteCarloAi]
+    [pallet_eterra_simple_matchmaker, EterraSimpleMatchMaker]
+    [pallet_eterra_randomness, EterraRandomness]
 );
@@ -13,7 +13,8 @@ use alloc::vec::Vec;
 use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{BlakeTwo256, IdentifyAccount, Verify},
-    MultiAddress, MultiSignature,
+    transaction_validity::TransactionPriority,
+    MultiAddress, MultiSignature, Permill,
 };
 use sp_runtime::traits::AccountIdConversion;
 #[cfg(feature = "std")]
@@ -24,10 +25,10 @@ use codec::{Decode, Encode};
 use frame_support::parameter_types;
 use frame_support::PalletId;
 use frame_support::traits::ConstU32;
-use frame_support::traits::ConstU64;
 use frame_support::traits::ConstU8;
 use frame_support::traits::ConstU128;
 use frame_support::traits::ConstU16;
+use frame_support::traits::ConstBool;
 
 use frame_support::traits::Get;
 pub use frame_system::Call as SystemCall;
@@ -42,8 +43,11 @@ pub use pallet_eterra_tcg;
 pub use pallet_eterra_simple_matchmaker;
 pub use pallet_eterra_gamer;
 pub use pallet_eterra_game_authority;
+pub use pallet_eterra_randomness;
+pub use pallet_eterra_rating;
 
 pub struct HandProviderAdapter;
+pub struct RatingProviderAdapter;
 
 pub use pallet_timestamp::Call as TimestampCall;
 use scale_info::TypeInfo;
@@ -92,9 +96,9 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     // The version of the runtime specification. A full node will not attempt to use its native
     //   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
-    // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
-    //   the compatible custom types.
-    spec_version: 100,
+    // This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+    //   the compatible custom types. Bump it whenever storage changes.
+    spec_version: 102,
     impl_version: 1,
     apis: apis::RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -198,7 +202,21 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_eterra::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_eterra_simple_matchmaker::migrations::v1::MigrateV0ToV1<Runtime>,
+    // The AI's searches seed an in-memory PRNG; its storage nonce is gone.
+    frame_support::migrations::RemoveStorage<
+        EterraMonteCarloAiPalletName,
+        EterraMonteCarloAiNonce,
+        frame_support::weights::constants::RocksDbWeight,
+    >,
+);
+
+parameter_types! {
+    pub const EterraMonteCarloAiPalletName: &'static str = "EterraMonteCarloAi";
+    pub const EterraMonteCarloAiNonce: &'static str = "Nonce";
+}
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -314,6 +332,9 @@ parameter_types! {
     pub const AiBotPalletId: PalletId = PalletId(*b"ai/bot__");
     pub AiBotAccountParam: AccountId = AiBotPalletId::get().into_account_truncating();
 
+    // Share of a losing wager kept by the treasury
+    pub const EterraHouseCut: Permill = Permill::from_percent(5);
+
     pub const PlayersPerMatchConst: u8 = 2;
     pub const QueueCapacityConst: u32 = 1024;
 
@@ -329,21 +350,34 @@ impl Get<u8> for AiBotDifficulty {
 
 impl pallet_eterra::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type Randomness = EterraRandomness;
     type NumPlayers = EterraNumPlayers;
     type MaxRounds = EterraMaxRounds;
     type BlocksToPlayLimit = EterraBlocksToPlayLimit;
+    type MaxTimeoutsPerBlock = ConstU32<64>;
+    type FinishedGameRetention = ConstU32<{ 7 * DAYS }>;
+    type MaxPrunesPerBlock = ConstU32<64>;
     type HandSize = ConstU32<5>; // <<—— added
     type AiAccount = AiBotAccountParam;
     type AiDifficulty = ConstU8<60>;
+    type AiMovesOffchain = ConstBool<true>; // AI turns are searched by the offchain worker
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type StakeCurrency = Balances;
+    type TreasuryAccount = TreasuryAccount;
+    type HouseCut = EterraHouseCut;
+    type MatchmakingStake = ConstU128<0>;
+    type OnGameFinished = EterraRating; // Glicko-2 ratings and records
+    type WeightInfo = pallet_eterra::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_eterra_tcg::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type RandomnessSeed = ConstU64<42>;
+    type Randomness = EterraRandomness;
 
     type MaxAttempts = ConstU8<3>; // Set maximum attempts per card to 3
     type CardsPerPack = ConstU8<5>; // Set number of cards per pack to 5
     type MaxPacks = ConstU32<10>; // Set maximum packs a player can have to 10
+    type WeightInfo = pallet_eterra_tcg::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_eterra_simple_matchmaker::CurrentHandProvider<AccountId> for HandProviderAdapter {
@@ -352,21 +386,45 @@ impl pallet_eterra_simple_matchmaker::CurrentHandProvider<AccountId> for HandPro
         // Adjust the path to your pallet module and types.
         pallet_eterra::CurrentHandOf::<Runtime>::contains_key(who)
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_hand(who: &AccountId) {
+        pallet_eterra::CurrentHandOf::<Runtime>::insert(who, frame_support::BoundedVec::default());
+    }
+}
+
+impl pallet_eterra_simple_matchmaker::RatingProvider<AccountId> for RatingProviderAdapter {
+    fn rating(who: &AccountId) -> u32 {
+        use sp_runtime::FixedPointNumber;
+        // Glicko-2 ratings from the rating pallet, truncated to whole points.
+        let rating = pallet_eterra_rating::Pallet::<Runtime>::rating_of(who).rating;
+        (rating.into_inner().max(0) / sp_runtime::FixedI64::DIV).try_into().unwrap_or(u32::MAX)
+    }
 }
 
 impl pallet_eterra_simple_matchmaker::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type PlayersPerMatch = PlayersPerMatchConst;
     type QueueCapacity = QueueCapacityConst;
+    type MaxQueues = ConstU32<16>; // e.g. ranked, casual, a few wager tiers and rule sets
     type HandProvider = HandProviderAdapter; // uses the impl above
     type GameCreator  = pallet_eterra::Pallet<Runtime>;
+    type RatingProvider = RatingProviderAdapter;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type BaseRatingWindow = ConstU32<50>;
+    type RatingWindowGrowth = ConstU32<5>; // +50 rating points a minute
+    type FifoFallbackAfter = ConstU32<{ 5 * MINUTES }>;
+    type QueueTimeout = ConstU32<{ 30 * MINUTES }>;
+    type AcceptWindow = ConstU32<0>; // ready-check off; clients must call accept_match once set
+    type DeclineCooldown = ConstU32<{ 2 * MINUTES }>;
+    type WeightInfo = pallet_eterra_simple_matchmaker::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_eterra_simple_tcg::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
 
-    // You already had this:
-    type RandomnessSeed = ConstU64<12345>;
+    // Card stats come from the commit-reveal seed
+    type Randomness = EterraRandomness;
 
     // NEW: hook up balances as the currency
     type Currency = Balances;
@@ -376,6 +434,8 @@ impl pallet_eterra_simple_tcg::Config for Runtime {
 
     // NEW: the faucet account that should receive the fee (Treasury via PalletId!)
     type FaucetAccount = TreasuryAccount;
+
+    type WeightInfo = pallet_eterra_simple_tcg::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_eterra_daily_slots::Config for Runtime {
@@ -388,6 +448,8 @@ impl pallet_eterra_daily_slots::Config for Runtime {
     type MaxWeightEntries = MaxWeightEntries;
     type Currency = Balances;
     type RewardPerWin = RewardPerWinAmount; // defined below
+    type Randomness = EterraRandomness;
+    type WeightInfo = pallet_eterra_daily_slots::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_eterra_game_authority::Config for Runtime {
@@ -411,19 +473,64 @@ impl frame_support::traits::Get<Balance> for RewardPerWinAmount {
     }
 }
 
+impl pallet_eterra_randomness::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RevealWindow = ConstU32<{ 10 * MINUTES }>;
+    type WeightInfo = pallet_eterra_randomness::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const EterraRatingTau: sp_runtime::FixedI64 = sp_runtime::FixedI64::from_inner(500_000_000);
+}
+
+impl pallet_eterra_rating::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Tau = EterraRatingTau; // 0.5
+}
+
 impl pallet_eterra_faucet::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
 }
 
+parameter_types! {
+    pub const AiUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = UncheckedExtrinsic;
+}
+
 impl pallet_eterra_monte_carlo_ai::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Adapter = eterra_card_ai_adapter::eterra_adapter::Adapter;
     // Limits & tuning params for Monte Carlo search
-    type MaxActions = ConstU32<64>;        // max legal moves enumerated
+    // max legal moves enumerated: every hand card on every cell of the largest board
+    type MaxActions = ConstU32<{ eterra_card_ai_adapter::eterra_adapter::MAX_ACTIONS as u32 }>;
     type BaseIterations = ConstU32<200>;   // baseline simulations per suggest() call
     type MaxPlayoutDepth = ConstU16<16>;   // cut off long playouts
-    type RandomnessSeed = ConstU64<12345>; // deterministic-ish seed for hashing/entropy
+    type ExplorationConstant = ConstU32<1_000>; // UCB1 `c` in thousandths
+    type MaxTreeNodes = ConstU32<32_768>;       // caps the search tree's memory
+    type SolverThreshold = ConstU32<21>;        // solve the last four plies exactly
+    // Offchain AI: PvE turns are searched off-chain and submitted as signed unsigned txs
+    type TurnProvider = pallet_eterra::Pallet<Runtime>;
+    type AuthorityId = pallet_eterra_monte_carlo_ai::crypto::AuthorityId;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type OffchainIterations = ConstU32<20_000>; // far beyond what fits in a block
+    type MaxOffchainGames = ConstU32<16>;       // AI turns searched per worker run
+    type UnsignedPriority = AiUnsignedPriority;
+    type WeightInfo = pallet_eterra_monte_carlo_ai::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = pallet_eterra::AiBenchmarkHelper;
 }
 
 parameter_types! {
@@ -513,4 +620,10 @@ mod runtime {
 
     #[runtime::pallet_index(17)]
     pub type EterraGameAuthority = pallet_eterra_game_authority;
+
+    #[runtime::pallet_index(18)]
+    pub type EterraRandomness = pallet_eterra_randomness;
+
+    #[runtime::pallet_index(19)]
+    pub type EterraRating = pallet_eterra_rating;
 }